@@ -29,10 +29,12 @@ fn main() -> anyhow::Result<(), anyhow::Error> {
 
     window.make_current();
     window.set_key_polling(true);
+    window.set_framebuffer_size_polling(true);
 
     glfw.set_swap_interval(glfw::SwapInterval::None);
 
-    let mut renderer = renderer::Renderer::new(&|s| glfw.get_proc_address_raw(s))?;
+    let (width, height) = window.get_framebuffer_size();
+    let mut renderer = renderer::Renderer::new(&|s| glfw.get_proc_address_raw(s), width, height)?;
 
     let mut timer = std::time::Instant::now();
 
@@ -49,6 +51,18 @@ fn main() -> anyhow::Result<(), anyhow::Error> {
                     window.set_should_close(true);
                 }
 
+                glfw::WindowEvent::FramebufferSize(width, height) => {
+                    renderer.resize(width, height)?;
+                }
+
+                glfw::WindowEvent::Key(Key::F12, _, Action::Press, _) => {
+                    let timestamp = std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .as_millis();
+                    renderer.request_screenshot(format!("screenshot-{timestamp}.png"));
+                }
+
                 glfw::WindowEvent::Key(Key::F, _, Action::Repeat, _) => {
                     game_state.camera.zoom(-1.0);
                 }
@@ -1,32 +1,84 @@
-use gl_bindings::gl43::{
-    self as gl, AttributeComponents, AttributeIndex, BufferBindingIndex, BufferUsage,
+use gl_bindings::{
+    gl43::{self as gl, BufferBindingIndex, BufferUsage},
+    gl44, gl45,
 };
-use glam::Mat4;
-use std::{borrow::Borrow, mem::MaybeUninit, usize};
+use glam::{Mat4, Vec4};
+use std::{path::PathBuf, rc::Rc};
 
 use crate::{color32, GameState};
 
 use self::gfx::VertexLayout;
 
+mod compute;
 mod gfx;
 mod gltf;
+mod indirect;
 
 mod mesh;
+mod profiler;
+mod screenshot;
+mod shader;
+mod state;
+mod stream;
+mod target;
 mod text;
+mod texture;
+mod uniform;
 
 pub struct Renderer {
-    gl: gl::Api,
+    gl: Rc<gl::Api>,
+    target: target::RenderTarget,
     text_renderer: text::Renderer,
-    vao: gl::VertexArray,
-    gl_buffers: Vec<gl::Buffer>,
-    textures: Vec<gl::Texture>,
-    samplers: Vec<gl::Sampler>,
+    frame_uniforms: uniform::FrameUniforms<FrameBlock>,
+    draw_uniforms: uniform::DrawUniforms<DrawBlock>,
+    program: gl::OwnedProgram,
+    vao: gl::OwnedVertexArray,
+    indirect: indirect::IndirectDraws,
+    state: state::StateTracker,
+    profiler: profiler::GpuProfiler,
+    screenshots: screenshot::Screenshots,
+    debug_messages: gl::DebugCollector,
+    gl_buffers: Vec<gl::OwnedBuffer>,
+    textures: Vec<gl::OwnedTexture>,
+    samplers: Vec<gl::OwnedSampler>,
     meshes: Vec<MeshView>,
     materials: Vec<MeshMaterial>,
 }
 
 const FONT: &[u8] = include_bytes!("../resources/recursive.ttf");
 
+/// Debug messages that are pure noise, ids are only unique per source and type.
+const MUTED_DEBUG_MESSAGES: &[(gl::DebugSource, gl::DebugType, &[gl::GLuint])] = &[
+    // NVIDIA: which memory a buffer object lives in
+    (gl::DebugSource::API, gl::DebugType::OTHER, &[131_185]),
+];
+
+/// Uniform block binding points shared by all shaders.
+const FRAME_BINDING: gl::GLuint = 0;
+const DRAW_BINDING: gl::GLuint = 1;
+
+/// The `Frame` block of the shaders.
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct FrameBlock {
+    view_projection: Mat4,
+}
+
+unsafe impl gl::Pod for FrameBlock {}
+impl uniform::Block for FrameBlock {}
+
+/// The `Draw` block of the shaders.
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct DrawBlock {
+    model: Mat4,
+    color: color32::Linear32,
+    uv_rect: Vec4,
+}
+
+unsafe impl gl::Pod for DrawBlock {}
+impl uniform::Block for DrawBlock {}
+
 #[derive(Debug)]
 struct MeshView {
     vertices: usize,
@@ -82,49 +134,36 @@ mod cube {
     ];
 }
 
-fn create_shader_program(gl: &gl::Api, vextex_source: &str, fragment_source: &str) -> gl::Program {
-    unsafe {
-        let vs = gl.create_shader(gl::ShaderKind::VERTEX);
-        let fs = gl.create_shader(gl::ShaderKind::FRAGMENT);
-
-        gl.shader_source(
-            vs,
-            1,
-            [vextex_source.as_ptr().cast()].as_ptr(),
-            [vextex_source.len().try_into().expect("should fit")].as_ptr(),
-        );
-        gl.compile_shader(vs);
+/// Logs what the context provides and fails with a readable message if it lacks OpenGL 4.3.
+fn check_context(gl: &gl::Api) -> anyhow::Result<()> {
+    let capabilities = unsafe { gl::Capabilities::query(gl) };
+    log::info!("{capabilities}");
 
-        gl.shader_source(
-            fs,
-            1,
-            [fragment_source.as_ptr().cast()].as_ptr(),
-            [fragment_source.len().try_into().expect("should fit")].as_ptr(),
+    if !capabilities.supports(4, 3) {
+        anyhow::bail!("your driver lacks OpenGL 4.3, it only provides {capabilities}");
+    }
+    if !gl.missing_functions().is_empty() {
+        log::warn!(
+            "your driver lacks {} of {capabilities}",
+            gl.missing_functions().join(", ")
         );
-        gl.compile_shader(fs);
-
-        let program = gl.create_program();
-        gl.attach_shader(program, vs);
-        gl.attach_shader(program, fs);
-        gl.link_program(program);
-        gl.detach_shader(program, vs);
-        gl.detach_shader(program, fs);
-
-        gl.delete_shader(vs);
-        gl.delete_shader(fs);
+    }
 
-        gl.use_program(program);
+    Ok(())
+}
 
-        program
+fn create_vao(
+    gl: &Rc<gl::Api>,
+    dsa: Option<&gl45::Api>,
+    layout: &VertexLayout,
+) -> gl::OwnedVertexArray {
+    if let Some(dsa) = dsa {
+        return create_vao_dsa(gl, dsa, layout);
     }
-}
 
-fn create_vao(gl: &gl::Api, layout: &VertexLayout) -> gl::VertexArray {
     let vao = unsafe {
-        let mut vao = MaybeUninit::zeroed();
-        gl.gen_vertex_arrays(1, vao.as_mut_ptr());
-        let vao = vao.assume_init();
-        gl.bind_vertex_array(vao);
+        let vao = gl::OwnedVertexArray::new(gl);
+        gl.bind_vertex_array(vao.handle());
         vao
     };
 
@@ -133,15 +172,64 @@ fn create_vao(gl: &gl::Api, layout: &VertexLayout) -> gl::VertexArray {
 
         unsafe {
             gl.enable_vertex_attrib_array(location);
-            gl.vertex_attrib_format_ptr(
+            let components = attribute.kind.components().try_into().unwrap();
+            let offset = attribute.offset.try_into().unwrap();
+            if attribute.kind.is_integer() {
+                gl.vertex_attrib_i_format(location, components, attribute.kind.into(), offset);
+            } else {
+                gl.vertex_attrib_format(
+                    location,
+                    components,
+                    attribute.kind.into(),
+                    attribute.normalized.into(),
+                    offset,
+                );
+            }
+
+            gl.vertex_attrib_binding(
                 location,
-                attribute.kind.components().try_into().unwrap(),
-                attribute.kind.into(),
-                attribute.normalized.into(),
-                attribute.offset.try_into().unwrap(),
+                BufferBindingIndex::new(attribute.buffer.try_into().unwrap()),
             );
+        }
+    }
+    vao
+}
 
-            gl.vertex_attrib_binding(
+fn create_vao_dsa(
+    gl: &Rc<gl::Api>,
+    dsa: &gl45::Api,
+    layout: &VertexLayout,
+) -> gl::OwnedVertexArray {
+    let vao = unsafe { gl::OwnedVertexArray::from_raw(Rc::clone(gl), dsa.create_vertex_array()) };
+
+    for attribute in &layout.attributes {
+        let location = gl::AttributeIndex::new(attribute.location.into());
+
+        unsafe {
+            dsa.enable_vertex_array_attrib(vao.handle(), location);
+            let components = attribute.kind.components().try_into().unwrap();
+            let offset = attribute.offset.try_into().unwrap();
+            if attribute.kind.is_integer() {
+                dsa.vertex_array_attrib_i_format(
+                    vao.handle(),
+                    location,
+                    components,
+                    attribute.kind.into(),
+                    offset,
+                );
+            } else {
+                dsa.vertex_array_attrib_format(
+                    vao.handle(),
+                    location,
+                    components,
+                    attribute.kind.into(),
+                    attribute.normalized.into(),
+                    offset,
+                );
+            }
+
+            dsa.vertex_array_attrib_binding(
+                vao.handle(),
                 location,
                 BufferBindingIndex::new(attribute.buffer.try_into().unwrap()),
             );
@@ -150,15 +238,179 @@ fn create_vao(gl: &gl::Api, layout: &VertexLayout) -> gl::VertexArray {
     vao
 }
 
+/// Creates a buffer filled with `data` and names it `label`.
+/// Without direct state access it stays bound to `target`.
+fn create_static_buffer(
+    gl: &Rc<gl::Api>,
+    dsa: Option<&gl45::Api>,
+    target: gl::BufferTarget,
+    data: &[u8],
+    label: &str,
+) -> gl::OwnedBuffer {
+    let buffer = upload_static_buffer(gl, dsa, target, data);
+    unsafe { gl.label(buffer.handle(), label) };
+    buffer
+}
+
+fn upload_static_buffer(
+    gl: &Rc<gl::Api>,
+    dsa: Option<&gl45::Api>,
+    target: gl::BufferTarget,
+    data: &[u8],
+) -> gl::OwnedBuffer {
+    if let Some(dsa) = dsa {
+        return unsafe {
+            let buffer = gl::OwnedBuffer::from_raw(Rc::clone(gl), dsa.create_buffer());
+            dsa.named_buffer_storage_slice(buffer.handle(), data, gl::BufferStorageFlags::NONE);
+            buffer
+        };
+    }
+
+    unsafe {
+        let buffer = gl::OwnedBuffer::new(gl);
+
+        gl.bind_buffer(target, buffer.handle());
+        gl.buffer_data_slice(target, data, BufferUsage::STATIC_DRAW);
+        buffer
+    }
+}
+
+/// Packs the vertex data of `meshes` into one buffer per buffer of `layout` and all indices
+/// into a single index buffer, returning the data and where each mesh ended up.
+fn pack_meshes(
+    layout: &VertexLayout,
+    data: &[u8],
+    meshes: Vec<gfx::Mesh>,
+) -> (Vec<Vec<u8>>, Vec<u8>, Vec<MeshView>) {
+    let mut vertex_buffers = vec![Vec::new(); layout.buffers.len()];
+    let mut index_buffer: Vec<u8> = Vec::new();
+
+    let mut base_index = 0;
+    let mut mesh_handles = Vec::with_capacity(meshes.len());
+
+    for mesh in meshes {
+        log::debug!(
+            "packing mesh {} with {} vertices",
+            mesh.name.as_deref().unwrap_or("<unnamed>"),
+            mesh.vertices.count
+        );
+
+        // indirect draws address indices by count, so each mesh starts aligned to its kind
+        if let Some(indices) = &mesh.indices {
+            let aligned = index_buffer.len().next_multiple_of(indices.kind.size());
+            index_buffer.resize(aligned, 0);
+        }
+
+        mesh_handles.push(MeshView {
+            base_index,
+            vertices: mesh.vertices.count,
+            indices: mesh.indices.as_ref().map(|indices| MeshIndices {
+                count: indices.count,
+                offset: index_buffer.len(),
+                kind: indices.kind,
+            }),
+            vertex_offset: 0,
+        });
+
+        for (index, buffer_range) in mesh.vertices.buffers.iter().enumerate() {
+            vertex_buffers[index].extend(&data[buffer_range.clone()]);
+        }
+
+        if let Some(index) = mesh.indices {
+            index_buffer.extend(index.data);
+        }
+
+        base_index += mesh.vertices.count;
+    }
+
+    (vertex_buffers, index_buffer, mesh_handles)
+}
+
+/// Uploads `meshes` and attaches the buffers to `vao`, which has to be bound without DSA.
+/// Returns the buffers, the index buffer last if any mesh is indexed, and the meshes.
+fn upload_meshes(
+    gl: &Rc<gl::Api>,
+    dsa: Option<&gl45::Api>,
+    layout: &VertexLayout,
+    vao: &gl::OwnedVertexArray,
+    data: &[u8],
+    meshes: Vec<gfx::Mesh>,
+) -> (Vec<gl::OwnedBuffer>, Vec<MeshView>) {
+    let (vertex_buffers, index_buffer, mesh_handles) = pack_meshes(layout, data, meshes);
+
+    let mut gl_buffers: Vec<_> = layout
+        .buffers
+        .iter()
+        .map(|buffer| {
+            create_static_buffer(
+                gl,
+                dsa,
+                gl::BufferTarget::ARRAY_BUFFER,
+                &vertex_buffers[buffer.buffer],
+                &format!("mesh vertex buffer {}", buffer.buffer),
+            )
+        })
+        .collect();
+
+    for b in &layout.buffers {
+        let location = gl::BufferBindingIndex::new(b.buffer.try_into().unwrap());
+        let stride = match b.stride {
+            gfx::Stride::Packed => layout.buffer_vertex_size(b.buffer).try_into().unwrap(),
+            gfx::Stride::Inverleaved(n) => n.try_into().unwrap(),
+        };
+
+        unsafe {
+            if let Some(dsa) = dsa {
+                dsa.vertex_array_vertex_buffer(
+                    vao.handle(),
+                    location,
+                    gl_buffers[b.buffer].handle(),
+                    0,
+                    stride,
+                );
+            } else {
+                gl.bind_vertex_buffer(location, gl_buffers[b.buffer].handle(), 0, stride);
+            }
+        }
+    }
+
+    // OpenGL rejects empty buffer storage, meshes without indices don't need one anyway
+    if !index_buffer.is_empty() {
+        // without DSA, binding the index buffer while the VAO is bound attaches it
+        let index_buffer = create_static_buffer(
+            gl,
+            dsa,
+            gl::BufferTarget::ELEMENT_ARRAY_BUFFER,
+            &index_buffer,
+            "mesh index buffer",
+        );
+        if let Some(dsa) = dsa {
+            unsafe { dsa.vertex_array_element_buffer(vao.handle(), index_buffer.handle()) };
+        }
+        gl_buffers.push(index_buffer);
+    }
+
+    (gl_buffers, mesh_handles)
+}
+
 impl Renderer {
     pub fn new(
         proc_address: &impl Fn(&str) -> *const std::ffi::c_void,
+        width: gl::GLsizei,
+        height: gl::GLsizei,
     ) -> anyhow::Result<Self, anyhow::Error> {
-        let gl = unsafe { gl::Api::with_loader(proc_address) }?;
+        let gl = Rc::new(unsafe { gl::Api::with_loader(proc_address) });
+        check_context(&gl)?;
+        let storage = unsafe { gl44::Api::load_if_supported(&gl, proc_address) };
+        let dsa = unsafe { gl45::Api::load_if_supported(&gl, proc_address) };
 
-        unsafe {
-            gl.enable(gl::Capability::DEBUG_OUTPUT);
-            gl.debug_message_callback(Some(debug_message_callback), std::ptr::null_mut());
+        if dsa.is_none() {
+            log::info!("OpenGL 4.5 not available, falling back to bind-to-edit");
+        }
+
+        let debug_messages = unsafe { gl::DebugCollector::install(&gl) };
+        for &(source, kind, ids) in MUTED_DEBUG_MESSAGES {
+            unsafe { gl.mute_debug_messages(source, kind, ids) };
         }
 
         unsafe {
@@ -166,10 +418,22 @@ impl Renderer {
             gl.clear_color(r, g, b, a);
         }
 
-        let mut text_renderer = text::Renderer::new(&gl);
+        // before the font atlas, creating the color texture changes the texture binding.
+        // Sized like the window framebuffer, which differs from the window size on HiDPI screens
+        let target = target::RenderTarget::new(&gl, width, height)?;
+
+        let mut text_renderer = text::Renderer::new(&gl)?;
 
         let config = gltf::Config::default();
-        let vao = create_vao(&gl, &config.vertex_layout);
+        let program = shader::ProgramBuilder::new(&gl)
+            .stage(gl::ShaderKind::VERTEX, VS)
+            .stage(gl::ShaderKind::FRAGMENT, FS)
+            .build()?;
+        config
+            .vertex_layout
+            .check_inputs(&unsafe { gl::ProgramReflection::new(&gl, program.handle()) })?;
+        let vao = create_vao(&gl, dsa.as_ref(), &config.vertex_layout);
+        unsafe { gl.label(vao.handle(), "mesh vertex array") };
 
         //let scene =
         //gltf::load_from_memory(&config, include_bytes!("../resources/Avocado.glb")).unwrap();
@@ -186,107 +450,33 @@ impl Renderer {
             panic!();
         };
 
-        let mut vertex_buffers: Vec<Vec<u8>> =
-            Vec::with_capacity(config.vertex_layout.buffers.len());
-
-        for i in 0..config.vertex_layout.buffers.len() {
-            vertex_buffers.push(Vec::new());
-        }
-
-        let mut index_buffer: Vec<u8> = Vec::new();
-
-        let mut base_index = 0;
-        let mut mesh_handles = Vec::with_capacity(meshes.len());
-
-        for mesh in meshes {
-            println!("{:?}", mesh.name);
-
-            mesh_handles.push(MeshView {
-                base_index,
-                vertices: mesh.vertices.count,
-                indices: mesh.indices.as_ref().map(|indices| MeshIndices {
-                    count: indices.count,
-                    offset: index_buffer.len(),
-                    kind: indices.kind,
-                }),
-                vertex_offset: 0,
-            });
-
-            for (index, buffer_range) in mesh.vertices.buffers.iter().enumerate() {
-                let chunk = &scene.data[buffer_range.clone()];
-                vertex_buffers[index].extend(chunk);
-            }
-
-            if let Some(index) = mesh.indices {
-                index_buffer.extend(index.data);
-            }
-
-            base_index += mesh.vertices.count;
-        }
+        let (gl_buffers, mesh_handles) = upload_meshes(
+            &gl,
+            dsa.as_ref(),
+            &config.vertex_layout,
+            &vao,
+            &scene.data,
+            meshes,
+        );
+        let indirect = indirect::IndirectDraws::new(&gl, dsa.as_ref(), &mesh_handles);
 
-        let mut gl_buffers: Vec<_> = config
-            .vertex_layout
-            .buffers
+        // before the font atlas, which has to stay bound to texture unit 0
+        let textures = scene
+            .images
             .iter()
-            .map(|buffer| unsafe {
-                let mut gl_buffer = MaybeUninit::zeroed();
-                gl.gen_buffers(1, gl_buffer.as_mut_ptr());
-                let gl_buffer = gl_buffer.assume_init();
-
-                gl.bind_buffer(gl::BufferTarget::ARRAY_BUFFER, gl_buffer);
-                gl.buffer_data(
-                    gl::BufferTarget::ARRAY_BUFFER,
-                    (vertex_buffers[buffer.buffer].len() * std::mem::size_of::<u8>())
-                        .try_into()
-                        .unwrap(),
-                    vertex_buffers[buffer.buffer].as_ptr().cast(),
-                    BufferUsage::STATIC_DRAW,
-                );
-
-                gl_buffer
+            .flatten()
+            .enumerate()
+            .map(|(index, image)| {
+                let texture = texture::create_image_texture(&gl, image, image.srgb);
+                unsafe { gl.label(texture.handle(), &format!("scene image {index}")) };
+                texture
             })
             .collect();
+        let samplers = Vec::new();
 
-        for b in &config.vertex_layout.buffers {
-            unsafe {
-                let location = gl::BufferBindingIndex::new(b.buffer.try_into().unwrap());
-                gl.bind_vertex_buffer(
-                    location,
-                    gl_buffers[b.buffer],
-                    0,
-                    match b.stride {
-                        gfx::Stride::Packed => config
-                            .vertex_layout
-                            .buffer_vertex_size(b.buffer)
-                            .try_into()
-                            .unwrap(),
-                        gfx::Stride::Inverleaved(n) => n.try_into().unwrap(),
-                    },
-                );
-            }
-        }
-
-        unsafe {
-            let mut gl_buffer = MaybeUninit::zeroed();
-            gl.gen_buffers(1, gl_buffer.as_mut_ptr());
-            let gl_buffer = gl_buffer.assume_init();
-
-            gl.bind_buffer(gl::BufferTarget::ELEMENT_ARRAY_BUFFER, gl_buffer);
-
-            gl.buffer_data(
-                gl::BufferTarget::ELEMENT_ARRAY_BUFFER,
-                (index_buffer.len() * std::mem::size_of::<u8>())
-                    .try_into()
-                    .unwrap(),
-                index_buffer.as_ptr().cast(),
-                BufferUsage::STATIC_DRAW,
-            );
-
-            gl_buffers.push(gl_buffer);
-        };
-
-        let font_handle = text_renderer.load_font_from_memory(
+        let _font_handle = text_renderer.load_font_from_memory(
             &gl,
+            dsa.as_ref(),
             72.0,
             FONT,
             ('a'..='z')
@@ -295,14 +485,24 @@ impl Renderer {
                 .chain(".,-_+/=()!".chars()),
         )?;
 
-        let textures = Vec::new();
-        let samplers = Vec::new();
+        let frame_uniforms = uniform::FrameUniforms::new(&gl, FRAME_BINDING);
+        let draw_uniforms = uniform::DrawUniforms::new(&gl, storage.as_ref(), DRAW_BINDING);
+
         let materials = Vec::new();
 
         Ok(Self {
             gl,
+            target,
             text_renderer,
+            frame_uniforms,
+            draw_uniforms,
+            program,
             vao,
+            indirect,
+            state: state::StateTracker::new(),
+            profiler: profiler::GpuProfiler::new(),
+            screenshots: screenshot::Screenshots::new(),
+            debug_messages,
             gl_buffers,
             meshes: mesh_handles,
             textures,
@@ -311,68 +511,103 @@ impl Renderer {
         })
     }
 
+    /// Recreates the render target for the new size of the window framebuffer.
+    pub fn resize(
+        &mut self,
+        width: gl::GLsizei,
+        height: gl::GLsizei,
+    ) -> Result<(), target::IncompleteFramebuffer> {
+        // minimized windows have an empty framebuffer, keep drawing into the old target
+        if width <= 0 || height <= 0 {
+            return Ok(());
+        }
+
+        self.target = target::RenderTarget::new(&self.gl, width, height)?;
+        // creating the color texture replaced the font atlas on texture unit 0
+        self.text_renderer.bind_atlas(&self.gl);
+        Ok(())
+    }
+
+    /// Saves the next rendered frame as a PNG to `path`, a frame or two later.
+    pub fn request_screenshot(&mut self, path: impl Into<PathBuf>) {
+        self.screenshots.request(path.into());
+    }
+
     pub fn update(&mut self, dt: f32, game_state: &mut GameState) {
         let gl = &self.gl;
+        self.profiler.begin_frame(gl);
+        self.target.bind(gl);
 
+        self.frame_uniforms.update(
+            gl,
+            &FrameBlock {
+                view_projection: game_state.camera.view_projection(),
+            },
+        );
+        self.frame_uniforms.bind(gl);
+
+        let slot = self.draw_uniforms.push(&DrawBlock {
+            model: Mat4::IDENTITY,
+            color: color32::Linear32::WHITE,
+            uv_rect: Vec4::ZERO,
+        });
+        self.draw_uniforms.upload(gl);
+        self.draw_uniforms.bind(gl, slot);
+
+        let meshes = unsafe { gl::DebugScope::new(gl, "meshes") };
+        let meshes_timer = self.profiler.scope(gl, "meshes");
+        self.state.apply(gl, &state::RenderState::OPAQUE);
         unsafe {
             gl.clear(gl::ClearMask::ALL);
-            gl.bind_vertex_array(self.vao);
-
-            let vp = game_state.camera.view_projection();
-            gl.uniform_matrix4_fv(1, 1, gl::GLboolean::FALSE, std::ptr::addr_of!(vp).cast());
+            gl.use_program(self.program.handle());
+            gl.bind_vertex_array(self.vao.handle());
         }
 
-        for mesh in &self.meshes {
+        self.indirect.draw(gl);
+
+        // meshes without indices aren't part of the indirect draws
+        for mesh in self.meshes.iter().filter(|mesh| mesh.indices.is_none()) {
             unsafe {
-                match &mesh.indices {
-                    Some(index) => {
-                        gl.draw_elements_base_vertex(
-                            gl::Primitive::TRIANGLES,
-                            index.count.try_into().unwrap(),
-                            match index.kind {
-                                gfx::IndexKind::U8 => gl::ElementKind::UNSIGNED_BYTE,
-                                gfx::IndexKind::U16 => gl::ElementKind::UNSIGNED_SHORT,
-                                gfx::IndexKind::U32 => gl::ElementKind::UNSIGNED_INT,
-                            },
-                            index.offset as *const _,
-                            mesh.base_index.try_into().unwrap(),
-                        );
-                    }
-                    None => {
-                        gl.draw_arrays(
-                            gl::Primitive::TRIANGLES,
-                            mesh.vertex_offset.try_into().unwrap(),
-                            mesh.vertices.try_into().unwrap(),
-                        );
-                    }
-                }
+                gl.draw_arrays(
+                    gl::Primitive::TRIANGLES,
+                    mesh.vertex_offset.try_into().unwrap(),
+                    mesh.vertices.try_into().unwrap(),
+                );
             }
         }
 
-        self.text_renderer.update(gl);
+        drop(meshes_timer);
+        drop(meshes);
+
+        {
+            let _text = unsafe { gl::DebugScope::new(gl, "text") };
+            let _timer = self.profiler.scope(gl, "text");
+            self.text_renderer.update(gl, &mut self.state);
+        }
+        self.target.blit_to_default(gl);
+        self.screenshots.update(gl, &self.target);
+
+        let counters = self.state.take_counters();
+        log::trace!(
+            "{} state changes, {} redundant ones skipped",
+            counters.issued,
+            counters.skipped
+        );
+        for timing in self.profiler.timings() {
+            log::trace!("{} took {:?} on the GPU", timing.name, timing.duration);
+        }
+        for message in self.debug_messages.take() {
+            log_debug_message(&message);
+        }
     }
 }
 
-extern "system" fn debug_message_callback(
-    source: gl::DebugSource,
-    kind: gl::DebugType,
-    id: gl::GLuint,
-    severity: gl::DebugSeverity,
-    _length: gl::GLsizei,
-    message: *const gl::GLchar,
-    _user_param: *mut std::ffi::c_void,
-) {
-    let error_message = unsafe {
-        std::ffi::CStr::from_ptr(message.cast())
-            .to_str()
-            .unwrap_or("[FAILED TO READ GL ERROR MESSAGE]")
-    };
-
-    match severity {
-        gl::DebugSeverity::HIGH => log::error!("{id}: {kind} from {source}: {error_message}"),
-        gl::DebugSeverity::MEDIUM => log::warn!("{id}: {kind} from {source}: {error_message}"),
-        gl::DebugSeverity::LOW => log::info!("{id}: {kind} from {source}: {error_message}"),
-        _ => log::trace!("{id}: {kind} from {source}: {error_message}"),
+fn log_debug_message(message: &gl::DebugMessage) {
+    match message.severity {
+        gl::DebugSeverity::HIGH => log::error!("{message}"),
+        gl::DebugSeverity::MEDIUM => log::warn!("{message}"),
+        gl::DebugSeverity::LOW => log::info!("{message}"),
+        _ => log::trace!("{message}"),
     }
 }
 
@@ -381,7 +616,15 @@ const VS: &str = "
 layout(location = 0) in vec3 position;
 layout(location = 1) in vec2 uv;
 
-layout(location = 1) uniform mat4 vp;
+layout(std140, binding = 0) uniform Frame {
+    mat4 view_projection;
+} frame;
+
+layout(std140, binding = 1) uniform Draw {
+    mat4 model;
+    vec4 color;
+    vec4 uv_rect;
+} draw;
 
 out vec3 vertex_color;
 out vec2 vertex_uv;
@@ -391,14 +634,19 @@ void main() {
     vec3 vertex = position.xyz;
     vertex_uv = uv;
 
-    gl_Position = vp * vec4(vertex.x, vertex.y, vertex.z, 1.0);
+    gl_Position = frame.view_projection * draw.model * vec4(vertex.x, vertex.y, vertex.z, 1.0);
 }";
 
 const FS: &str = "
 #version 430
 
-layout(location = 0) uniform sampler2D sampler;
-layout(location = 7) uniform vec4 in_color;
+uniform sampler2D sampler;
+
+layout(std140, binding = 1) uniform Draw {
+    mat4 model;
+    vec4 color;
+    vec4 uv_rect;
+} draw;
 
 in vec3 vertex_color;
 in vec2 vertex_uv;
@@ -406,5 +654,5 @@ in vec2 vertex_uv;
 out vec4 color;
 
 void main() {
-    color = texture(sampler, vertex_uv).r * in_color;
+    color = texture(sampler, vertex_uv).r * draw.color;
 }";
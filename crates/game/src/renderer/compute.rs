@@ -0,0 +1,127 @@
+use std::rc::Rc;
+
+use gl_bindings::gl43::{
+    self as gl, GLboolean, GLuint, ImageAccess, InternalFormat, MemoryBarrier, ShaderKind, Texture,
+};
+
+use super::shader::{ProgramBuilder, ShaderError};
+
+/// A program with a single compute shader, used for GPU work outside of the draw calls like
+/// particle simulation or post-processing.
+pub struct ComputeProgram {
+    program: gl::OwnedProgram,
+}
+
+impl ComputeProgram {
+    pub fn new(gl: &Rc<gl::Api>, source: &str) -> Result<Self, ShaderError> {
+        let program = ProgramBuilder::new(gl)
+            .stage(ShaderKind::COMPUTE, source)
+            .build()?;
+
+        Ok(Self { program })
+    }
+
+    /// Binds level 0 of `texture` to the image unit `unit`, to be read or written by
+    /// `image2D` uniforms with `layout(binding = unit)`.
+    pub fn bind_image(
+        gl: &gl::Api,
+        unit: GLuint,
+        texture: Texture,
+        access: ImageAccess,
+        format: InternalFormat,
+    ) {
+        unsafe {
+            gl.bind_image_texture(unit, texture, 0, GLboolean::FALSE, 0, access, format);
+        }
+    }
+
+    /// Runs `groups` work groups and issues `barrier`, so that the following commands see
+    /// the results written by the shader. Leaves the program bound.
+    pub fn dispatch(&self, gl: &gl::Api, groups: [GLuint; 3], barrier: MemoryBarrier) {
+        let [x, y, z] = groups;
+
+        unsafe {
+            gl.use_program(self.program.handle());
+            gl.dispatch_compute(x, y, z);
+            gl.memory_barrier(barrier);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use gl_bindings::{
+        gl43::{self as gl, Object},
+        mock,
+    };
+
+    use super::ComputeProgram;
+
+    #[test]
+    fn dispatches_with_the_program_bound_and_issues_the_barrier() {
+        mock::reset();
+        let gl = Rc::new(unsafe { gl::Api::with_loader(&mock::loader) });
+        let compute = ComputeProgram::new(&gl, "void main() {}").unwrap();
+        let program = mock::Arg::Int(compute.program.handle().raw().into());
+
+        let start = mock::calls().len();
+        compute.dispatch(&gl, [8, 4, 1], gl::MemoryBarrier::SHADER_IMAGE_ACCESS);
+
+        let calls: Vec<_> = mock::calls()
+            .into_iter()
+            .skip(start)
+            // the `checked` feature adds one after every call
+            .filter(|call| call.name != "glGetError")
+            .map(|call| (call.name, call.args))
+            .collect();
+        assert_eq!(
+            calls,
+            [
+                ("glUseProgram", vec![program]),
+                (
+                    "glDispatchCompute",
+                    vec![mock::Arg::Int(8), mock::Arg::Int(4), mock::Arg::Int(1)]
+                ),
+                // SHADER_IMAGE_ACCESS_BARRIER_BIT
+                ("glMemoryBarrier", vec![mock::Arg::Int(0x20)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn images_bind_level_zero_of_the_whole_texture() {
+        mock::reset();
+        let gl = Rc::new(unsafe { gl::Api::with_loader(&mock::loader) });
+        let texture = unsafe { gl::OwnedTexture::new(&gl) };
+
+        ComputeProgram::bind_image(
+            &gl,
+            2,
+            texture.handle(),
+            gl::ImageAccess::WRITE_ONLY,
+            gl::InternalFormat::RGBA8,
+        );
+
+        let calls = mock::calls();
+        let bind = calls
+            .iter()
+            .rfind(|call| call.name != "glGetError")
+            .unwrap();
+        assert_eq!(bind.name, "glBindImageTexture");
+        // unit, texture, level, not layered, layer, WRITE_ONLY, RGBA8
+        assert_eq!(
+            bind.args,
+            [
+                mock::Arg::Int(2),
+                mock::Arg::Int(texture.handle().raw().into()),
+                mock::Arg::Int(0),
+                mock::Arg::Int(0),
+                mock::Arg::Int(0),
+                mock::Arg::Int(0x88B9),
+                mock::Arg::Int(0x8058)
+            ]
+        );
+    }
+}
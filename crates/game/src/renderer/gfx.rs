@@ -4,12 +4,42 @@ use gl_bindings::gl43;
 
 use crate::color32;
 
+/// Linear filtering without mipmaps, clamped to the edge by default.
 #[derive(Clone, Copy, Debug)]
 pub struct Sampler {
     pub min_filter: MinFilter,
     pub mag_filter: MagFilter,
     pub wrap_u: Wrap,
     pub wrap_v: Wrap,
+    pub wrap_w: Wrap,
+    /// The range of mip levels that are sampled from, all of them by default.
+    pub min_lod: f32,
+    pub max_lod: f32,
+    /// The color outside of the texture for `Wrap::Border`.
+    pub border_color: [f32; 4],
+    /// Compares depth lookups against a reference value instead of returning the depth,
+    /// like shadow samplers do.
+    pub compare: Option<Compare>,
+    /// Needs OpenGL 4.6 or one of the texture filter anisotropic extensions, `None` leaves
+    /// the parameter untouched.
+    pub anisotropy: Option<f32>,
+}
+
+impl Default for Sampler {
+    fn default() -> Self {
+        Self {
+            min_filter: MinFilter::default(),
+            mag_filter: MagFilter::default(),
+            wrap_u: Wrap::default(),
+            wrap_v: Wrap::default(),
+            wrap_w: Wrap::default(),
+            min_lod: -1000.0,
+            max_lod: 1000.0,
+            border_color: [0.0; 4],
+            compare: None,
+            anisotropy: None,
+        }
+    }
 }
 
 #[derive(Default, Clone, Copy, Debug)]
@@ -36,6 +66,19 @@ pub enum Wrap {
     #[default]
     Clamp,
     Mirrored,
+    Border,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compare {
+    Never,
+    Less,
+    Equal,
+    LessOrEqual,
+    Greater,
+    NotEqual,
+    GreaterOrEqual,
+    Always,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -90,6 +133,8 @@ pub struct Image {
     pub width: u32,
     pub height: u32,
     pub format: ImageFormat,
+    /// Colors stored as sRGB, like base color and emissive textures, unlike data such as normals.
+    pub srgb: bool,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -111,36 +156,104 @@ pub enum ImageFormat {
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AttributeSemantic {
     Position,
+    Normal,
     UV(u8),
+    Joints(u8),
+    Weights(u8),
 }
 
-#[derive(Clone, Copy, Debug)]
+/// The components of an attribute the shader reads as floats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComponentKind {
+    I8,
+    U8,
+    I16,
+    U16,
+    F16,
+    F32,
+}
+
+/// The components of an attribute the shader reads as integers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntegerKind {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AttributeKind {
     Vec2,
     Vec3,
     Vec4,
+    /// Up to four components converted to floats, integers are mapped to 0..1 or -1..1 if the
+    /// attribute is `normalized`, e.g. quantized UVs and normals.
+    Float(ComponentKind, u8),
+    /// Up to four integers the shader reads as they are, e.g. joint indices.
+    Integer(IntegerKind, u8),
+    /// A signed `vec4` in 32 bits, 10 bits each for xyz and 2 for w, usually normalized.
+    Packed2_10_10_10,
+}
+
+impl ComponentKind {
+    pub const fn size(self) -> usize {
+        match self {
+            Self::I8 | Self::U8 => 1,
+            Self::I16 | Self::U16 | Self::F16 => 2,
+            Self::F32 => 4,
+        }
+    }
+}
+
+impl IntegerKind {
+    pub const fn size(self) -> usize {
+        match self {
+            Self::I8 | Self::U8 => 1,
+            Self::I16 | Self::U16 => 2,
+            Self::I32 | Self::U32 => 4,
+        }
+    }
+
+    pub const fn is_signed(self) -> bool {
+        matches!(self, Self::I8 | Self::I16 | Self::I32)
+    }
 }
 
 impl AttributeKind {
+    /// The size of one component, all four together for `Packed2_10_10_10`.
     pub const fn component_size(self) -> usize {
         match self {
-            AttributeKind::Vec2 | AttributeKind::Vec3 | AttributeKind::Vec4 => {
+            Self::Vec2 | Self::Vec3 | Self::Vec4 | Self::Packed2_10_10_10 => {
                 std::mem::size_of::<f32>()
             }
+            Self::Float(kind, _) => kind.size(),
+            Self::Integer(kind, _) => kind.size(),
         }
     }
 
     pub fn size(self) -> usize {
-        self.component_size() * usize::from(self.components())
+        match self {
+            Self::Packed2_10_10_10 => self.component_size(),
+            _ => self.component_size() * usize::from(self.components()),
+        }
     }
 
     pub const fn components(self) -> u8 {
         match self {
             Self::Vec2 => 2,
             Self::Vec3 => 3,
-            Self::Vec4 => 4,
+            Self::Vec4 | Self::Packed2_10_10_10 => 4,
+            Self::Float(_, components) | Self::Integer(_, components) => components,
         }
     }
+
+    /// Whether the attribute needs `vertex_attrib_i_format` instead of `vertex_attrib_format`.
+    pub const fn is_integer(self) -> bool {
+        matches!(self, Self::Integer(..))
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -151,7 +264,7 @@ pub struct Indices {
     pub data: Vec<u8>,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum IndexKind {
     U8,
     U16,
@@ -203,6 +316,62 @@ impl VertexLayout {
             }
         })
     }
+
+    /// Checks that every vertex input of `program` is fed by an attribute of the same kind.
+    pub fn check_inputs(&self, program: &gl43::ProgramReflection) -> Result<(), LayoutMismatch> {
+        for input in &program.inputs {
+            let Some(location) = input.location else {
+                continue;
+            };
+
+            let Some(attribute) = self
+                .attributes
+                .iter()
+                .find(|attr| i32::from(attr.location) == location)
+            else {
+                return Err(LayoutMismatch::Missing(input.name.clone()));
+            };
+
+            if gl43::ShaderDataKind::from(attribute.kind) != input.kind {
+                return Err(LayoutMismatch::Kind {
+                    input: input.name.clone(),
+                    expected: input.kind,
+                    found: attribute.kind,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A vertex input of a shader program that a `VertexLayout` doesn't match.
+#[derive(Debug)]
+pub enum LayoutMismatch {
+    Missing(String),
+    Kind {
+        input: String,
+        expected: gl43::ShaderDataKind,
+        found: AttributeKind,
+    },
+}
+
+impl std::error::Error for LayoutMismatch {}
+
+impl std::fmt::Display for LayoutMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing(input) => write!(f, "no vertex attribute for input {input}"),
+            Self::Kind {
+                input,
+                expected,
+                found,
+            } => write!(
+                f,
+                "input {input} is a {expected}, the attribute a {found:?}"
+            ),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -277,12 +446,250 @@ pub struct Scene {
     pub data: Vec<u8>,
 }
 
+impl IndexKind {
+    pub const fn size(self) -> usize {
+        match self {
+            Self::U8 => 1,
+            Self::U16 => 2,
+            Self::U32 => 4,
+        }
+    }
+}
+
+impl From<IndexKind> for gl43::ElementKind {
+    fn from(value: IndexKind) -> Self {
+        match value {
+            IndexKind::U8 => Self::UNSIGNED_BYTE,
+            IndexKind::U16 => Self::UNSIGNED_SHORT,
+            IndexKind::U32 => Self::UNSIGNED_INT,
+        }
+    }
+}
+
 impl From<AttributeKind> for gl43::VertexAttributeKind {
     fn from(value: AttributeKind) -> Self {
         match value {
-            AttributeKind::Vec3 | AttributeKind::Vec4 | AttributeKind::Vec2 => {
-                gl43::VertexAttributeKind::FLOAT
-            }
+            AttributeKind::Vec3 | AttributeKind::Vec4 | AttributeKind::Vec2 => Self::FLOAT,
+            AttributeKind::Float(kind, _) => match kind {
+                ComponentKind::I8 => Self::BYTE,
+                ComponentKind::U8 => Self::UNSIGNED_BYTE,
+                ComponentKind::I16 => Self::SHORT,
+                ComponentKind::U16 => Self::UNSIGNED_SHORT,
+                ComponentKind::F16 => Self::HALF_FLOAT,
+                ComponentKind::F32 => Self::FLOAT,
+            },
+            AttributeKind::Integer(kind, _) => match kind {
+                IntegerKind::I8 => Self::BYTE,
+                IntegerKind::U8 => Self::UNSIGNED_BYTE,
+                IntegerKind::I16 => Self::SHORT,
+                IntegerKind::U16 => Self::UNSIGNED_SHORT,
+                IntegerKind::I32 => Self::INT,
+                IntegerKind::U32 => Self::UNSIGNED_INT,
+            },
+            AttributeKind::Packed2_10_10_10 => Self::INT_2_10_10_10_REV,
+        }
+    }
+}
+
+impl From<AttributeKind> for gl43::ShaderDataKind {
+    fn from(value: AttributeKind) -> Self {
+        const FLOATS: [gl43::ShaderDataKind; 4] = [
+            gl43::ShaderDataKind::FLOAT,
+            gl43::ShaderDataKind::FLOAT_VEC2,
+            gl43::ShaderDataKind::FLOAT_VEC3,
+            gl43::ShaderDataKind::FLOAT_VEC4,
+        ];
+        const INTS: [gl43::ShaderDataKind; 4] = [
+            gl43::ShaderDataKind::INT,
+            gl43::ShaderDataKind::INT_VEC2,
+            gl43::ShaderDataKind::INT_VEC3,
+            gl43::ShaderDataKind::INT_VEC4,
+        ];
+        const UINTS: [gl43::ShaderDataKind; 4] = [
+            gl43::ShaderDataKind::UNSIGNED_INT,
+            gl43::ShaderDataKind::UNSIGNED_INT_VEC2,
+            gl43::ShaderDataKind::UNSIGNED_INT_VEC3,
+            gl43::ShaderDataKind::UNSIGNED_INT_VEC4,
+        ];
+
+        let kinds = match value {
+            AttributeKind::Integer(kind, _) if kind.is_signed() => INTS,
+            AttributeKind::Integer(..) => UINTS,
+            _ => FLOATS,
+        };
+        kinds[usize::from(value.components().clamp(1, 4)) - 1]
+    }
+}
+
+impl From<MinFilter> for gl43::MinFilter {
+    fn from(value: MinFilter) -> Self {
+        match value {
+            MinFilter::Linear => Self::LINEAR,
+            MinFilter::Nearest => Self::NEAREST,
+            MinFilter::NearestMipmapNearest => Self::NEAREST_MIPMAP_NEAREST,
+            MinFilter::NearestMipmapLinear => Self::NEAREST_MIPMAP_LINEAR,
+            MinFilter::LinearMipmapLinear => Self::LINEAR_MIPMAP_LINEAR,
+            MinFilter::LinearMipmapNearest => Self::LINEAR_MIPMAP_NEAREST,
+        }
+    }
+}
+
+impl From<MagFilter> for gl43::MagFilter {
+    fn from(value: MagFilter) -> Self {
+        match value {
+            MagFilter::Linear => Self::LINEAR,
+            MagFilter::Nearest => Self::NEAREST,
+        }
+    }
+}
+
+impl From<Wrap> for gl43::WrapMode {
+    fn from(value: Wrap) -> Self {
+        match value {
+            Wrap::Repeat => Self::REPEAT,
+            Wrap::Clamp => Self::CLAMP_TO_EDGE,
+            Wrap::Mirrored => Self::MIRRORED_REPEAT,
+            Wrap::Border => Self::CLAMP_TO_BORDER,
         }
     }
 }
+
+impl From<Compare> for gl43::CompareFunc {
+    fn from(value: Compare) -> Self {
+        match value {
+            Compare::Never => Self::NEVER,
+            Compare::Less => Self::LESS,
+            Compare::Equal => Self::EQUAL,
+            Compare::LessOrEqual => Self::LEQUAL,
+            Compare::Greater => Self::GREATER,
+            Compare::NotEqual => Self::NOTEQUAL,
+            Compare::GreaterOrEqual => Self::GEQUAL,
+            Compare::Always => Self::ALWAYS,
+        }
+    }
+}
+
+impl From<Sampler> for Vec<gl43::SamplerParameter> {
+    fn from(value: Sampler) -> Self {
+        let mut parameters = vec![
+            gl43::SamplerParameter::MinFilter(value.min_filter.into()),
+            gl43::SamplerParameter::MagFilter(value.mag_filter.into()),
+            gl43::SamplerParameter::WrapS(value.wrap_u.into()),
+            gl43::SamplerParameter::WrapT(value.wrap_v.into()),
+            gl43::SamplerParameter::WrapR(value.wrap_w.into()),
+            gl43::SamplerParameter::MinLod(value.min_lod),
+            gl43::SamplerParameter::MaxLod(value.max_lod),
+            gl43::SamplerParameter::BorderColor(value.border_color),
+            gl43::SamplerParameter::Compare(value.compare.map(Into::into)),
+        ];
+        parameters.extend(value.anisotropy.map(gl43::SamplerParameter::MaxAnisotropy));
+        parameters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gl_bindings::gl43::{ProgramReflection, ShaderDataKind, Variable};
+
+    use super::{AttributeKind, ComponentKind, IntegerKind, LayoutMismatch, VertexLayout};
+
+    fn input(name: &str, kind: ShaderDataKind, location: Option<i32>) -> Variable {
+        Variable {
+            name: name.to_owned(),
+            kind,
+            array_size: 1,
+            location,
+        }
+    }
+
+    fn program(inputs: Vec<Variable>) -> ProgramReflection {
+        ProgramReflection {
+            inputs,
+            ..ProgramReflection::default()
+        }
+    }
+
+    #[test]
+    fn inputs_fed_by_attributes_of_their_kind_pass() {
+        let program = program(vec![
+            input("position", ShaderDataKind::FLOAT_VEC3, Some(0)),
+            input("uv", ShaderDataKind::FLOAT_VEC2, Some(1)),
+            // built-ins have no location and need no attribute
+            input("gl_VertexID", ShaderDataKind::INT, None),
+        ]);
+
+        assert!(VertexLayout::default().check_inputs(&program).is_ok());
+    }
+
+    #[test]
+    fn inputs_without_an_attribute_are_missing() {
+        let program = program(vec![input("normal", ShaderDataKind::FLOAT_VEC3, Some(2))]);
+
+        assert!(matches!(
+            VertexLayout::default().check_inputs(&program),
+            Err(LayoutMismatch::Missing(name)) if name == "normal"
+        ));
+    }
+
+    #[test]
+    fn integer_inputs_fed_by_float_attributes_mismatch() {
+        let program = program(vec![input("position", ShaderDataKind::INT_VEC3, Some(0))]);
+
+        assert!(matches!(
+            VertexLayout::default().check_inputs(&program),
+            Err(LayoutMismatch::Kind {
+                input,
+                expected: ShaderDataKind::INT_VEC3,
+                found: AttributeKind::Vec3,
+            }) if input == "position"
+        ));
+    }
+
+    #[test]
+    fn attribute_sizes_cover_all_components() {
+        assert_eq!(AttributeKind::Vec2.size(), 8);
+        assert_eq!(AttributeKind::Vec3.size(), 12);
+        assert_eq!(AttributeKind::Vec4.size(), 16);
+        assert_eq!(AttributeKind::Float(ComponentKind::U8, 4).size(), 4);
+        assert_eq!(AttributeKind::Float(ComponentKind::F16, 2).size(), 4);
+        assert_eq!(AttributeKind::Integer(IntegerKind::U16, 3).size(), 6);
+        assert_eq!(AttributeKind::Integer(IntegerKind::I32, 1).size(), 4);
+        // all four components share one 32 bit word
+        assert_eq!(AttributeKind::Packed2_10_10_10.size(), 4);
+    }
+
+    #[test]
+    fn attributes_feed_inputs_of_their_numeric_kind() {
+        let kind = ShaderDataKind::from;
+
+        assert_eq!(kind(AttributeKind::Vec3), ShaderDataKind::FLOAT_VEC3);
+        assert_eq!(
+            kind(AttributeKind::Float(ComponentKind::U8, 4)),
+            ShaderDataKind::FLOAT_VEC4
+        );
+        assert_eq!(
+            kind(AttributeKind::Float(ComponentKind::I16, 1)),
+            ShaderDataKind::FLOAT
+        );
+        assert_eq!(
+            kind(AttributeKind::Packed2_10_10_10),
+            ShaderDataKind::FLOAT_VEC4
+        );
+        assert_eq!(
+            kind(AttributeKind::Integer(IntegerKind::I8, 2)),
+            ShaderDataKind::INT_VEC2
+        );
+        assert_eq!(
+            kind(AttributeKind::Integer(IntegerKind::I32, 1)),
+            ShaderDataKind::INT
+        );
+        assert_eq!(
+            kind(AttributeKind::Integer(IntegerKind::U16, 4)),
+            ShaderDataKind::UNSIGNED_INT_VEC4
+        );
+        assert_eq!(
+            kind(AttributeKind::Integer(IntegerKind::U32, 3)),
+            ShaderDataKind::UNSIGNED_INT_VEC3
+        );
+    }
+}
@@ -16,8 +16,9 @@ pub struct UnsupportedAttribute(gltf::Semantic);
 use crate::renderer::gfx::{Primitive, VertexAttribute};
 
 use super::gfx::{
-    AttributeKind, AttributeSemantic, Image, ImageFormat, IndexKind, Indices, MagFilter, Material,
-    Mesh, MetallicRoughness, MinFilter, Sampler, Scene, Texture, TextureImage, VertexLayout, Wrap,
+    AttributeKind, AttributeSemantic, ComponentKind, Image, ImageFormat, IndexKind, Indices,
+    IntegerKind, MagFilter, Material, Mesh, MetallicRoughness, MinFilter, Sampler, Scene, Texture,
+    TextureImage, VertexLayout, Wrap,
 };
 
 pub struct Config {
@@ -48,7 +49,11 @@ fn load(
     buffers: Vec<gltf::buffer::Data>,
     images: Vec<gltf::image::Data>,
 ) -> Result<Scene, Error> {
-    //let images = images.into_iter().map(Into::into);
+    let mut images: Vec<Image> = images.into_iter().map(Into::into).collect();
+    for index in srgb_images(&document) {
+        images[index].srgb = true;
+    }
+
     let mut data = Vec::new();
 
     let meshes = process_meshes(
@@ -63,7 +68,7 @@ fn load(
 
     Ok(Scene {
         meshes: Some(meshes),
-        images: None,
+        images: Some(images),
         textures: None,
         materials: None,
         samplers: None,
@@ -71,6 +76,18 @@ fn load(
     })
 }
 
+/// The images used as base color or emissive textures, which glTF stores as sRGB.
+fn srgb_images(document: &gltf::Document) -> impl Iterator<Item = usize> + '_ {
+    document.materials().flat_map(|material| {
+        let base_color = material.pbr_metallic_roughness().base_color_texture();
+        let emissive = material.emissive_texture();
+        [base_color, emissive]
+            .into_iter()
+            .flatten()
+            .map(|info| info.texture().source().index())
+    })
+}
+
 //fn process_textures(&mut self, textures: gltf::iter::Textures) {
 //for texture in textures {
 //self.textures.push(TextureImage {
@@ -227,7 +244,13 @@ fn process_meshes(
                             gltf::accessor::Dimensions::Mat4 => 16,
                         };
 
-                        if attribute.data_type() != gltf::accessor::DataType::F32 {
+                        // copied as they are, converting would defeat quantized data
+                        if !stores(
+                            vertex_attribute.kind,
+                            vertex_attribute.normalized,
+                            attribute.data_type(),
+                            attribute.normalized(),
+                        ) {
                             return Err(Error::AttributeKindNotSupported);
                         }
 
@@ -295,6 +318,53 @@ fn process_meshes(
     Ok(meshes)
 }
 
+/// Whether the components of `kind` have the same layout as `data` and mean the same, integers
+/// the accessor marks as `normalized` only go into normalized float attributes and vice versa.
+const fn stores(
+    kind: AttributeKind,
+    normalized: bool,
+    data: gltf::accessor::DataType,
+    data_normalized: bool,
+) -> bool {
+    use gltf::accessor::DataType as E;
+
+    let same_meaning = match kind {
+        AttributeKind::Float(
+            ComponentKind::I8 | ComponentKind::U8 | ComponentKind::I16 | ComponentKind::U16,
+            _,
+        ) => normalized == data_normalized,
+        _ => !data_normalized,
+    };
+
+    same_meaning
+        && matches!(
+            (kind, data),
+            (
+                AttributeKind::Vec2
+                    | AttributeKind::Vec3
+                    | AttributeKind::Vec4
+                    | AttributeKind::Float(ComponentKind::F32, _),
+                E::F32
+            ) | (
+                AttributeKind::Float(ComponentKind::I8, _)
+                    | AttributeKind::Integer(IntegerKind::I8, _),
+                E::I8
+            ) | (
+                AttributeKind::Float(ComponentKind::U8, _)
+                    | AttributeKind::Integer(IntegerKind::U8, _),
+                E::U8
+            ) | (
+                AttributeKind::Float(ComponentKind::I16, _)
+                    | AttributeKind::Integer(IntegerKind::I16, _),
+                E::I16
+            ) | (
+                AttributeKind::Float(ComponentKind::U16, _)
+                    | AttributeKind::Integer(IntegerKind::U16, _),
+                E::U16
+            ) | (AttributeKind::Integer(IntegerKind::U32, _), E::U32)
+        )
+}
+
 impl From<(gltf::accessor::DataType, gltf::accessor::Dimensions)> for AttributeKind {
     fn from(value: (gltf::accessor::DataType, gltf::accessor::Dimensions)) -> Self {
         use gltf::accessor::DataType as E;
@@ -329,7 +399,10 @@ impl TryFrom<gltf::Semantic> for AttributeSemantic {
         use gltf::Semantic as E;
         match value {
             E::Positions => Ok(Self::Position),
+            E::Normals => Ok(Self::Normal),
             E::TexCoords(n) => Ok(Self::UV(n.try_into().unwrap_or(u8::MAX))),
+            E::Joints(n) => Ok(Self::Joints(n.try_into().unwrap_or(u8::MAX))),
+            E::Weights(n) => Ok(Self::Weights(n.try_into().unwrap_or(u8::MAX))),
             _ => Err(UnsupportedAttribute(value)),
         }
     }
@@ -341,6 +414,7 @@ impl From<gltf::image::Data> for Image {
             data: value.pixels,
             width: value.width,
             height: value.height,
+            srgb: false,
             format: match value.format {
                 gltf::image::Format::R8 => ImageFormat::R8,
                 gltf::image::Format::R8G8 => ImageFormat::Rg8,
@@ -356,3 +430,57 @@ impl From<gltf::image::Data> for Image {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gltf::accessor::DataType;
+
+    use super::stores;
+    use crate::renderer::gfx::{AttributeKind, ComponentKind, IntegerKind};
+
+    #[test]
+    fn floats_store_only_unnormalized_f32() {
+        assert!(stores(AttributeKind::Vec3, false, DataType::F32, false));
+        assert!(stores(
+            AttributeKind::Float(ComponentKind::F32, 1),
+            false,
+            DataType::F32,
+            false
+        ));
+        assert!(!stores(AttributeKind::Vec3, false, DataType::U16, false));
+        assert!(!stores(AttributeKind::Vec2, false, DataType::F32, true));
+    }
+
+    #[test]
+    fn quantized_floats_need_the_same_component_and_normalization() {
+        let u8_color = AttributeKind::Float(ComponentKind::U8, 4);
+
+        assert!(stores(u8_color, true, DataType::U8, true));
+        assert!(stores(u8_color, false, DataType::U8, false));
+        assert!(!stores(u8_color, true, DataType::U8, false));
+        assert!(!stores(u8_color, false, DataType::U8, true));
+        assert!(!stores(u8_color, true, DataType::I8, true));
+        assert!(!stores(u8_color, true, DataType::U16, true));
+    }
+
+    #[test]
+    fn integers_store_only_unnormalized_data_of_their_kind() {
+        let joints = AttributeKind::Integer(IntegerKind::U16, 4);
+
+        assert!(stores(joints, false, DataType::U16, false));
+        assert!(!stores(joints, false, DataType::U16, true));
+        assert!(!stores(joints, false, DataType::I16, false));
+        assert!(stores(
+            AttributeKind::Integer(IntegerKind::U32, 1),
+            false,
+            DataType::U32,
+            false
+        ));
+        assert!(!stores(
+            AttributeKind::Integer(IntegerKind::I32, 1),
+            false,
+            DataType::U32,
+            false
+        ));
+    }
+}
@@ -0,0 +1,184 @@
+use std::rc::Rc;
+
+use gl_bindings::{
+    gl43::{
+        self as gl, BufferTarget, DrawElementsIndirectCommand, ElementKind, GLsizei, Primitive,
+    },
+    gl45,
+};
+
+use super::{create_static_buffer, gfx::IndexKind, MeshView};
+
+/// The draw commands of all indexed meshes sharing a vertex array, so they are drawn with
+/// one `multi_draw_elements_indirect` per index kind instead of one call per mesh.
+pub struct IndirectDraws {
+    /// `None` without indexed meshes, OpenGL rejects buffers of size zero.
+    buffer: Option<gl::OwnedBuffer>,
+    batches: Vec<Batch>,
+}
+
+/// The consecutive commands of all meshes with the same index kind.
+struct Batch {
+    kind: ElementKind,
+    /// Byte offset of the first command.
+    offset: usize,
+    count: GLsizei,
+}
+
+impl IndirectDraws {
+    /// The index offsets of `meshes` have to be aligned to the size of their index kind.
+    pub fn new(gl: &Rc<gl::Api>, dsa: Option<&gl45::Api>, meshes: &[MeshView]) -> Self {
+        let mut commands = Vec::with_capacity(meshes.len());
+        let mut batches = Vec::new();
+
+        for kind in [IndexKind::U8, IndexKind::U16, IndexKind::U32] {
+            let first = commands.len();
+
+            for mesh in meshes {
+                let Some(indices) = mesh.indices.as_ref().filter(|i| i.kind == kind) else {
+                    continue;
+                };
+
+                commands.push(DrawElementsIndirectCommand {
+                    count: indices.count.try_into().unwrap(),
+                    instance_count: 1,
+                    first_index: (indices.offset / kind.size()).try_into().unwrap(),
+                    base_vertex: mesh.base_index.try_into().unwrap(),
+                    base_instance: 0,
+                });
+            }
+
+            if commands.len() > first {
+                batches.push(Batch {
+                    kind: kind.into(),
+                    offset: first * std::mem::size_of::<DrawElementsIndirectCommand>(),
+                    count: (commands.len() - first).try_into().unwrap(),
+                });
+            }
+        }
+
+        let buffer = (!batches.is_empty()).then(|| {
+            create_static_buffer(
+                gl,
+                dsa,
+                BufferTarget::DRAW_INDIRECT_BUFFER,
+                gl::as_bytes(&commands),
+                "mesh draw commands",
+            )
+        });
+
+        Self { buffer, batches }
+    }
+
+    /// Draws all meshes, expects their vertex array and program to be bound.
+    pub fn draw(&self, gl: &gl::Api) {
+        let Some(buffer) = &self.buffer else {
+            return;
+        };
+
+        unsafe {
+            gl.bind_buffer(BufferTarget::DRAW_INDIRECT_BUFFER, buffer.handle());
+
+            for batch in &self.batches {
+                gl.multi_draw_elements_indirect(
+                    Primitive::TRIANGLES,
+                    batch.kind,
+                    batch.offset as *const _,
+                    batch.count,
+                    0,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use gl_bindings::{gl43 as gl, mock};
+
+    use super::IndirectDraws;
+    use crate::renderer::{gfx::IndexKind, MeshIndices, MeshView};
+
+    const TRIANGLES: i64 = 0x0004;
+    const UNSIGNED_SHORT: i64 = 0x1403;
+    const UNSIGNED_INT: i64 = 0x1405;
+
+    fn api() -> Rc<gl::Api> {
+        mock::reset();
+        Rc::new(unsafe { gl::Api::with_loader(&mock::loader) })
+    }
+
+    fn mesh(base_index: usize, indices: Option<(IndexKind, usize, usize)>) -> MeshView {
+        MeshView {
+            vertices: 3,
+            vertex_offset: 0,
+            indices: indices.map(|(kind, count, offset)| MeshIndices {
+                count,
+                offset,
+                kind,
+            }),
+            base_index,
+        }
+    }
+
+    fn calls_named(name: &str) -> Vec<mock::Call> {
+        mock::calls()
+            .into_iter()
+            .filter(|call| call.name == name)
+            .collect()
+    }
+
+    #[test]
+    fn one_draw_per_index_kind() {
+        let gl = api();
+        let meshes = [
+            mesh(0, Some((IndexKind::U16, 6, 0))),
+            mesh(4, Some((IndexKind::U32, 3, 12))),
+            mesh(7, None),
+            mesh(10, Some((IndexKind::U16, 9, 24))),
+        ];
+
+        let draws = IndirectDraws::new(&gl, None, &meshes);
+        // three commands of 20 bytes each
+        assert_eq!(calls_named("glBufferData")[0].args[1], mock::Arg::Int(60));
+
+        draws.draw(&gl);
+
+        // the second batch starts after the two u16 commands
+        let draws = calls_named("glMultiDrawElementsIndirect");
+        let batches: Vec<_> = draws.iter().map(|call| &call.args[..4]).collect();
+        assert_eq!(
+            batches,
+            [
+                [
+                    mock::Arg::Int(TRIANGLES),
+                    mock::Arg::Int(UNSIGNED_SHORT),
+                    mock::Arg::Pointer(std::ptr::null()),
+                    mock::Arg::Int(2),
+                ],
+                [
+                    mock::Arg::Int(TRIANGLES),
+                    mock::Arg::Int(UNSIGNED_INT),
+                    mock::Arg::Pointer(40 as *const _),
+                    mock::Arg::Int(1),
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn meshes_without_indices_need_no_buffer() {
+        let gl = api();
+
+        let draws = IndirectDraws::new(&gl, None, &[mesh(0, None), mesh(3, None)]);
+        draws.draw(&gl);
+
+        let calls = mock::calls();
+        assert!(!calls.iter().any(|call| call.name == "glGenBuffers"));
+        assert!(!calls
+            .iter()
+            .any(|call| call.name == "glMultiDrawElementsIndirect"));
+    }
+}
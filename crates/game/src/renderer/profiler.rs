@@ -0,0 +1,210 @@
+use std::{ptr::addr_of_mut, rc::Rc, time::Duration};
+
+use gl_bindings::gl43::{self as gl, GLuint64, OwnedQuery, QueryCounterTarget, QueryParameter};
+
+/// Frames whose queries may be in flight, results arrive this many frames late.
+const FRAMES: usize = 2;
+
+/// Measures how long named scopes take on the GPU with timestamp queries. Never waits for
+/// the GPU, a frame whose results aren't available yet is skipped instead.
+pub struct GpuProfiler {
+    frames: [Frame; FRAMES],
+    frame: usize,
+    /// Whether the scopes of the current frame are measured.
+    recording: bool,
+    timings: Vec<ScopeTiming>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ScopeTiming {
+    pub name: &'static str,
+    pub duration: Duration,
+}
+
+#[derive(Default)]
+struct Frame {
+    /// Reused from frame to frame, only grows.
+    queries: Vec<OwnedQuery>,
+    used: usize,
+    scopes: Vec<PendingScope>,
+}
+
+struct PendingScope {
+    name: &'static str,
+    begin: usize,
+    end: usize,
+}
+
+impl Frame {
+    fn timestamp(&mut self, gl: &Rc<gl::Api>) -> usize {
+        if self.used == self.queries.len() {
+            self.queries.push(unsafe { OwnedQuery::new(gl) });
+        }
+
+        let index = self.used;
+        self.used += 1;
+        unsafe { gl.query_counter(self.queries[index].handle(), QueryCounterTarget::TIMESTAMP) };
+        index
+    }
+
+    fn result(&self, gl: &gl::Api, index: usize, pname: QueryParameter) -> GLuint64 {
+        let mut value = 0;
+        unsafe {
+            gl.get_query_object_ui64v(self.queries[index].handle(), pname, addr_of_mut!(value));
+        }
+        value
+    }
+
+    /// Whether the GPU finished all queries, they complete in order.
+    fn is_available(&self, gl: &gl::Api) -> bool {
+        self.used == 0
+            || self.result(gl, self.used - 1, QueryParameter::QUERY_RESULT_AVAILABLE) != 0
+    }
+}
+
+impl GpuProfiler {
+    pub fn new() -> Self {
+        Self {
+            frames: Default::default(),
+            frame: 0,
+            recording: false,
+            timings: Vec::new(),
+        }
+    }
+
+    /// Collects the results of the frame recorded `FRAMES` frames ago if they are available
+    /// and starts recording the current one.
+    pub fn begin_frame(&mut self, gl: &gl::Api) {
+        self.frame = (self.frame + 1) % FRAMES;
+        let frame = &mut self.frames[self.frame];
+
+        self.recording = frame.is_available(gl);
+        if !self.recording {
+            return;
+        }
+
+        if !frame.scopes.is_empty() {
+            self.timings.clear();
+            self.timings.extend(frame.scopes.iter().map(|scope| {
+                let begin = frame.result(gl, scope.begin, QueryParameter::QUERY_RESULT);
+                let end = frame.result(gl, scope.end, QueryParameter::QUERY_RESULT);
+                ScopeTiming {
+                    name: scope.name,
+                    duration: Duration::from_nanos(end.saturating_sub(begin)),
+                }
+            }));
+        }
+
+        frame.used = 0;
+        frame.scopes.clear();
+    }
+
+    /// Measures the GPU time of the commands issued until the returned scope is dropped.
+    pub fn scope<'a>(&'a mut self, gl: &'a Rc<gl::Api>, name: &'static str) -> GpuScope<'a> {
+        let scope = self.recording.then(|| {
+            let frame = &mut self.frames[self.frame];
+            let begin = frame.timestamp(gl);
+            frame.scopes.push(PendingScope {
+                name,
+                begin,
+                end: begin,
+            });
+            frame.scopes.len() - 1
+        });
+
+        GpuScope {
+            profiler: self,
+            gl,
+            scope,
+        }
+    }
+
+    /// The timings of the latest frame whose results arrived, in the order the scopes began.
+    pub fn timings(&self) -> &[ScopeTiming] {
+        &self.timings
+    }
+}
+
+/// A scope of `GpuProfiler::scope`, records the end timestamp when dropped.
+#[must_use = "the scope ends when dropped"]
+pub struct GpuScope<'a> {
+    profiler: &'a mut GpuProfiler,
+    gl: &'a Rc<gl::Api>,
+    /// `None` if the frame isn't recorded.
+    scope: Option<usize>,
+}
+
+impl Drop for GpuScope<'_> {
+    fn drop(&mut self) {
+        let Some(scope) = self.scope else {
+            return;
+        };
+
+        let frame = &mut self.profiler.frames[self.profiler.frame];
+        let end = frame.timestamp(self.gl);
+        frame.scopes[scope].end = end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{rc::Rc, time::Duration};
+
+    use gl_bindings::{gl43 as gl, mock};
+
+    use super::GpuProfiler;
+
+    const QUERY_RESULT: u32 = 0x8866;
+    const QUERY_RESULT_AVAILABLE: u32 = 0x8867;
+
+    fn count(calls: &[mock::Call], name: &str) -> usize {
+        calls.iter().filter(|call| call.name == name).count()
+    }
+
+    fn names(profiler: &GpuProfiler) -> Vec<&'static str> {
+        profiler
+            .timings()
+            .iter()
+            .map(|timing| timing.name)
+            .collect()
+    }
+
+    fn record(profiler: &mut GpuProfiler, gl: &Rc<gl::Api>, scopes: &[&'static str]) {
+        profiler.begin_frame(gl);
+        for &name in scopes {
+            let _scope = profiler.scope(gl, name);
+        }
+    }
+
+    #[test]
+    fn frames_without_results_are_skipped_and_queries_reused() {
+        mock::reset();
+        let gl = Rc::new(unsafe { gl::Api::with_loader(&mock::loader) });
+        let mut profiler = GpuProfiler::new();
+
+        record(&mut profiler, &gl, &["shadows", "scene"]);
+        record(&mut profiler, &gl, &["scene"]);
+        let calls = mock::take_calls();
+        assert_eq!(count(&calls, "glGenQueries"), 6);
+        assert_eq!(count(&calls, "glQueryCounter"), 6);
+
+        // the first frame is still in flight, nothing is measured until it arrives
+        record(&mut profiler, &gl, &["shadows", "scene"]);
+        let calls = mock::take_calls();
+        assert_eq!(count(&calls, "glQueryCounter"), 0);
+        assert_eq!(count(&calls, "glGetQueryObjectui64v"), 1);
+        assert!(profiler.timings().is_empty());
+
+        mock::set_integer(QUERY_RESULT_AVAILABLE, 1);
+        mock::set_integer(QUERY_RESULT, 1000);
+        record(&mut profiler, &gl, &["scene"]);
+        assert_eq!(names(&profiler), ["scene"]);
+        assert_eq!(profiler.timings()[0].duration, Duration::ZERO);
+
+        record(&mut profiler, &gl, &["shadows", "scene"]);
+        assert_eq!(names(&profiler), ["shadows", "scene"]);
+        let calls = mock::take_calls();
+        assert_eq!(count(&calls, "glGenQueries"), 0);
+        assert_eq!(count(&calls, "glQueryCounter"), 6);
+    }
+}
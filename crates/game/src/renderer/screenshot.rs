@@ -0,0 +1,103 @@
+use std::{path::PathBuf, rc::Rc};
+
+use gl_bindings::gl43::{self as gl, ColorBuffer, PixelReadback};
+
+use super::target::RenderTarget;
+
+/// Captures frames of a render target to PNG files. The pixels are read back without stalling
+/// and encoded on a separate thread, so a screenshot doesn't cause a hitch.
+pub struct Screenshots {
+    requested: Vec<PathBuf>,
+    pending: Vec<(PathBuf, PixelReadback)>,
+}
+
+impl Screenshots {
+    pub const fn new() -> Self {
+        Self {
+            requested: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Saves the next frame to `path` once it is rendered.
+    pub fn request(&mut self, path: PathBuf) {
+        self.requested.push(path);
+    }
+
+    /// Starts reading back the frame in `target` for each request and saves the captures
+    /// the GPU finished. Call after the frame is rendered.
+    pub fn update(&mut self, gl: &Rc<gl::Api>, target: &RenderTarget) {
+        for path in self.requested.drain(..) {
+            let readback = target.read_back(gl, ColorBuffer::COLOR_ATTACHMENT0);
+            self.pending.push((path, readback));
+        }
+
+        let mut index = 0;
+        while index < self.pending.len() {
+            if !unsafe { self.pending[index].1.is_ready() } {
+                index += 1;
+                continue;
+            }
+
+            let (path, readback) = self.pending.swap_remove(index);
+            let (width, height) = readback.dimensions();
+            let pixels = unsafe { readback.finish() };
+
+            std::thread::spawn(move || match save_png(&path, width, height, pixels) {
+                Ok(()) => log::info!("saved screenshot to {}", path.display()),
+                Err(error) => log::error!("failed to save {}: {error}", path.display()),
+            });
+        }
+    }
+}
+
+/// Writes RGBA8 pixels with rows bottom to top, as OpenGL reads them, to a PNG file.
+pub fn save_png(
+    path: &std::path::Path,
+    width: gl::GLsizei,
+    height: gl::GLsizei,
+    pixels: Vec<u8>,
+) -> anyhow::Result<()> {
+    top_down(width, height, pixels)?.save(path)?;
+    Ok(())
+}
+
+/// Turns RGBA8 pixels with rows bottom to top into an image with rows top to bottom.
+fn top_down(
+    width: gl::GLsizei,
+    height: gl::GLsizei,
+    pixels: Vec<u8>,
+) -> anyhow::Result<image::RgbaImage> {
+    let image = image::RgbaImage::from_raw(width.try_into()?, height.try_into()?, pixels)
+        .ok_or_else(|| anyhow::anyhow!("pixels don't match the size {width}x{height}"))?;
+    Ok(image::imageops::flip_vertical(&image))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{save_png, top_down};
+
+    #[test]
+    fn rows_are_flipped_to_top_down() {
+        // 2x3, each row filled with its index as OpenGL returns it, bottom row first
+        let pixels: Vec<u8> = (0..3).flat_map(|row| [row; 8]).collect();
+
+        let image = top_down(2, 3, pixels).unwrap();
+
+        assert_eq!(image.dimensions(), (2, 3));
+        let rows: Vec<u8> = image
+            .rows()
+            .map(|mut row| row.next().unwrap().0[0])
+            .collect();
+        assert_eq!(rows, [2, 1, 0]);
+    }
+
+    #[test]
+    fn pixels_of_another_size_are_an_error() {
+        let path = std::env::temp_dir().join("screenshot_of_the_wrong_size.png");
+
+        assert!(save_png(&path, 4, 4, vec![0; 4 * 3 * 4]).is_err());
+        assert!(top_down(-1, 4, Vec::new()).is_err());
+        assert!(!path.exists());
+    }
+}
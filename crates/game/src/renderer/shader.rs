@@ -0,0 +1,375 @@
+use std::{ptr::addr_of_mut, rc::Rc};
+
+use gl_bindings::gl43::{
+    self as gl, GLchar, GLint, GLsizei, OwnedProgram, ProgramParameterName, Shader, ShaderKind,
+    ShaderParameterName,
+};
+
+/// Compiles shader stages and links them into a program, reporting the info logs of the
+/// driver instead of handing out a program that silently renders nothing. Takes any mix of
+/// stages the linker accepts, e.g. vertex, tessellation, geometry and fragment or a lone compute.
+pub struct ProgramBuilder<'a> {
+    gl: &'a Rc<gl::Api>,
+    stages: Vec<(ShaderKind, &'a str)>,
+}
+
+#[derive(Debug)]
+pub enum ShaderError {
+    Compile {
+        stage: ShaderKind,
+        log: String,
+        /// The source lines the log complains about, if the log format was recognized.
+        lines: Vec<SourceLine>,
+    },
+    Link {
+        log: String,
+    },
+    /// The stages can't be linked together, checked before compiling anything.
+    Stages(&'static str),
+}
+
+/// A line of a shader source together with the log message about it.
+#[derive(Debug)]
+pub struct SourceLine {
+    pub number: usize,
+    pub source: String,
+    pub message: String,
+}
+
+impl std::error::Error for ShaderError {}
+
+impl std::fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Compile { stage, log, lines } => {
+                writeln!(f, "failed to compile {stage} shader:")?;
+
+                if lines.is_empty() {
+                    return write!(f, "{log}");
+                }
+
+                for line in lines {
+                    writeln!(f, "{:>4} | {}", line.number, line.source)?;
+                    writeln!(f, "     = {}", line.message)?;
+                }
+                Ok(())
+            }
+            Self::Link { log } => write!(f, "failed to link program:\n{log}"),
+            Self::Stages(reason) => write!(f, "invalid shader stages: {reason}"),
+        }
+    }
+}
+
+impl<'a> ProgramBuilder<'a> {
+    pub const fn new(gl: &'a Rc<gl::Api>) -> Self {
+        Self {
+            gl,
+            stages: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn stage(mut self, kind: ShaderKind, source: &'a str) -> Self {
+        self.stages.push((kind, source));
+        self
+    }
+
+    pub fn build(self) -> Result<OwnedProgram, ShaderError> {
+        let gl = self.gl;
+        check_stages(&self.stages)?;
+        let mut shaders = Vec::with_capacity(self.stages.len());
+
+        for (kind, source) in self.stages {
+            match compile(gl, kind, source) {
+                Ok(shader) => shaders.push(shader),
+                Err(error) => {
+                    for shader in shaders {
+                        unsafe { gl.delete_shader(shader) };
+                    }
+                    return Err(error);
+                }
+            }
+        }
+
+        let mut status = 0;
+        let program = unsafe {
+            let program = OwnedProgram::new(gl);
+
+            for &shader in &shaders {
+                gl.attach_shader(program.handle(), shader);
+            }
+            gl.link_program(program.handle());
+            for shader in shaders {
+                gl.detach_shader(program.handle(), shader);
+                gl.delete_shader(shader);
+            }
+
+            gl.get_program_iv(
+                program.handle(),
+                ProgramParameterName::LINK_STATUS,
+                addr_of_mut!(status),
+            );
+            program
+        };
+
+        if status == 0 {
+            let log = unsafe {
+                let mut length = 0;
+                gl.get_program_iv(
+                    program.handle(),
+                    ProgramParameterName::INFO_LOG_LENGTH,
+                    addr_of_mut!(length),
+                );
+                info_log(length, |size, written, log| {
+                    gl.get_program_info_log(program.handle(), size, written, log);
+                })
+            };
+            return Err(ShaderError::Link { log });
+        }
+
+        Ok(program)
+    }
+}
+
+/// Catches the combinations every linker rejects, with a clearer message than the driver logs.
+fn check_stages(stages: &[(ShaderKind, &str)]) -> Result<(), ShaderError> {
+    let has = |kind| stages.iter().any(|&(stage, _)| stage == kind);
+
+    if stages.is_empty() {
+        return Err(ShaderError::Stages("no stages"));
+    }
+    if has(ShaderKind::COMPUTE) {
+        return if stages
+            .iter()
+            .all(|&(stage, _)| stage == ShaderKind::COMPUTE)
+        {
+            Ok(())
+        } else {
+            Err(ShaderError::Stages(
+                "compute can't be mixed with other stages",
+            ))
+        };
+    }
+    if !has(ShaderKind::VERTEX) {
+        return Err(ShaderError::Stages("missing a vertex stage"));
+    }
+    if has(ShaderKind::TESS_CONTROL) && !has(ShaderKind::TESS_EVALUATION) {
+        return Err(ShaderError::Stages(
+            "tessellation control needs a tessellation evaluation stage",
+        ));
+    }
+    Ok(())
+}
+
+fn compile(gl: &gl::Api, kind: ShaderKind, source: &str) -> Result<Shader, ShaderError> {
+    let mut status = 0;
+    let shader = unsafe {
+        let shader = gl.create_shader(kind);
+        gl.shader_source_str(shader, source);
+        gl.compile_shader(shader);
+        gl.get_shader_iv(
+            shader,
+            ShaderParameterName::COMPILE_STATUS,
+            addr_of_mut!(status),
+        );
+        shader
+    };
+
+    if status != 0 {
+        return Ok(shader);
+    }
+
+    let log = unsafe {
+        let mut length = 0;
+        gl.get_shader_iv(
+            shader,
+            ShaderParameterName::INFO_LOG_LENGTH,
+            addr_of_mut!(length),
+        );
+        let log = info_log(length, |size, written, log| {
+            gl.get_shader_info_log(shader, size, written, log);
+        });
+        gl.delete_shader(shader);
+        log
+    };
+
+    Err(ShaderError::Compile {
+        stage: kind,
+        lines: annotate(&log, source),
+        log,
+    })
+}
+
+/// Reads an info log of `length` bytes, including the null terminator, with `read`.
+fn info_log(length: GLint, read: impl FnOnce(GLsizei, *mut GLsizei, *mut GLchar)) -> String {
+    let mut log = vec![0u8; usize::try_from(length).unwrap_or_default()];
+    let mut written = 0;
+    read(length, addr_of_mut!(written), log.as_mut_ptr().cast());
+    log.truncate(usize::try_from(written).unwrap_or_default());
+    String::from_utf8_lossy(&log).trim_end().to_owned()
+}
+
+/// Pairs each message of the log with the source line it refers to.
+fn annotate(log: &str, source: &str) -> Vec<SourceLine> {
+    log.lines()
+        .filter_map(|message| {
+            let number = line_number(message)?;
+            let source = source.lines().nth(number.checked_sub(1)?)?;
+
+            Some(SourceLine {
+                number,
+                source: source.to_owned(),
+                message: message.trim().to_owned(),
+            })
+        })
+        .collect()
+}
+
+/// The line number of a log message, the drivers prefix them with one of
+/// `0(12) :` (Nvidia), `0:12(5):` (Mesa) or `ERROR: 0:12:` (AMD, Intel).
+fn line_number(message: &str) -> Option<usize> {
+    let message = message
+        .trim_start_matches(|c: char| c.is_ascii_alphabetic())
+        .trim_start_matches(':')
+        .trim_start();
+    // skip the index of the source string
+    let rest = message.strip_prefix(|c: char| c.is_ascii_digit())?;
+    let rest = rest.trim_start_matches(|c: char| c.is_ascii_digit());
+    let rest = rest.strip_prefix(['(', ':'])?;
+    let end = rest.find(|c: char| !c.is_ascii_digit())?;
+
+    rest[..end].parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use gl_bindings::{gl43 as gl, mock};
+
+    use super::{annotate, line_number, ProgramBuilder, ShaderError, ShaderKind};
+
+    /// `COMPILE_STATUS` and `LINK_STATUS`, the mock answers `glGet*iv` by raw name.
+    const COMPILE_STATUS: gl::GLenum = 0x8B81;
+    const LINK_STATUS: gl::GLenum = 0x8B82;
+
+    fn api() -> Rc<gl::Api> {
+        mock::reset();
+        Rc::new(unsafe { gl::Api::with_loader(&mock::loader) })
+    }
+
+    #[test]
+    fn builds_when_the_stages_compile() {
+        let gl = api();
+        let program = ProgramBuilder::new(&gl)
+            .stage(ShaderKind::VERTEX, "void main() {}")
+            .stage(ShaderKind::FRAGMENT, "void main() {}")
+            .build();
+
+        assert!(program.is_ok());
+        let names: Vec<_> = mock::calls().iter().map(|call| call.name).collect();
+        assert_eq!(
+            names
+                .iter()
+                .filter(|&&name| name == "glCompileShader")
+                .count(),
+            2
+        );
+        assert!(names.contains(&"glLinkProgram"));
+    }
+
+    #[test]
+    fn fails_when_a_stage_doesnt_compile() {
+        let gl = api();
+        mock::set_integer(COMPILE_STATUS, 0);
+
+        let error = ProgramBuilder::new(&gl)
+            .stage(ShaderKind::VERTEX, "void main() {")
+            .stage(ShaderKind::FRAGMENT, "void main() {}")
+            .build()
+            .err();
+
+        assert!(matches!(
+            error,
+            Some(ShaderError::Compile {
+                stage: ShaderKind::VERTEX,
+                ..
+            })
+        ));
+        // the failed shader is deleted and nothing is linked
+        let names: Vec<_> = mock::calls().iter().map(|call| call.name).collect();
+        assert!(names.contains(&"glDeleteShader"));
+        assert!(!names.contains(&"glLinkProgram"));
+    }
+
+    #[test]
+    fn fails_when_the_program_doesnt_link() {
+        let gl = api();
+        mock::set_integer(LINK_STATUS, 0);
+
+        let error = ProgramBuilder::new(&gl)
+            .stage(ShaderKind::COMPUTE, "void main() {}")
+            .build()
+            .err();
+
+        assert!(matches!(error, Some(ShaderError::Link { .. })));
+    }
+
+    #[test]
+    fn rejects_invalid_stage_combinations_before_compiling() {
+        let gl = api();
+
+        let error = ProgramBuilder::new(&gl)
+            .stage(ShaderKind::VERTEX, "void main() {}")
+            .stage(ShaderKind::COMPUTE, "void main() {}")
+            .build()
+            .err();
+
+        assert!(matches!(error, Some(ShaderError::Stages(_))));
+        assert!(mock::calls().is_empty());
+    }
+
+    #[test]
+    fn line_numbers_are_found_in_every_driver_format() {
+        for (message, expected) in [
+            ("0:12(5): error: `foo' undeclared", Some(12)),
+            ("0(12) : error C1008: undefined variable \"foo\"", Some(12)),
+            ("ERROR: 0:12: 'foo' : undeclared identifier", Some(12)),
+            ("WARNING: 0:3: extension not supported", Some(3)),
+            ("ERROR: 1 compilation errors.  No code generated.", None),
+            ("error: linking failed", None),
+            ("", None),
+        ] {
+            assert_eq!(line_number(message), expected, "{message:?}");
+        }
+    }
+
+    #[test]
+    fn annotations_pair_messages_with_their_source_line() {
+        let source = "#version 430\nvoid main() {\n    foo = 1;\n}";
+
+        for message in [
+            "0:3(5): error: `foo' undeclared",
+            "0(3) : error C1008: undefined variable \"foo\"",
+            "ERROR: 0:3: 'foo' : undeclared identifier",
+        ] {
+            let lines = annotate(message, source);
+
+            assert_eq!(lines.len(), 1, "{message:?}");
+            assert_eq!(lines[0].number, 3);
+            assert_eq!(lines[0].source, "    foo = 1;");
+            assert_eq!(lines[0].message, message);
+        }
+    }
+
+    #[test]
+    fn annotations_outside_of_the_source_are_dropped() {
+        let source = "#version 430\nvoid main() {}";
+        let log = "0:9(1): error: past the end
+0(5) : error C0000: past the end
+ERROR: 0:3: past the end
+0:0(1): error: before the first line";
+
+        assert!(annotate(log, source).is_empty());
+    }
+}
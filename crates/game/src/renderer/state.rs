@@ -0,0 +1,432 @@
+use gl_bindings::gl43::{
+    self as gl, BlendEquation, BlendFactor, Capability, DepthFunc, Face, FrontFace, GLint, GLuint,
+    PolygonMode, StencilFunc, StencilOp,
+};
+
+/// The fixed function state a draw expects, applied through a `StateTracker`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RenderState {
+    /// `None` disables the depth test.
+    pub depth_test: Option<DepthFunc>,
+    pub depth_write: bool,
+    /// The faces to discard, `None` disables culling.
+    pub cull: Option<Face>,
+    pub front_face: FrontFace,
+    pub blend: Option<Blend>,
+    pub stencil: Option<Stencil>,
+    /// `[x, y, width, height]` in window coordinates, `None` disables the scissor test.
+    pub scissor: Option<[GLint; 4]>,
+    pub polygon_mode: PolygonMode,
+    /// Which of the red, green, blue and alpha channels are written.
+    pub color_mask: [bool; 4],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Blend {
+    pub source: BlendFactor,
+    pub destination: BlendFactor,
+    pub equation: BlendEquation,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stencil {
+    pub func: StencilFunc,
+    pub reference: GLint,
+    /// Applied to both the reference and the stored value before comparing.
+    pub read_mask: GLuint,
+    pub write_mask: GLuint,
+    pub fail: StencilOp,
+    pub depth_fail: StencilOp,
+    pub pass: StencilOp,
+}
+
+impl RenderState {
+    /// The state of a fresh context.
+    pub const DEFAULT: Self = Self {
+        depth_test: None,
+        depth_write: true,
+        cull: None,
+        front_face: FrontFace::CCW,
+        blend: None,
+        stencil: None,
+        scissor: None,
+        polygon_mode: PolygonMode::FILL,
+        color_mask: [true; 4],
+    };
+
+    /// Depth tested geometry without blending. Doesn't cull, the winding of the built-in cube
+    /// and of the glTF meshes don't agree yet.
+    pub const OPAQUE: Self = Self {
+        depth_test: Some(DepthFunc::LEQUAL),
+        ..Self::DEFAULT
+    };
+
+    /// Blends colors premultiplied with their alpha over what was drawn before.
+    pub const PREMULTIPLIED_ALPHA: Self = Self {
+        depth_test: Some(DepthFunc::LEQUAL),
+        blend: Some(Blend {
+            source: BlendFactor::ONE,
+            destination: BlendFactor::ONE_MINUS_SRC_ALPHA,
+            equation: BlendEquation::ADD,
+        }),
+        ..Self::DEFAULT
+    };
+}
+
+impl Default for RenderState {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// How many state changing calls the tracker issued and how many it skipped because the
+/// context already had the requested value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StateCounters {
+    pub issued: usize,
+    pub skipped: usize,
+}
+
+/// The individual pieces of context state, one per call that sets it.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ContextState {
+    depth_test: bool,
+    depth_func: DepthFunc,
+    depth_mask: bool,
+    cull_face: bool,
+    cull_mode: Face,
+    front_face: FrontFace,
+    blend: bool,
+    blend_func: (BlendFactor, BlendFactor),
+    blend_equation: BlendEquation,
+    stencil_test: bool,
+    stencil_func: (StencilFunc, GLint, GLuint),
+    stencil_op: (StencilOp, StencilOp, StencilOp),
+    stencil_mask: GLuint,
+    scissor_test: bool,
+    scissor: [GLint; 4],
+    polygon_mode: PolygonMode,
+    color_mask: [bool; 4],
+}
+
+impl ContextState {
+    /// The initial values of the specification.
+    const INITIAL: Self = Self {
+        depth_test: false,
+        depth_func: DepthFunc::LESS,
+        depth_mask: true,
+        cull_face: false,
+        cull_mode: Face::BACK,
+        front_face: FrontFace::CCW,
+        blend: false,
+        blend_func: (BlendFactor::ONE, BlendFactor::ZERO),
+        blend_equation: BlendEquation::ADD,
+        stencil_test: false,
+        stencil_func: (StencilFunc::ALWAYS, 0, GLuint::MAX),
+        stencil_op: (StencilOp::KEEP, StencilOp::KEEP, StencilOp::KEEP),
+        stencil_mask: GLuint::MAX,
+        scissor_test: false,
+        // actually the window size, but the test is off anyway
+        scissor: [0; 4],
+        polygon_mode: PolygonMode::FILL,
+        color_mask: [true; 4],
+    };
+}
+
+/// Mirrors the context state to issue only the calls that change it. Assumes that nothing
+/// else touches the tracked state.
+pub struct StateTracker {
+    current: ContextState,
+    counters: StateCounters,
+}
+
+impl StateTracker {
+    /// Expects the context to still have its initial state.
+    pub const fn new() -> Self {
+        Self {
+            current: ContextState::INITIAL,
+            counters: StateCounters {
+                issued: 0,
+                skipped: 0,
+            },
+        }
+    }
+
+    /// Returns the counters since the last call and starts counting from zero.
+    pub fn take_counters(&mut self) -> StateCounters {
+        std::mem::take(&mut self.counters)
+    }
+
+    pub fn apply(&mut self, gl: &gl::Api, state: &RenderState) {
+        let blend = state.blend.unwrap_or(Blend {
+            source: self.current.blend_func.0,
+            destination: self.current.blend_func.1,
+            equation: self.current.blend_equation,
+        });
+        let stencil = state.stencil.unwrap_or(Stencil {
+            func: self.current.stencil_func.0,
+            reference: self.current.stencil_func.1,
+            read_mask: self.current.stencil_func.2,
+            write_mask: self.current.stencil_mask,
+            fail: self.current.stencil_op.0,
+            depth_fail: self.current.stencil_op.1,
+            pass: self.current.stencil_op.2,
+        });
+
+        // unused parts keep their current value, so switching back is free
+        let target = ContextState {
+            depth_test: state.depth_test.is_some(),
+            depth_func: state.depth_test.unwrap_or(self.current.depth_func),
+            depth_mask: state.depth_write,
+            cull_face: state.cull.is_some(),
+            cull_mode: state.cull.unwrap_or(self.current.cull_mode),
+            front_face: state.front_face,
+            blend: state.blend.is_some(),
+            blend_func: (blend.source, blend.destination),
+            blend_equation: blend.equation,
+            stencil_test: state.stencil.is_some(),
+            stencil_func: (stencil.func, stencil.reference, stencil.read_mask),
+            stencil_op: (stencil.fail, stencil.depth_fail, stencil.pass),
+            stencil_mask: stencil.write_mask,
+            scissor_test: state.scissor.is_some(),
+            scissor: state.scissor.unwrap_or(self.current.scissor),
+            polygon_mode: state.polygon_mode,
+            color_mask: state.color_mask,
+        };
+
+        self.transition(gl, target);
+    }
+
+    // one flat update per piece of state reads better than splitting them up
+    #[allow(clippy::too_many_lines)]
+    fn transition(&mut self, gl: &gl::Api, target: ContextState) {
+        let current = &mut self.current;
+        let counters = &mut self.counters;
+
+        unsafe {
+            update(counters, &mut current.depth_test, target.depth_test, |on| {
+                toggle(gl, Capability::DEPTH, on);
+            });
+            update(
+                counters,
+                &mut current.depth_func,
+                target.depth_func,
+                |func| {
+                    gl.depth_func(func);
+                },
+            );
+            update(counters, &mut current.depth_mask, target.depth_mask, |on| {
+                gl.depth_mask(on.into());
+            });
+            update(counters, &mut current.cull_face, target.cull_face, |on| {
+                toggle(gl, Capability::CULL_FACE, on);
+            });
+            update(counters, &mut current.cull_mode, target.cull_mode, |face| {
+                gl.cull_face(face);
+            });
+            update(
+                counters,
+                &mut current.front_face,
+                target.front_face,
+                |face| {
+                    gl.front_face(face);
+                },
+            );
+            update(counters, &mut current.blend, target.blend, |on| {
+                toggle(gl, Capability::BLEND, on);
+            });
+            update(
+                counters,
+                &mut current.blend_func,
+                target.blend_func,
+                |(s, d)| {
+                    gl.blend_func(s, d);
+                },
+            );
+            update(
+                counters,
+                &mut current.blend_equation,
+                target.blend_equation,
+                |e| {
+                    gl.blend_equation(e);
+                },
+            );
+            update(
+                counters,
+                &mut current.stencil_test,
+                target.stencil_test,
+                |on| {
+                    toggle(gl, Capability::STENCIL, on);
+                },
+            );
+            update(
+                counters,
+                &mut current.stencil_func,
+                target.stencil_func,
+                |(f, r, m)| {
+                    gl.stencil_func(f, r, m);
+                },
+            );
+            update(
+                counters,
+                &mut current.stencil_op,
+                target.stencil_op,
+                |(s, d, p)| {
+                    gl.stencil_op(s, d, p);
+                },
+            );
+            update(
+                counters,
+                &mut current.stencil_mask,
+                target.stencil_mask,
+                |mask| {
+                    gl.stencil_mask(mask);
+                },
+            );
+            update(
+                counters,
+                &mut current.scissor_test,
+                target.scissor_test,
+                |on| {
+                    toggle(gl, Capability::SCISSOR, on);
+                },
+            );
+            update(
+                counters,
+                &mut current.scissor,
+                target.scissor,
+                |[x, y, w, h]| {
+                    gl.scissor(x, y, w, h);
+                },
+            );
+            update(
+                counters,
+                &mut current.polygon_mode,
+                target.polygon_mode,
+                |mode| {
+                    gl.polygon_mode(Face::FRONT_AND_BACK, mode);
+                },
+            );
+            update(
+                counters,
+                &mut current.color_mask,
+                target.color_mask,
+                |[r, g, b, a]| {
+                    gl.color_mask(r.into(), g.into(), b.into(), a.into());
+                },
+            );
+        }
+    }
+}
+
+/// Calls `set` if `value` differs from `current`.
+fn update<T: PartialEq + Copy>(
+    counters: &mut StateCounters,
+    current: &mut T,
+    value: T,
+    set: impl FnOnce(T),
+) {
+    if *current == value {
+        counters.skipped += 1;
+        return;
+    }
+
+    set(value);
+    *current = value;
+    counters.issued += 1;
+}
+
+unsafe fn toggle(gl: &gl::Api, capability: Capability, on: bool) {
+    unsafe {
+        if on {
+            gl.enable(capability);
+        } else {
+            gl.disable(capability);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gl_bindings::{gl43 as gl, mock};
+
+    use super::{RenderState, StateCounters, StateTracker};
+
+    /// Number of pieces of state the tracker compares on every `apply`.
+    const PIECES: usize = 17;
+
+    fn api() -> gl::Api {
+        mock::reset();
+        unsafe { gl::Api::with_loader(&mock::loader) }
+    }
+
+    /// The names of the state changing calls, without the `glGetError`s of `checked`.
+    fn take_calls() -> Vec<&'static str> {
+        mock::take_calls()
+            .into_iter()
+            .map(|call| call.name)
+            .filter(|&name| name != "glGetError")
+            .collect()
+    }
+
+    #[test]
+    fn the_initial_state_needs_no_calls() {
+        let gl = api();
+        let mut tracker = StateTracker::new();
+
+        tracker.apply(&gl, &RenderState::DEFAULT);
+
+        assert!(take_calls().is_empty());
+        assert_eq!(
+            tracker.take_counters(),
+            StateCounters {
+                issued: 0,
+                skipped: PIECES
+            }
+        );
+    }
+
+    #[test]
+    fn repeated_applies_are_skipped() {
+        let gl = api();
+        let mut tracker = StateTracker::new();
+
+        tracker.apply(&gl, &RenderState::OPAQUE);
+        assert_eq!(take_calls(), ["glEnable", "glDepthFunc"]);
+        assert_eq!(tracker.take_counters().issued, 2);
+
+        tracker.apply(&gl, &RenderState::OPAQUE);
+        assert!(take_calls().is_empty());
+        assert_eq!(
+            tracker.take_counters(),
+            StateCounters {
+                issued: 0,
+                skipped: PIECES
+            }
+        );
+    }
+
+    #[test]
+    fn only_the_differences_are_issued() {
+        let gl = api();
+        let mut tracker = StateTracker::new();
+        tracker.apply(&gl, &RenderState::OPAQUE);
+        mock::reset();
+        tracker.take_counters();
+
+        tracker.apply(&gl, &RenderState::PREMULTIPLIED_ALPHA);
+        assert_eq!(take_calls(), ["glEnable", "glBlendFunc"]);
+
+        // the blend function stays, only blending is turned off again
+        tracker.apply(&gl, &RenderState::OPAQUE);
+        assert_eq!(take_calls(), ["glDisable"]);
+        assert_eq!(
+            tracker.take_counters(),
+            StateCounters {
+                issued: 3,
+                skipped: 2 * PIECES - 3
+            }
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use std::rc::Rc;
+
+use gl_bindings::{
+    gl43::{
+        self as gl, BufferStorageFlags, BufferTarget, GLsync, MapAccess, SyncCondition, SyncStatus,
+        SyncWaitFlags,
+    },
+    gl44,
+};
+
+/// Number of frames the CPU may record while the GPU still reads the data of earlier ones.
+const FRAMES: usize = 3;
+
+/// How long to wait for a fence in one go, in nanoseconds.
+const FENCE_TIMEOUT: u64 = 100_000_000;
+
+/// A persistently mapped buffer for data that changes every frame, like text quads, particles
+/// or debug lines. It's split into one region per frame in flight, each frame hands out
+/// sub-ranges of its region, which is only reused once the GPU is done with it.
+pub struct StreamBuffer {
+    buffer: gl::OwnedBuffer,
+    mapping: *mut u8,
+    region_size: usize,
+    alignment: usize,
+    frame: usize,
+    /// Bytes handed out in the region of the current frame.
+    used: usize,
+    fences: [Option<GLsync>; FRAMES],
+}
+
+impl StreamBuffer {
+    /// Creates a buffer with `region_size` bytes per frame, allocations start at multiples of
+    /// `alignment`, e.g. `UNIFORM_BUFFER_OFFSET_ALIGNMENT` for uniform blocks. The regions are
+    /// rounded up to `alignment` so that every region starts aligned as well.
+    /// Returns `None` if the buffer can't be mapped. Leaves the buffer bound to `target`.
+    pub fn new(
+        gl: &Rc<gl::Api>,
+        storage: &gl44::Api,
+        target: BufferTarget,
+        region_size: usize,
+        alignment: usize,
+    ) -> Option<Self> {
+        let alignment = alignment.max(1);
+        let region_size = region_size.next_multiple_of(alignment);
+        let size = (region_size * FRAMES).try_into().unwrap();
+        let access = MapAccess::WRITE | MapAccess::PERSISTENT | MapAccess::COHERENT;
+
+        let (buffer, mapping) = unsafe {
+            let buffer = gl::OwnedBuffer::new(gl);
+            gl.bind_buffer(target, buffer.handle());
+            storage.buffer_storage(
+                target,
+                size,
+                std::ptr::null(),
+                BufferStorageFlags::MAP_WRITE
+                    | BufferStorageFlags::MAP_PERSISTENT
+                    | BufferStorageFlags::MAP_COHERENT,
+            );
+            let mapping = gl.map_buffer_range(target, 0, size, access);
+            (buffer, mapping.cast::<u8>())
+        };
+
+        if mapping.is_null() {
+            return None;
+        }
+
+        Some(Self {
+            buffer,
+            mapping,
+            region_size,
+            alignment,
+            frame: 0,
+            used: 0,
+            fences: [None; FRAMES],
+        })
+    }
+
+    pub const fn handle(&self) -> gl::Buffer {
+        self.buffer.handle()
+    }
+
+    /// Moves on to the region of the next frame, waiting for the GPU to finish reading it.
+    pub fn begin_frame(&mut self, gl: &gl::Api) {
+        self.frame = (self.frame + 1) % FRAMES;
+        self.used = 0;
+
+        let Some(fence) = self.fences[self.frame].take() else {
+            return;
+        };
+
+        unsafe {
+            loop {
+                match gl.client_wait_sync(fence, SyncWaitFlags::FLUSH_COMMANDS, FENCE_TIMEOUT) {
+                    SyncStatus::TIMEOUT_EXPIRED => continue,
+                    SyncStatus::WAIT_FAILED => log::error!("waiting for a stream buffer failed"),
+                    _ => (),
+                }
+                break;
+            }
+            gl.delete_sync(fence);
+        }
+    }
+
+    /// Marks the end of the commands reading the region of the current frame.
+    pub fn end_frame(&mut self, gl: &gl::Api) {
+        self.fences[self.frame] =
+            Some(unsafe { gl.fence_sync(SyncCondition::GPU_COMMANDS_COMPLETE, 0) });
+    }
+
+    /// Hands out `size` bytes of the current frame's region and returns their offset into the
+    /// buffer, for `bind_buffer_range` or as a draw offset. `None` if the region is full.
+    pub const fn allocate(&mut self, size: usize) -> Option<(usize, &mut [u8])> {
+        let start = self.used.next_multiple_of(self.alignment);
+        if start + size > self.region_size {
+            return None;
+        }
+        self.used = start + size;
+
+        let offset = self.frame * self.region_size + start;
+        // Safety: the range lies within the mapping and isn't handed out again this frame,
+        // earlier frames using it are done according to their fence
+        let data = unsafe { std::slice::from_raw_parts_mut(self.mapping.add(offset), size) };
+
+        Some((offset, data))
+    }
+
+    /// Copies `data` into the current frame's region, see `allocate`.
+    pub fn push(&mut self, data: &[u8]) -> Option<usize> {
+        let (offset, target) = self.allocate(data.len())?;
+        target.copy_from_slice(data);
+        Some(offset)
+    }
+}
+
+impl Drop for StreamBuffer {
+    fn drop(&mut self) {
+        // deleting the buffer unmaps it
+        let gl = self.buffer.api();
+        for fence in self.fences.iter_mut().filter_map(Option::take) {
+            unsafe { gl.delete_sync(fence) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use gl_bindings::{gl43 as gl, gl44, mock};
+
+    use super::{StreamBuffer, FRAMES};
+
+    /// `MAJOR_VERSION` and `MINOR_VERSION`, the mock answers `glGet*iv` by raw name.
+    const MAJOR_VERSION: gl::GLenum = 0x821B;
+    const MINOR_VERSION: gl::GLenum = 0x821C;
+
+    fn stream(region_size: usize, alignment: usize) -> (Rc<gl::Api>, StreamBuffer) {
+        mock::reset();
+        mock::set_integer(MAJOR_VERSION, 4);
+        mock::set_integer(MINOR_VERSION, 4);
+
+        let gl = Rc::new(unsafe { gl::Api::with_loader(&mock::loader) });
+        let storage = unsafe { gl44::Api::load_if_supported(&gl, &mock::loader) }
+            .expect("the mock should provide 4.4 once the version says so");
+        let stream = StreamBuffer::new(
+            &gl,
+            &storage,
+            gl::BufferTarget::UNIFORM_BUFFER,
+            region_size,
+            alignment,
+        )
+        .expect("the mock should map the buffer");
+        (gl, stream)
+    }
+
+    fn count(name: &str) -> usize {
+        mock::calls()
+            .iter()
+            .filter(|call| call.name == name)
+            .count()
+    }
+
+    #[test]
+    fn regions_and_allocations_stay_aligned() {
+        let (gl, mut stream) = stream(100, 64);
+
+        assert_eq!(stream.push(&[1; 10]), Some(0));
+        assert_eq!(stream.push(&[2; 10]), Some(64));
+        // the region was rounded up to 128 bytes, a third allocation doesn't fit
+        assert_eq!(stream.push(&[3; 10]), None);
+
+        stream.end_frame(&gl);
+        stream.begin_frame(&gl);
+        assert_eq!(stream.push(&[4; 10]), Some(128));
+    }
+
+    #[test]
+    fn waits_for_a_region_only_when_wrapping_around_to_it() {
+        let (gl, mut stream) = stream(256, 256);
+
+        for frame in 0..FRAMES {
+            assert_eq!(stream.push(&[0; 16]), Some(frame * 256));
+            stream.end_frame(&gl);
+            stream.begin_frame(&gl);
+            // regions that were never used have no fence to wait for
+            assert_eq!(count("glClientWaitSync"), usize::from(frame + 1 == FRAMES));
+        }
+
+        assert_eq!(stream.push(&[0; 16]), Some(0));
+        assert_eq!(count("glFenceSync"), FRAMES);
+        assert_eq!(count("glDeleteSync"), 1);
+    }
+}
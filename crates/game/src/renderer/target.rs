@@ -0,0 +1,146 @@
+use std::rc::Rc;
+
+use gl_bindings::gl43::{
+    self as gl, BlitFilter, Border, ClearMask, ColorBuffer, Framebuffer, FramebufferAttachment,
+    FramebufferStatus, FramebufferTarget, GLsizei, InternalFormat, OwnedFramebuffer,
+    OwnedRenderbuffer, OwnedTexture, PixelReadback, RenderbufferTarget, TextureDataFormat,
+    TextureFormat, TextureTarget,
+};
+
+/// An offscreen framebuffer with a color texture and a depth/stencil renderbuffer,
+/// the base for post-processing and render-to-texture.
+pub struct RenderTarget {
+    framebuffer: OwnedFramebuffer,
+    // the attachments only need to live as long as the framebuffer
+    _color: OwnedTexture,
+    _depth_stencil: OwnedRenderbuffer,
+    width: GLsizei,
+    height: GLsizei,
+}
+
+#[derive(Debug)]
+pub struct IncompleteFramebuffer(FramebufferStatus);
+
+impl std::error::Error for IncompleteFramebuffer {}
+
+impl std::fmt::Display for IncompleteFramebuffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "framebuffer is incomplete: {}", self.0)
+    }
+}
+
+impl RenderTarget {
+    /// Note: leaves the color texture bound to the active texture unit.
+    pub fn new(
+        gl: &Rc<gl::Api>,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> Result<Self, IncompleteFramebuffer> {
+        unsafe {
+            let color = OwnedTexture::new(gl);
+            gl.bind_texture(TextureTarget::TEXTURE_2D, color.handle());
+            gl.tex_image_2d(
+                TextureTarget::TEXTURE_2D,
+                0,
+                InternalFormat::RGBA8,
+                width,
+                height,
+                Border::ZERO,
+                TextureFormat::RGBA,
+                TextureDataFormat::U8,
+                std::ptr::null(),
+            );
+
+            let depth_stencil = OwnedRenderbuffer::new(gl);
+            gl.bind_renderbuffer(RenderbufferTarget::RENDERBUFFER, depth_stencil.handle());
+            gl.renderbuffer_storage(
+                RenderbufferTarget::RENDERBUFFER,
+                InternalFormat::DEPTH24_STENCIL8,
+                width,
+                height,
+            );
+
+            let framebuffer = OwnedFramebuffer::new(gl);
+            gl.bind_framebuffer(FramebufferTarget::FRAMEBUFFER, framebuffer.handle());
+            gl.framebuffer_texture_2d(
+                FramebufferTarget::FRAMEBUFFER,
+                FramebufferAttachment::COLOR_ATTACHMENT0,
+                TextureTarget::TEXTURE_2D,
+                color.handle(),
+                0,
+            );
+            gl.framebuffer_renderbuffer(
+                FramebufferTarget::FRAMEBUFFER,
+                FramebufferAttachment::DEPTH_STENCIL_ATTACHMENT,
+                RenderbufferTarget::RENDERBUFFER,
+                depth_stencil.handle(),
+            );
+            gl.draw_buffers(1, [ColorBuffer::COLOR_ATTACHMENT0].as_ptr());
+
+            let status = gl.check_framebuffer_status(FramebufferTarget::FRAMEBUFFER);
+            gl.bind_framebuffer(FramebufferTarget::FRAMEBUFFER, Framebuffer::DEFAULT);
+
+            if status != FramebufferStatus::COMPLETE {
+                return Err(IncompleteFramebuffer(status));
+            }
+
+            Ok(Self {
+                framebuffer,
+                _color: color,
+                _depth_stencil: depth_stencil,
+                width,
+                height,
+            })
+        }
+    }
+
+    /// Makes all following draw calls render into this target.
+    pub fn bind(&self, gl: &gl::Api) {
+        unsafe {
+            gl.bind_framebuffer(FramebufferTarget::FRAMEBUFFER, self.framebuffer.handle());
+            gl.viewport(0, 0, self.width, self.height);
+        }
+    }
+
+    /// Copies the color attachment to the window, which has to be the size of this target,
+    /// and leaves the window framebuffer bound.
+    pub fn blit_to_default(&self, gl: &gl::Api) {
+        unsafe {
+            gl.bind_framebuffer(
+                FramebufferTarget::READ_FRAMEBUFFER,
+                self.framebuffer.handle(),
+            );
+            gl.bind_framebuffer(FramebufferTarget::DRAW_FRAMEBUFFER, Framebuffer::DEFAULT);
+            gl.blit_framebuffer(
+                0,
+                0,
+                self.width,
+                self.height,
+                0,
+                0,
+                self.width,
+                self.height,
+                ClearMask::COLOR,
+                BlitFilter::NEAREST,
+            );
+            gl.bind_framebuffer(FramebufferTarget::FRAMEBUFFER, Framebuffer::DEFAULT);
+        }
+    }
+
+    /// Starts reading `attachment` back as RGBA8 without waiting for the GPU, rows bottom to
+    /// top. `COLOR_ATTACHMENT0` holds the rendered frame.
+    pub fn read_back(&self, gl: &Rc<gl::Api>, attachment: ColorBuffer) -> PixelReadback {
+        unsafe {
+            gl.bind_framebuffer(
+                FramebufferTarget::READ_FRAMEBUFFER,
+                self.framebuffer.handle(),
+            );
+            gl.read_buffer(attachment);
+            let readback = PixelReadback::start(gl, 0, 0, self.width, self.height);
+            // `blit_to_default` reads the frame
+            gl.read_buffer(ColorBuffer::COLOR_ATTACHMENT0);
+            gl.bind_framebuffer(FramebufferTarget::READ_FRAMEBUFFER, Framebuffer::DEFAULT);
+            readback
+        }
+    }
+}
@@ -1,47 +1,70 @@
-use std::{mem::MaybeUninit, ptr::addr_of};
-
-use gl_bindings::gl43::{
-    self as gl, Border, InternalFormat, Primitive, Program, SamplerParameterName,
-    SamplerParameterValue, Texture, TextureDataFormat, TextureFormat, TextureTarget, TextureUnit,
-    VertexArray,
+use std::rc::Rc;
+
+use gl_bindings::{
+    gl43::{
+        self as gl, Border, GLint, GLuint, InternalFormat, OwnedProgram, OwnedSampler,
+        OwnedTexture, OwnedVertexArray, Primitive, ShaderKind, TextureDataFormat, TextureFormat,
+        TextureTarget, TextureUnit,
+    },
+    gl45,
 };
 use glam::{vec2, vec3, vec4, Vec2, Vec3};
 
 use crate::color32;
 
-use super::{create_shader_program, FS, VS};
+use super::{
+    gfx::Sampler,
+    shader::{ProgramBuilder, ShaderError},
+    state::{RenderState, StateTracker},
+    texture::create_sampler,
+    uniform::{FrameUniforms, StorageArray, Uniform},
+    DrawBlock, FrameBlock, FRAME_BINDING,
+};
+
+/// Shader storage block binding point of the per-glyph blocks.
+const GLYPHS_BINDING: GLuint = 0;
 
 pub struct Renderer {
-    vao: VertexArray,
-    program: Program,
-    textures: Vec<Texture>,
+    vao: OwnedVertexArray,
+    program: OwnedProgram,
+    sampler_location: Option<GLint>,
+    frame_uniforms: FrameUniforms<FrameBlock>,
+    glyphs: StorageArray<DrawBlock>,
+    textures: Vec<OwnedTexture>,
+    samplers: Vec<OwnedSampler>,
     fonts: Vec<Font>,
     texts: Vec<Text>,
     draw_list: Vec<(usize, color32::Linear32, Vec3)>,
 }
 
 impl Renderer {
-    pub fn new(gl: &gl::Api) -> Self {
-        let vao = unsafe {
-            let mut vao = MaybeUninit::zeroed();
-            gl.gen_vertex_arrays(1, vao.as_mut_ptr());
-            vao.assume_init()
-        };
-        let program = create_shader_program(gl, VS, FS);
+    pub fn new(gl: &Rc<gl::Api>) -> Result<Self, ShaderError> {
+        let vao = unsafe { OwnedVertexArray::new(gl) };
+        let program = ProgramBuilder::new(gl)
+            .stage(ShaderKind::VERTEX, VS)
+            .stage(ShaderKind::FRAGMENT, FS)
+            .build()?;
+        let sampler_location =
+            unsafe { gl::ProgramReflection::new(gl, program.handle()) }.uniform_location("sampler");
 
-        Self {
+        Ok(Self {
+            frame_uniforms: FrameUniforms::new(gl, FRAME_BINDING),
+            glyphs: StorageArray::new(gl, GLYPHS_BINDING),
             fonts: Vec::with_capacity(10),
             texts: Vec::with_capacity(100),
             textures: Vec::with_capacity(10),
+            samplers: Vec::with_capacity(10),
             draw_list: Vec::with_capacity(100),
             vao,
             program,
-        }
+            sampler_location,
+        })
     }
 
     pub fn load_font_from_memory(
         &mut self,
-        gl: &gl::Api,
+        gl: &Rc<gl::Api>,
+        dsa: Option<&gl45::Api>,
         font_size: f32,
         data: &[u8],
         char_set: impl Iterator<Item = char>,
@@ -50,49 +73,41 @@ impl Renderer {
         self.fonts.push(font);
 
         let (texture, sampler) = unsafe {
-            let mut texture = MaybeUninit::zeroed();
-            gl.gen_textures(1, texture.as_mut_ptr());
-            let texture = texture.assume_init();
-
-            gl.active_texture(TextureUnit::ZERO);
-
-            gl.bind_texture(TextureTarget::TEXTURE_2D, texture);
-            gl.tex_image_2d(
-                TextureTarget::TEXTURE_2D,
-                0,
-                InternalFormat::RGB8,
-                Font::ATLAS_SIZE.try_into().unwrap(),
-                Font::ATLAS_SIZE.try_into().unwrap(),
-                Border::ZERO,
-                TextureFormat::RGB,
-                TextureDataFormat::U8,
-                atlas.as_ptr().cast(),
-            );
+            let texture = create_atlas_texture(gl, dsa, &atlas);
+            gl.label(texture.handle(), "font atlas");
 
-            let mut sampler = MaybeUninit::zeroed();
-            gl.gen_samplers(1, sampler.as_mut_ptr());
-            let sampler = sampler.assume_init();
-
-            gl.bind_sampler(0, sampler);
-            gl.sampler_parameter_i(
-                sampler,
-                SamplerParameterName::TEXTURE_MIN_FILTER,
-                SamplerParameterValue::LINEAR,
-            );
+            let sampler = create_sampler(gl, Sampler::default());
+            gl.bind_sampler(0, sampler.handle());
 
             (texture, sampler)
         };
+        self.textures.push(texture);
+        self.samplers.push(sampler);
+
         Ok(self.fonts.len() - 1)
     }
 
+    /// Binds the atlas of the last loaded font to texture unit 0 again, after something else
+    /// replaced the binding.
+    pub fn bind_atlas(&self, gl: &gl::Api) {
+        let Some(atlas) = self.textures.last() else {
+            return;
+        };
+
+        unsafe {
+            gl.active_texture(TextureUnit::ZERO);
+            gl.bind_texture(TextureTarget::TEXTURE_2D, atlas.handle());
+        }
+    }
+
     pub fn create_text(
         &mut self,
         font_handle: usize,
         position: Vec3,
         content: &str,
-    ) -> Result<usize, CharacterNotFound> {
+    ) -> Result<usize, TextError> {
         let Some(font) = self.fonts.get(font_handle) else {
-            return Err(todo!());
+            return Err(TextError::FontNotFound(font_handle));
         };
 
         let text = Text::new(font, position, content)?;
@@ -105,51 +120,105 @@ impl Renderer {
         self.draw_list.push((text_handle, color, position));
     }
 
-    pub fn update(&mut self, gl: &gl::Api) {
-        unsafe {
-            gl.enable(gl::Capability::DEPTH);
-            gl.disable(gl::Capability::CULL_FACE);
-            gl.depth_func(gl::DepthFunc::LEQUAL);
-        }
+    pub fn update(&mut self, gl: &gl::Api, state: &mut StateTracker) {
+        state.apply(gl, &RenderState::PREMULTIPLIED_ALPHA);
 
         let projection = glam::Mat4::orthographic_lh(0.0, 1024.0, 768.0, 0.0, 0.01, 100.0);
         let view = glam::Mat4::IDENTITY;
 
-        unsafe {
-            gl.use_program(self.program);
-        }
+        self.frame_uniforms.update(
+            gl,
+            &FrameBlock {
+                view_projection: projection * view,
+            },
+        );
+        self.frame_uniforms.bind(gl);
 
         for (handle, color, offset) in self.draw_list.drain(..) {
             let text = self.texts.get(handle).unwrap();
 
             for i in 0..text.positions.len() {
-                let position = text.positions[i];
-                let uv = text.uvs[i];
-                let scale = text.scales[i];
-
-                let model =
-                    glam::Mat4::from_translation(position + offset) * glam::Mat4::from_scale(scale);
-
-                let view_projection = projection * view * model;
-
-                unsafe {
-                    gl.uniform_matrix4_fv(
-                        1,
-                        1,
-                        gl::GLboolean::FALSE,
-                        std::ptr::addr_of!(view_projection).cast(),
-                    );
-                    gl.uniform4_fv(6, 1, addr_of!(uv).cast());
-                    gl.uniform4_fv(7, 1, addr_of!(color).cast());
-                }
-
-                unsafe {
-                    gl.bind_vertex_array(self.vao);
-                    gl.uniform1_i(0, 0);
-                    gl.draw_arrays(Primitive::TRIANGLE_STRIP, 0, 4);
-                }
+                let model = glam::Mat4::from_translation(text.positions[i] + offset)
+                    * glam::Mat4::from_scale(text.scales[i]);
+
+                self.glyphs.push(DrawBlock {
+                    model,
+                    color,
+                    uv_rect: text.uvs[i],
+                });
             }
         }
+
+        let glyph_count = self.glyphs.upload(gl);
+        if glyph_count == 0 {
+            return;
+        }
+
+        if let Some(location) = self.sampler_location {
+            0_i32.set_uniform(gl, self.program.handle(), location);
+        }
+        unsafe {
+            gl.use_program(self.program.handle());
+            gl.bind_vertex_array(self.vao.handle());
+            // the quad is made up from `gl_VertexID`, each instance reads its glyph block
+            gl.draw_arrays_instanced(
+                Primitive::TRIANGLE_STRIP,
+                0,
+                4,
+                glyph_count.try_into().unwrap(),
+            );
+        }
+    }
+}
+
+/// Creates the texture of a font atlas and binds it to texture unit 0.
+unsafe fn create_atlas_texture(
+    gl: &Rc<gl::Api>,
+    dsa: Option<&gl45::Api>,
+    atlas: &[u8],
+) -> OwnedTexture {
+    let size = Font::ATLAS_SIZE.try_into().unwrap();
+
+    if let Some(dsa) = dsa {
+        return unsafe {
+            let texture = dsa.create_texture(TextureTarget::TEXTURE_2D);
+            let texture = OwnedTexture::from_raw(Rc::clone(gl), texture);
+
+            dsa.texture_storage_2d(texture.handle(), 1, InternalFormat::RGB8, size, size);
+            dsa.texture_sub_image_2d(
+                texture.handle(),
+                0,
+                0,
+                0,
+                size,
+                size,
+                TextureFormat::RGB,
+                TextureDataFormat::U8,
+                atlas.as_ptr().cast(),
+            );
+            dsa.bind_texture_unit(0, texture.handle());
+            texture
+        };
+    }
+
+    unsafe {
+        let texture = OwnedTexture::new(gl);
+
+        gl.active_texture(TextureUnit::ZERO);
+
+        gl.bind_texture(TextureTarget::TEXTURE_2D, texture.handle());
+        gl.tex_image_2d(
+            TextureTarget::TEXTURE_2D,
+            0,
+            InternalFormat::RGB8,
+            size,
+            size,
+            Border::ZERO,
+            TextureFormat::RGB,
+            TextureDataFormat::U8,
+            atlas.as_ptr().cast(),
+        );
+        texture
     }
 }
 
@@ -169,15 +238,37 @@ pub struct Text {
 #[derive(Debug)]
 pub struct CharacterNotFound(char);
 
+#[derive(Debug)]
+pub enum TextError {
+    FontNotFound(usize),
+    CharacterNotFound(char),
+}
+
 #[derive(Debug)]
 pub enum FontLoadingError {
     CharacterNotFound(char),
     FontInvalid,
 }
 
+impl std::error::Error for TextError {}
 impl std::error::Error for FontLoadingError {}
 impl std::error::Error for CharacterNotFound {}
 
+impl From<CharacterNotFound> for TextError {
+    fn from(value: CharacterNotFound) -> Self {
+        Self::CharacterNotFound(value.0)
+    }
+}
+
+impl std::fmt::Display for TextError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FontNotFound(handle) => write!(f, "no font loaded for handle {handle}"),
+            Self::CharacterNotFound(char) => write!(f, "character {char} not found"),
+        }
+    }
+}
+
 impl std::fmt::Display for FontLoadingError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -335,3 +426,125 @@ impl Font {
         Ok((font, data))
     }
 }
+
+const VS: &str = "
+#version 430
+
+layout(std140, binding = 0) uniform Frame {
+    mat4 view_projection;
+} frame;
+
+struct Glyph {
+    mat4 model;
+    vec4 color;
+    vec4 uv_rect;
+};
+
+layout(std430, binding = 0) readonly buffer Glyphs {
+    Glyph glyphs[];
+};
+
+out vec4 vertex_color;
+out vec2 vertex_uv;
+
+void main() {
+    Glyph glyph = glyphs[gl_InstanceID];
+    vec2 corner = vec2(gl_VertexID & 1, gl_VertexID >> 1);
+
+    vertex_color = glyph.color;
+    vertex_uv = glyph.uv_rect.xy + corner * glyph.uv_rect.zw;
+    gl_Position = frame.view_projection * glyph.model * vec4(corner, 0.0, 1.0);
+}";
+
+const FS: &str = "
+#version 430
+
+uniform sampler2D sampler;
+
+in vec4 vertex_color;
+in vec2 vertex_uv;
+
+out vec4 color;
+
+void main() {
+    color = texture(sampler, vertex_uv).r * vertex_color;
+}";
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use gl_bindings::{gl43 as gl, mock};
+    use glam::{Vec2, Vec3};
+
+    use super::{Font, Glyph, Renderer, TextError};
+    use crate::{
+        color32,
+        renderer::{state::StateTracker, DrawBlock},
+    };
+
+    fn font() -> Font {
+        Font {
+            glyphs: vec![Glyph {
+                position: Vec2::ZERO,
+                bitmap_size: Vec2::splat(0.1),
+                size: Vec2::splat(10.0),
+                advance: Vec2::new(12.0, 0.0),
+                offset: Vec2::ZERO,
+                char: 'a',
+            }],
+            size: 16.0,
+            white_space: 8.0,
+        }
+    }
+
+    #[test]
+    fn texts_draw_all_glyphs_with_one_instanced_draw() {
+        mock::reset();
+        let gl = Rc::new(unsafe { gl::Api::with_loader(&mock::loader) });
+        let mut renderer = Renderer::new(&gl).unwrap();
+
+        assert!(matches!(
+            renderer.create_text(0, Vec3::ZERO, "a"),
+            Err(TextError::FontNotFound(0))
+        ));
+
+        renderer.fonts.push(font());
+        assert!(matches!(
+            renderer.create_text(0, Vec3::ZERO, "ab"),
+            Err(TextError::CharacterNotFound('b'))
+        ));
+        let text = renderer.create_text(0, Vec3::ZERO, "a a\na").unwrap();
+
+        renderer.draw(text, color32::Linear32::WHITE, Vec3::ZERO);
+        mock::reset();
+        renderer.update(&gl, &mut StateTracker::new());
+
+        let calls = mock::calls();
+        let named = |name| calls.iter().filter(move |call| call.name == name);
+
+        // the three glyph blocks are uploaded at once, SHADER_STORAGE_BUFFER
+        let uploads: Vec<_> = named("glBufferSubData")
+            .filter(|call| call.args[0] == mock::Arg::Int(0x90D2))
+            .collect();
+        assert_eq!(uploads.len(), 1);
+        assert_eq!(
+            uploads[0].args[2],
+            mock::Arg::Int((3 * std::mem::size_of::<DrawBlock>()).try_into().unwrap())
+        );
+
+        // TRIANGLE_STRIP, 4 vertices of the quad, one instance per glyph
+        let draws: Vec<_> = named("glDrawArraysInstanced").collect();
+        assert_eq!(draws.len(), 1);
+        assert_eq!(
+            draws[0].args,
+            [
+                mock::Arg::Int(0x0005),
+                mock::Arg::Int(0),
+                mock::Arg::Int(4),
+                mock::Arg::Int(3)
+            ]
+        );
+        assert_eq!(named("glDrawArrays").count(), 0);
+    }
+}
@@ -0,0 +1,245 @@
+use std::rc::Rc;
+
+use gl_bindings::gl43::{
+    self as gl, GLsizei, InternalFormat, OwnedSampler, OwnedTexture, PixelStoreParameter,
+    SamplerParameter, TextureDataFormat, TextureFormat, TextureTarget,
+};
+
+use super::gfx::{Image, ImageFormat, Sampler};
+
+/// The number of mipmap levels down to 1x1 for a texture of the given size.
+pub const fn mip_levels(width: GLsizei, height: GLsizei) -> GLsizei {
+    let size = if width > height { width } else { height };
+    if size <= 1 {
+        return 1;
+    }
+    (GLsizei::BITS - size.leading_zeros()).cast_signed()
+}
+
+/// The internal format and the client side layout of an image, color textures like base colors
+/// are `srgb` while data like normals or roughness is not.
+const fn formats(
+    format: ImageFormat,
+    srgb: bool,
+) -> (InternalFormat, TextureFormat, TextureDataFormat) {
+    use ImageFormat as F;
+
+    match format {
+        F::R8 => (
+            InternalFormat::R8,
+            TextureFormat::RED,
+            TextureDataFormat::U8,
+        ),
+        F::Rg8 => (
+            InternalFormat::RG8,
+            TextureFormat::RG,
+            TextureDataFormat::U8,
+        ),
+        F::Rgb8 if srgb => (
+            InternalFormat::SRGB8,
+            TextureFormat::RGB,
+            TextureDataFormat::U8,
+        ),
+        F::Rgb8 => (
+            InternalFormat::RGB8,
+            TextureFormat::RGB,
+            TextureDataFormat::U8,
+        ),
+        F::Rgba8 if srgb => (
+            InternalFormat::SRGB8_ALPHA8,
+            TextureFormat::RGBA,
+            TextureDataFormat::U8,
+        ),
+        F::Rgba8 => (
+            InternalFormat::RGBA8,
+            TextureFormat::RGBA,
+            TextureDataFormat::U8,
+        ),
+        F::R16 => (
+            InternalFormat::R16,
+            TextureFormat::RED,
+            TextureDataFormat::U16,
+        ),
+        F::Rg16 => (
+            InternalFormat::RG16,
+            TextureFormat::RG,
+            TextureDataFormat::U16,
+        ),
+        F::Rgb16 => (
+            InternalFormat::RGB16,
+            TextureFormat::RGB,
+            TextureDataFormat::U16,
+        ),
+        F::Rgba16 => (
+            InternalFormat::RGBA16,
+            TextureFormat::RGBA,
+            TextureDataFormat::U16,
+        ),
+        F::Rf32 => (
+            InternalFormat::R32F,
+            TextureFormat::RED,
+            TextureDataFormat::F32,
+        ),
+        F::RgF32 => (
+            InternalFormat::RG32F,
+            TextureFormat::RG,
+            TextureDataFormat::F32,
+        ),
+        F::RgbF32 => (
+            InternalFormat::RGB32F,
+            TextureFormat::RGB,
+            TextureDataFormat::F32,
+        ),
+        F::RgbaF32 => (
+            InternalFormat::RGBA32F,
+            TextureFormat::RGBA,
+            TextureDataFormat::F32,
+        ),
+    }
+}
+
+/// Uploads `image` with a full mipmap chain. Leaves the texture bound to `TEXTURE_2D` of the
+/// active texture unit.
+pub fn create_image_texture(gl: &Rc<gl::Api>, image: &Image, srgb: bool) -> OwnedTexture {
+    let width = image.width.try_into().unwrap();
+    let height = image.height.try_into().unwrap();
+    let (internal_format, format, kind) = formats(image.format, srgb);
+
+    unsafe {
+        let texture = OwnedTexture::new(gl);
+        gl.bind_texture(TextureTarget::TEXTURE_2D, texture.handle());
+        gl.tex_storage_2d(
+            TextureTarget::TEXTURE_2D,
+            mip_levels(width, height),
+            internal_format,
+            width,
+            height,
+        );
+
+        // glTF rows are tightly packed, which breaks the default 4 byte alignment for RGB8
+        gl.pixel_store_i(PixelStoreParameter::UNPACK_ALIGNMENT, 1);
+        gl.tex_sub_image_2d(
+            TextureTarget::TEXTURE_2D,
+            0,
+            0,
+            0,
+            width,
+            height,
+            format,
+            kind,
+            image.data.as_ptr().cast(),
+        );
+        gl.pixel_store_i(PixelStoreParameter::UNPACK_ALIGNMENT, 4);
+
+        gl.generate_mipmap(TextureTarget::TEXTURE_2D);
+        texture
+    }
+}
+
+/// Creates a sampler object with all parameters of `sampler` set, ready to be bound.
+pub fn create_sampler(gl: &Rc<gl::Api>, sampler: Sampler) -> OwnedSampler {
+    unsafe {
+        let owned = OwnedSampler::new(gl);
+        for parameter in Vec::<SamplerParameter>::from(sampler) {
+            gl.sampler_parameter(owned.handle(), parameter);
+        }
+        owned
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use gl_bindings::{gl43 as gl, mock};
+
+    use super::{create_image_texture, create_sampler, mip_levels};
+    use crate::renderer::gfx::{Compare, Image, ImageFormat, Sampler, Wrap};
+
+    #[test]
+    fn mip_chains_end_at_one_pixel() {
+        assert_eq!(mip_levels(1, 1), 1);
+        assert_eq!(mip_levels(2, 1), 2);
+        assert_eq!(mip_levels(1024, 512), 11);
+        assert_eq!(mip_levels(1000, 3), 10);
+    }
+
+    #[test]
+    fn image_textures_get_storage_for_every_level() {
+        mock::reset();
+        let gl = Rc::new(unsafe { gl::Api::with_loader(&mock::loader) });
+        let image = Image {
+            data: vec![0; 4 * 2 * 3],
+            width: 4,
+            height: 2,
+            format: ImageFormat::Rgb8,
+            srgb: true,
+        };
+
+        let _texture = create_image_texture(&gl, &image, image.srgb);
+
+        let calls = mock::calls();
+        let storage = calls
+            .iter()
+            .find(|call| call.name == "glTexStorage2D")
+            .expect("the texture should get immutable storage");
+        // TEXTURE_2D, 3 levels of SRGB8, 4x2
+        assert_eq!(
+            storage.args,
+            [
+                mock::Arg::Int(0x0DE1),
+                mock::Arg::Int(3),
+                mock::Arg::Int(0x8C41),
+                mock::Arg::Int(4),
+                mock::Arg::Int(2)
+            ]
+        );
+        assert!(calls.iter().any(|call| call.name == "glGenerateMipmap"));
+    }
+
+    #[test]
+    fn samplers_set_every_parameter() {
+        const TEXTURE_COMPARE_FUNC: i64 = 0x884D;
+        const TEXTURE_MAX_ANISOTROPY: i64 = 0x84FE;
+
+        mock::reset();
+        let gl = Rc::new(unsafe { gl::Api::with_loader(&mock::loader) });
+        let parameters = || -> Vec<_> {
+            mock::take_calls()
+                .into_iter()
+                .filter(|call| call.name.starts_with("glSamplerParameter"))
+                .map(|call| call.args[1..].to_vec())
+                .collect()
+        };
+
+        let _sampler = create_sampler(&gl, Sampler::default());
+        let defaults = parameters();
+        assert_eq!(defaults.len(), 9);
+        assert!(!defaults
+            .iter()
+            .any(|args| args[0] == mock::Arg::Int(TEXTURE_MAX_ANISOTROPY)));
+
+        let _shadows = create_sampler(
+            &gl,
+            Sampler {
+                wrap_u: Wrap::Border,
+                wrap_v: Wrap::Border,
+                border_color: [1.0; 4],
+                compare: Some(Compare::LessOrEqual),
+                anisotropy: Some(8.0),
+                ..Sampler::default()
+            },
+        );
+        let shadows = parameters();
+        // the compare mode and function are separate parameters
+        assert_eq!(shadows.len(), 11);
+        assert!(shadows.contains(&vec![
+            mock::Arg::Int(TEXTURE_COMPARE_FUNC),
+            mock::Arg::Int(0x0203)
+        ]));
+        assert!(shadows.contains(&vec![
+            mock::Arg::Int(TEXTURE_MAX_ANISOTROPY),
+            mock::Arg::Float(8.0)
+        ]));
+    }
+}
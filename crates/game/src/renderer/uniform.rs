@@ -0,0 +1,354 @@
+use std::{marker::PhantomData, ptr::addr_of_mut, rc::Rc};
+
+use gl_bindings::{
+    gl43::{
+        self as gl, BufferTarget, BufferUsage, GLboolean, GLint, GLsizei, GLuint,
+        StateParameterName,
+    },
+    gl44,
+};
+use glam::{IVec2, IVec3, IVec4, Mat3, Mat4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
+
+use crate::color32;
+
+use super::stream::StreamBuffer;
+
+/// Data of a uniform block. The type has to be `#[repr(C)]` and match the std140 layout of its
+/// block in the shader, `Pod` makes sure it can be copied into the buffer byte by byte.
+pub trait Block: gl::Pod {}
+
+const fn as_bytes<T: Block>(block: &T) -> &[u8] {
+    gl::as_bytes(std::slice::from_ref(block))
+}
+
+fn size<T>(bytes: usize) -> T
+where
+    T: TryFrom<usize>,
+    T::Error: std::fmt::Debug,
+{
+    bytes.try_into().expect("uniform buffer size should fit")
+}
+
+/// A single uniform block, uploaded once per frame, e.g. the camera matrices.
+pub struct FrameUniforms<T: Block> {
+    buffer: gl::OwnedBuffer,
+    binding: GLuint,
+    _block: PhantomData<T>,
+}
+
+impl<T: Block> FrameUniforms<T> {
+    /// Creates the buffer and binds it to the uniform block binding point `binding`.
+    pub fn new(gl: &Rc<gl::Api>, binding: GLuint) -> Self {
+        let buffer = unsafe {
+            let buffer = gl::OwnedBuffer::new(gl);
+            gl.bind_buffer(BufferTarget::UNIFORM_BUFFER, buffer.handle());
+            gl.buffer_data(
+                BufferTarget::UNIFORM_BUFFER,
+                size(std::mem::size_of::<T>()),
+                std::ptr::null(),
+                BufferUsage::DYNAMIC_DRAW,
+            );
+            buffer
+        };
+
+        let uniforms = Self {
+            buffer,
+            binding,
+            _block: PhantomData,
+        };
+        uniforms.bind(gl);
+        uniforms
+    }
+
+    pub fn update(&self, gl: &gl::Api, block: &T) {
+        unsafe {
+            gl.bind_buffer(BufferTarget::UNIFORM_BUFFER, self.buffer.handle());
+            gl.buffer_sub_data_slice(BufferTarget::UNIFORM_BUFFER, 0, as_bytes(block));
+        }
+    }
+
+    /// Binds the block again, needed when another buffer shares the binding point.
+    pub fn bind(&self, gl: &gl::Api) {
+        unsafe {
+            gl.bind_buffer_base(
+                BufferTarget::UNIFORM_BUFFER,
+                self.binding,
+                self.buffer.handle(),
+            );
+        }
+    }
+}
+
+/// Bytes of draw blocks a `DrawUniforms` can stream per frame before it falls back to
+/// reallocating its own buffer.
+const STREAM_REGION_SIZE: usize = 64 * 1024;
+
+/// One uniform block per draw call, all packed into a single buffer that is uploaded once per
+/// frame. Each draw binds its own slice of the buffer with `bind`.
+///
+/// The blocks go through a persistently mapped `StreamBuffer` if `storage` is available,
+/// otherwise the buffer is respecified every frame.
+pub struct DrawUniforms<T: Block> {
+    buffer: gl::OwnedBuffer,
+    stream: Option<StreamBuffer>,
+    binding: GLuint,
+    /// Size of one block rounded up to `UNIFORM_BUFFER_OFFSET_ALIGNMENT`.
+    stride: usize,
+    /// Bytes allocated for the buffer.
+    capacity: usize,
+    staging: Vec<u8>,
+    /// The buffer and offset the blocks of the last `upload` went to.
+    uploaded: (gl::Buffer, usize),
+    _block: PhantomData<T>,
+}
+
+impl<T: Block> DrawUniforms<T> {
+    pub fn new(gl: &Rc<gl::Api>, storage: Option<&gl44::Api>, binding: GLuint) -> Self {
+        let mut alignment = 0;
+        unsafe {
+            gl.get_integer_v(
+                StateParameterName::UNIFORM_BUFFER_OFFSET_ALIGNMENT,
+                addr_of_mut!(alignment),
+            );
+        }
+
+        let alignment = usize::try_from(alignment).unwrap_or_default().max(1);
+        let stride = std::mem::size_of::<T>().next_multiple_of(alignment);
+
+        let stream = storage.and_then(|storage| {
+            StreamBuffer::new(
+                gl,
+                storage,
+                BufferTarget::UNIFORM_BUFFER,
+                STREAM_REGION_SIZE,
+                alignment,
+            )
+        });
+        if stream.is_none() {
+            log::info!("persistent mapping not available, respecifying draw uniforms instead");
+        }
+
+        let buffer = unsafe { gl::OwnedBuffer::new(gl) };
+        Self {
+            uploaded: (buffer.handle(), 0),
+            buffer,
+            stream,
+            binding,
+            stride,
+            capacity: 0,
+            staging: Vec::new(),
+            _block: PhantomData,
+        }
+    }
+
+    /// Queues a block for the next `upload` and returns the slot to `bind` it with.
+    pub fn push(&mut self, block: &T) -> usize {
+        let slot = self.staging.len() / self.stride;
+        self.staging.extend_from_slice(as_bytes(block));
+        self.staging.resize((slot + 1) * self.stride, 0);
+        slot
+    }
+
+    /// Uploads all queued blocks, growing the buffer if needed, and clears the queue. Call once
+    /// per frame, the draws of the previous frame are fenced here.
+    pub fn upload(&mut self, gl: &gl::Api) {
+        if let Some(stream) = &mut self.stream {
+            stream.end_frame(gl);
+            stream.begin_frame(gl);
+        }
+
+        if self.staging.is_empty() {
+            return;
+        }
+
+        let streamed = self
+            .stream
+            .as_mut()
+            .and_then(|stream| Some((stream.handle(), stream.push(&self.staging)?)));
+
+        self.uploaded = streamed.unwrap_or_else(|| {
+            unsafe {
+                gl.bind_buffer(BufferTarget::UNIFORM_BUFFER, self.buffer.handle());
+
+                if self.staging.len() > self.capacity {
+                    self.capacity = self.staging.len().next_power_of_two();
+                    gl.buffer_data(
+                        BufferTarget::UNIFORM_BUFFER,
+                        size(self.capacity),
+                        std::ptr::null(),
+                        BufferUsage::STREAM_DRAW,
+                    );
+                }
+
+                gl.buffer_sub_data_slice(BufferTarget::UNIFORM_BUFFER, 0, &self.staging);
+            }
+            (self.buffer.handle(), 0)
+        });
+
+        self.staging.clear();
+    }
+
+    /// Binds the block of `slot` for the following draw call.
+    pub fn bind(&self, gl: &gl::Api, slot: usize) {
+        let (buffer, offset) = self.uploaded;
+        unsafe {
+            gl.bind_buffer_range(
+                BufferTarget::UNIFORM_BUFFER,
+                self.binding,
+                buffer,
+                size(offset + slot * self.stride),
+                size(std::mem::size_of::<T>()),
+            );
+        }
+    }
+}
+
+/// An array of blocks in a shader storage buffer, uploaded once per frame and indexed in the
+/// shader, e.g. by `gl_InstanceID` to draw all of them with one instanced draw call.
+/// The blocks are tightly packed, so `T` has to match the std430 layout of the array elements.
+pub struct StorageArray<T: Block> {
+    buffer: gl::OwnedBuffer,
+    binding: GLuint,
+    /// Blocks allocated for the buffer.
+    capacity: usize,
+    staging: Vec<T>,
+}
+
+impl<T: Block> StorageArray<T> {
+    /// Creates the buffer for the shader storage block binding point `binding`.
+    pub fn new(gl: &Rc<gl::Api>, binding: GLuint) -> Self {
+        Self {
+            buffer: unsafe { gl::OwnedBuffer::new(gl) },
+            binding,
+            capacity: 0,
+            staging: Vec::new(),
+        }
+    }
+
+    /// Queues a block for the next `upload` and returns its index in the array.
+    pub fn push(&mut self, block: T) -> usize {
+        self.staging.push(block);
+        self.staging.len() - 1
+    }
+
+    /// Uploads all queued blocks, growing the buffer if needed, binds it and clears the queue.
+    /// Returns the number of blocks in the array.
+    pub fn upload(&mut self, gl: &gl::Api) -> usize {
+        let count = self.staging.len();
+        if count == 0 {
+            return 0;
+        }
+
+        unsafe {
+            gl.bind_buffer(BufferTarget::SHADER_STORAGE_BUFFER, self.buffer.handle());
+
+            if count > self.capacity {
+                self.capacity = count.next_power_of_two();
+                gl.buffer_data(
+                    BufferTarget::SHADER_STORAGE_BUFFER,
+                    size(self.capacity * std::mem::size_of::<T>()),
+                    std::ptr::null(),
+                    BufferUsage::STREAM_DRAW,
+                );
+            }
+
+            gl.buffer_sub_data_slice(BufferTarget::SHADER_STORAGE_BUFFER, 0, &self.staging);
+            gl.bind_buffer_base(
+                BufferTarget::SHADER_STORAGE_BUFFER,
+                self.binding,
+                self.buffer.handle(),
+            );
+        }
+
+        self.staging.clear();
+        count
+    }
+}
+
+/// A value for a uniform of the matching GLSL type, slices set arrays starting at the location.
+pub trait Uniform {
+    /// Sets the uniform at `location` of `program`, the program doesn't have to be in use.
+    fn set_uniform(&self, gl: &gl::Api, program: gl::Program, location: GLint);
+}
+
+/// A type with a GLSL counterpart, made of `GLfloat`s, `GLint`s or `GLuint`s without padding.
+pub trait UniformElement: Sized {
+    fn set_uniform_array(values: &[Self], gl: &gl::Api, program: gl::Program, location: GLint);
+}
+
+impl<T: UniformElement> Uniform for T {
+    fn set_uniform(&self, gl: &gl::Api, program: gl::Program, location: GLint) {
+        T::set_uniform_array(std::slice::from_ref(self), gl, program, location);
+    }
+}
+
+impl<T: UniformElement> Uniform for [T] {
+    fn set_uniform(&self, gl: &gl::Api, program: gl::Program, location: GLint) {
+        T::set_uniform_array(self, gl, program, location);
+    }
+}
+
+macro_rules! uniform_element {
+    ($($kind:ty => $setter:ident),* $(,)?) => {
+        $(
+            impl UniformElement for $kind {
+                fn set_uniform_array(
+                    values: &[Self],
+                    gl: &gl::Api,
+                    program: gl::Program,
+                    location: GLint,
+                ) {
+                    unsafe {
+                        gl.$setter(program, location, size(values.len()), values.as_ptr().cast());
+                    }
+                }
+            }
+        )*
+    };
+}
+
+uniform_element! {
+    f32 => program_uniform1_fv,
+    Vec2 => program_uniform2_fv,
+    Vec3 => program_uniform3_fv,
+    Vec4 => program_uniform4_fv,
+    color32::Linear32 => program_uniform4_fv,
+    i32 => program_uniform1_iv,
+    IVec2 => program_uniform2_iv,
+    IVec3 => program_uniform3_iv,
+    IVec4 => program_uniform4_iv,
+    u32 => program_uniform1_uiv,
+    UVec2 => program_uniform2_uiv,
+    UVec3 => program_uniform3_uiv,
+    UVec4 => program_uniform4_uiv,
+}
+
+impl UniformElement for Mat3 {
+    fn set_uniform_array(values: &[Self], gl: &gl::Api, program: gl::Program, location: GLint) {
+        let count: GLsizei = size(values.len());
+        unsafe {
+            gl.program_uniform_matrix3_fv(
+                program,
+                location,
+                count,
+                GLboolean::FALSE,
+                values.as_ptr().cast(),
+            );
+        }
+    }
+}
+
+impl UniformElement for Mat4 {
+    fn set_uniform_array(values: &[Self], gl: &gl::Api, program: gl::Program, location: GLint) {
+        let count: GLsizei = size(values.len());
+        unsafe {
+            gl.program_uniform_matrix4_fv(
+                program,
+                location,
+                count,
+                GLboolean::FALSE,
+                values.as_ptr().cast(),
+            );
+        }
+    }
+}
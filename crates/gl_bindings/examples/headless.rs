@@ -0,0 +1,90 @@
+//! Renders into a framebuffer object without any window, e.g. on CI with
+//! `LIBGL_ALWAYS_SOFTWARE=1 cargo run --example headless --features egl`.
+use std::rc::Rc;
+
+use gl_bindings::{
+    egl::HeadlessContext,
+    gl43::{
+        self as gl, Capabilities, Capability, ClearMask, DebugCollector, DebugType,
+        FramebufferAttachment, FramebufferStatus, FramebufferTarget, InternalFormat,
+        OwnedFramebuffer, OwnedRenderbuffer, PixelReadback, RenderbufferTarget,
+    },
+};
+
+const SIZE: gl::GLsizei = 64;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info"))
+        .format_timestamp(None)
+        .init();
+
+    let context = HeadlessContext::new(4, 3, true)?;
+    let gl = Rc::new(unsafe { gl::Api::try_with_loader(&|s| context.proc_address(s)) }?);
+
+    let debug_messages = unsafe { DebugCollector::install(&gl) };
+    unsafe { gl.enable(Capability::DEBUG_OUTPUT_SYNCHRONOUS) };
+
+    let capabilities = unsafe { Capabilities::query(&gl) };
+    log::info!(
+        "{} on {}, surfaceless: {}",
+        capabilities.version_string,
+        capabilities.renderer,
+        context.is_surfaceless()
+    );
+
+    // both have to outlive the readback
+    let color = unsafe { OwnedRenderbuffer::new(&gl) };
+    let framebuffer = unsafe { OwnedFramebuffer::new(&gl) };
+
+    unsafe {
+        gl.bind_renderbuffer(RenderbufferTarget::RENDERBUFFER, color.handle());
+        gl.renderbuffer_storage(
+            RenderbufferTarget::RENDERBUFFER,
+            InternalFormat::RGBA8,
+            SIZE,
+            SIZE,
+        );
+
+        gl.bind_framebuffer(FramebufferTarget::FRAMEBUFFER, framebuffer.handle());
+        gl.framebuffer_renderbuffer(
+            FramebufferTarget::FRAMEBUFFER,
+            FramebufferAttachment::COLOR_ATTACHMENT0,
+            RenderbufferTarget::RENDERBUFFER,
+            color.handle(),
+        );
+
+        let status = gl.check_framebuffer_status(FramebufferTarget::FRAMEBUFFER);
+        if status != FramebufferStatus::COMPLETE {
+            return Err(format!("framebuffer is incomplete: {status}").into());
+        }
+
+        gl.viewport(0, 0, SIZE, SIZE);
+        gl.clear_color(0.2, 0.4, 0.8, 1.0);
+        gl.clear(ClearMask::COLOR);
+    }
+
+    let pixels = unsafe { gl.read_pixels_rgba8(0, 0, SIZE, SIZE) };
+    let readback = unsafe { PixelReadback::start(&gl, 0, 0, SIZE, SIZE) };
+    let matches = unsafe {
+        gl.finish();
+        readback.is_ready() && readback.finish() == pixels
+    };
+    if !matches {
+        return Err("pixel buffer readback doesn't match".into());
+    }
+
+    unsafe { gl.debug_marker("done") };
+    let messages = debug_messages.take();
+    for message in &messages {
+        log::info!("{message}");
+    }
+    if messages
+        .iter()
+        .any(|message| message.kind == DebugType::ERROR)
+    {
+        return Err("the debug output reported errors".into());
+    }
+
+    log::info!("cleared a {SIZE}x{SIZE} framebuffer to {:?}", &pixels[..4]);
+    Ok(())
+}
@@ -1,5 +1,3 @@
-use std::mem::MaybeUninit;
-
 use gl_bindings::gl43::{
     self as gl, Border, InternalFormat, SamplerParameterName, SamplerParameterValue,
     TextureDataFormat, TextureFormat, TextureTarget, TextureUnit,
@@ -31,18 +29,11 @@ fn create_vertex_buffer(gl: &gl::Api) -> gl::Buffer {
         .collect();
 
     unsafe {
-        let mut buffer = MaybeUninit::zeroed();
-        gl.gen_buffers(2, buffer.as_mut_ptr());
-        let vertex_buffer = buffer.assume_init();
-
+        let vertex_buffer = gl.gen_buffer();
         gl.bind_buffer(gl::BufferTarget::ARRAY_BUFFER, vertex_buffer);
-
-        gl.buffer_data(
+        gl.buffer_data_slice(
             gl::BufferTarget::ARRAY_BUFFER,
-            (vertex_data.len() * std::mem::size_of::<f32>())
-                .try_into()
-                .expect("we won't have more than 15 floats"),
-            vertex_data.as_ptr().cast(),
+            &vertex_data,
             gl::BufferUsage::STATIC_DRAW,
         );
         vertex_buffer
@@ -77,16 +68,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     window.make_current();
     window.set_key_polling(true);
 
-    let gl = unsafe { gl::Api::with_loader(&|s| glfw.get_proc_address_raw(s))? };
+    let gl = unsafe { gl::Api::try_with_loader(&|s| glfw.get_proc_address_raw(s)) }?;
     unsafe {
         gl.enable(gl::Capability::DEBUG_OUTPUT);
         gl.debug_message_callback(Some(debug_message_callback), std::ptr::null_mut());
     }
-    let vao = unsafe {
-        let mut vao = std::mem::MaybeUninit::zeroed();
-        gl.gen_vertex_arrays(1, vao.as_mut_ptr());
-        vao.assume_init()
-    };
+    let vao = unsafe { gl.gen_vertex_array() };
 
     unsafe {
         gl.clear_color(0.2, 0.2, 0.2, 1.0);
@@ -101,7 +88,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         let position_index = gl::AttributeIndex::new(0);
         gl.enable_vertex_attrib_array(position_index);
-        gl.vertex_attrib_format_ptr(
+        gl.vertex_attrib_format(
             position_index,
             gl::AttributeComponents::TWO,
             gl::VertexAttributeKind::FLOAT,
@@ -112,7 +99,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         let color_index = gl::AttributeIndex::new(1);
         gl.enable_vertex_attrib_array(color_index);
-        gl.vertex_attrib_format_ptr(
+        gl.vertex_attrib_format(
             color_index,
             gl::AttributeComponents::THREE,
             gl::VertexAttributeKind::FLOAT,
@@ -139,8 +126,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         let vs = gl.create_shader(gl::ShaderKind::VERTEX);
         let fs = gl.create_shader(gl::ShaderKind::FRAGMENT);
 
-        gl.shader_source(vs, 1, [VS_SOURCE].as_ptr().cast(), std::ptr::null());
-        gl.shader_source(fs, 1, [FS_SOURCE].as_ptr().cast(), std::ptr::null());
+        gl.shader_source_str(vs, VS_SOURCE);
+        gl.shader_source_str(fs, FS_SOURCE);
 
         gl.compile_shader(vs);
         gl.compile_shader(fs);
@@ -162,9 +149,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     let texture = unsafe {
-        let mut texture = MaybeUninit::zeroed();
-        gl.gen_textures(1, texture.as_mut_ptr());
-        let texture = texture.assume_init();
+        let texture = gl.gen_texture();
 
         gl.active_texture(TextureUnit::new(0));
         gl.bind_texture(TextureTarget::TEXTURE_2D, texture);
@@ -188,9 +173,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     let sampler = unsafe {
-        let mut sampler = MaybeUninit::zeroed();
-        gl.gen_samplers(1, sampler.as_mut_ptr());
-        let sampler = sampler.assume_init();
+        let sampler = gl.gen_sampler();
 
         gl.sampler_parameter_i(
             sampler,
@@ -229,7 +212,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     unsafe {
         gl.delete_program(program);
-        gl.delete_buffer(vertex_buffer);
+        gl.delete_buffers(1, [vertex_buffer].as_ptr());
         gl.delete_textures(1, [texture].as_ptr());
         gl.delete_samplers(1, [sampler].as_ptr());
     }
@@ -270,7 +253,7 @@ out vec3 vec_color;
 void main() {
     vec_color = color;
     gl_Position = vec4(pos.x, pos.y, 0.0, 1.0);
-}\0";
+}";
 
 const FS_SOURCE: &str = "#version 330 
 precision mediump float;
@@ -283,4 +266,4 @@ out vec4 color;
 void main() {
     color = texture(sampler, vec_color.xy);
     //color = vec4(vec_color, 1.0);
-}\0";
+}";
@@ -0,0 +1,256 @@
+//! A headless OpenGL context created through EGL, for rendering without a window or display,
+//! e.g. with Mesa's llvmpipe on machines without a GPU.
+//!
+//! Prefers a surfaceless context on the surfaceless platform and falls back to a 1x1 pbuffer
+//! on the default display. Render into a framebuffer object, the default framebuffer is either
+//! missing or too small to be of any use.
+use std::{
+    ffi::{c_char, c_void, CStr, CString},
+    ptr::addr_of_mut,
+};
+
+type EGLDisplay = *mut c_void;
+type EGLConfig = *mut c_void;
+type EGLContext = *mut c_void;
+type EGLSurface = *mut c_void;
+type EGLint = i32;
+type EGLenum = u32;
+type EGLBoolean = u32;
+
+const NO_DISPLAY: EGLDisplay = std::ptr::null_mut();
+const NO_CONTEXT: EGLContext = std::ptr::null_mut();
+const NO_SURFACE: EGLSurface = std::ptr::null_mut();
+const DEFAULT_DISPLAY: *mut c_void = std::ptr::null_mut();
+const FALSE: EGLBoolean = 0;
+
+const NONE: EGLint = 0x3038;
+const EXTENSIONS: EGLint = 0x3055;
+const SURFACE_TYPE: EGLint = 0x3033;
+const PBUFFER_BIT: EGLint = 0x0001;
+const RENDERABLE_TYPE: EGLint = 0x3040;
+const OPENGL_BIT: EGLint = 0x0008;
+const WIDTH: EGLint = 0x3057;
+const HEIGHT: EGLint = 0x3056;
+const OPENGL_API: EGLenum = 0x30A2;
+const CONTEXT_MAJOR_VERSION: EGLint = 0x3098;
+const CONTEXT_MINOR_VERSION: EGLint = 0x30FB;
+const CONTEXT_OPENGL_PROFILE_MASK: EGLint = 0x30FD;
+const CONTEXT_OPENGL_CORE_PROFILE_BIT: EGLint = 0x0001;
+const CONTEXT_OPENGL_DEBUG: EGLint = 0x31B0;
+const PLATFORM_SURFACELESS_MESA: EGLenum = 0x31DD;
+
+#[link(name = "EGL")]
+extern "system" {
+    fn eglGetError() -> EGLint;
+    fn eglQueryString(display: EGLDisplay, name: EGLint) -> *const c_char;
+    fn eglGetDisplay(native_display: *mut c_void) -> EGLDisplay;
+    fn eglGetPlatformDisplay(
+        platform: EGLenum,
+        native_display: *mut c_void,
+        attributes: *const isize,
+    ) -> EGLDisplay;
+    fn eglInitialize(display: EGLDisplay, major: *mut EGLint, minor: *mut EGLint) -> EGLBoolean;
+    fn eglTerminate(display: EGLDisplay) -> EGLBoolean;
+    fn eglBindAPI(api: EGLenum) -> EGLBoolean;
+    fn eglChooseConfig(
+        display: EGLDisplay,
+        attributes: *const EGLint,
+        configs: *mut EGLConfig,
+        config_size: EGLint,
+        config_count: *mut EGLint,
+    ) -> EGLBoolean;
+    fn eglCreateContext(
+        display: EGLDisplay,
+        config: EGLConfig,
+        share_context: EGLContext,
+        attributes: *const EGLint,
+    ) -> EGLContext;
+    fn eglDestroyContext(display: EGLDisplay, context: EGLContext) -> EGLBoolean;
+    fn eglCreatePbufferSurface(
+        display: EGLDisplay,
+        config: EGLConfig,
+        attributes: *const EGLint,
+    ) -> EGLSurface;
+    fn eglDestroySurface(display: EGLDisplay, surface: EGLSurface) -> EGLBoolean;
+    fn eglMakeCurrent(
+        display: EGLDisplay,
+        draw: EGLSurface,
+        read: EGLSurface,
+        context: EGLContext,
+    ) -> EGLBoolean;
+    fn eglGetProcAddress(name: *const c_char) -> *const c_void;
+}
+
+/// An EGL call that failed, with the code `eglGetError` reported for it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EglError {
+    pub call: &'static str,
+    pub code: EGLint,
+}
+
+impl std::fmt::Display for EglError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} failed with EGL error {:#06x}", self.call, self.code)
+    }
+}
+
+impl std::error::Error for EglError {}
+
+/// Turns a failed call into an `EglError`, `failed` tells whether the call returned its
+/// failure value.
+fn check(call: &'static str, failed: bool) -> Result<(), EglError> {
+    if !failed {
+        return Ok(());
+    }
+
+    // `EGL_SUCCESS` for failures that aren't errors, like `eglChooseConfig` finding no match
+    Err(EglError {
+        call,
+        code: unsafe { eglGetError() },
+    })
+}
+
+/// Whether the extensions of `display` include `extension`, `NO_DISPLAY` for client extensions.
+unsafe fn has_extension(display: EGLDisplay, extension: &str) -> bool {
+    let extensions = unsafe { eglQueryString(display, EXTENSIONS) };
+    if extensions.is_null() {
+        return false;
+    }
+
+    unsafe { CStr::from_ptr(extensions) }
+        .to_string_lossy()
+        .split(' ')
+        .any(|name| name == extension)
+}
+
+/// An OpenGL core profile context without a window, current on the thread that created it
+/// until dropped.
+pub struct HeadlessContext {
+    display: EGLDisplay,
+    context: EGLContext,
+    /// `NO_SURFACE` for surfaceless contexts.
+    surface: EGLSurface,
+}
+
+impl HeadlessContext {
+    /// Creates a core profile context of at least version `major.minor` and makes it current,
+    /// `debug` requests a debug context for `debug_message_callback`.
+    pub fn new(major: EGLint, minor: EGLint, debug: bool) -> Result<Self, EglError> {
+        unsafe {
+            let display = if has_extension(NO_DISPLAY, "EGL_MESA_platform_surfaceless") {
+                eglGetPlatformDisplay(PLATFORM_SURFACELESS_MESA, DEFAULT_DISPLAY, std::ptr::null())
+            } else {
+                eglGetDisplay(DEFAULT_DISPLAY)
+            };
+            check("eglGetDisplay", display == NO_DISPLAY)?;
+
+            let mut context = Self {
+                display,
+                context: NO_CONTEXT,
+                surface: NO_SURFACE,
+            };
+            context.init(major, minor, debug)?;
+            Ok(context)
+        }
+    }
+
+    /// Fills in the context and surface, `Drop` cleans up whatever was created on failure.
+    unsafe fn init(&mut self, major: EGLint, minor: EGLint, debug: bool) -> Result<(), EglError> {
+        unsafe {
+            let (mut egl_major, mut egl_minor) = (0, 0);
+            check(
+                "eglInitialize",
+                eglInitialize(
+                    self.display,
+                    addr_of_mut!(egl_major),
+                    addr_of_mut!(egl_minor),
+                ) == FALSE,
+            )?;
+            log::debug!("initialized EGL {egl_major}.{egl_minor}");
+
+            check("eglBindAPI", eglBindAPI(OPENGL_API) == FALSE)?;
+
+            let surfaceless = has_extension(self.display, "EGL_KHR_surfaceless_context");
+            let config_attributes = [
+                SURFACE_TYPE,
+                if surfaceless { 0 } else { PBUFFER_BIT },
+                RENDERABLE_TYPE,
+                OPENGL_BIT,
+                NONE,
+            ];
+            let mut config = std::ptr::null_mut();
+            let mut config_count = 0;
+            let chosen = eglChooseConfig(
+                self.display,
+                config_attributes.as_ptr(),
+                addr_of_mut!(config),
+                1,
+                addr_of_mut!(config_count),
+            );
+            check("eglChooseConfig", chosen == FALSE || config_count == 0)?;
+
+            let context_attributes = [
+                CONTEXT_MAJOR_VERSION,
+                major,
+                CONTEXT_MINOR_VERSION,
+                minor,
+                CONTEXT_OPENGL_PROFILE_MASK,
+                CONTEXT_OPENGL_CORE_PROFILE_BIT,
+                CONTEXT_OPENGL_DEBUG,
+                EGLint::from(debug),
+                NONE,
+            ];
+            self.context = eglCreateContext(
+                self.display,
+                config,
+                NO_CONTEXT,
+                context_attributes.as_ptr(),
+            );
+            check("eglCreateContext", self.context == NO_CONTEXT)?;
+
+            if !surfaceless {
+                let surface_attributes = [WIDTH, 1, HEIGHT, 1, NONE];
+                self.surface =
+                    eglCreatePbufferSurface(self.display, config, surface_attributes.as_ptr());
+                check("eglCreatePbufferSurface", self.surface == NO_SURFACE)?;
+            }
+
+            check(
+                "eglMakeCurrent",
+                eglMakeCurrent(self.display, self.surface, self.surface, self.context) == FALSE,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Looks up an OpenGL function, for `Api::with_loader`.
+    #[must_use]
+    pub fn proc_address(&self, symbol: &str) -> *const c_void {
+        let Ok(symbol) = CString::new(symbol) else {
+            return std::ptr::null();
+        };
+        unsafe { eglGetProcAddress(symbol.as_ptr()) }
+    }
+
+    /// Whether the context renders without any surface, only into framebuffer objects.
+    #[must_use]
+    pub fn is_surfaceless(&self) -> bool {
+        self.surface == NO_SURFACE
+    }
+}
+
+impl Drop for HeadlessContext {
+    fn drop(&mut self) {
+        unsafe {
+            eglMakeCurrent(self.display, NO_SURFACE, NO_SURFACE, NO_CONTEXT);
+            if self.surface != NO_SURFACE {
+                eglDestroySurface(self.display, self.surface);
+            }
+            if self.context != NO_CONTEXT {
+                eglDestroyContext(self.display, self.context);
+            }
+            eglTerminate(self.display);
+        }
+    }
+}
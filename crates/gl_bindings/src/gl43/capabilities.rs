@@ -0,0 +1,125 @@
+use std::{collections::HashSet, ffi::CStr, ptr::addr_of_mut};
+
+use super::{Api, GLint, GLubyte, GLuint, StateParameterName, StringName};
+
+/// What the current context reports about itself, to pick fallback paths or tell the user
+/// what their driver lacks.
+#[derive(Clone, Debug, Default)]
+pub struct Capabilities {
+    /// `(major, minor)`, e.g. `(4, 6)`.
+    pub version: (GLint, GLint),
+    /// The full `GL_VERSION` string, the version followed by vendor specific information.
+    pub version_string: String,
+    pub glsl_version: String,
+    pub vendor: String,
+    pub renderer: String,
+    pub extensions: HashSet<String>,
+    pub limits: Limits,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Limits {
+    pub max_texture_size: GLint,
+    pub max_texture_image_units: GLint,
+    pub max_combined_texture_image_units: GLint,
+    pub max_vertex_attribs: GLint,
+    pub max_samples: GLint,
+    pub max_uniform_buffer_bindings: GLint,
+    pub max_uniform_block_size: GLint,
+    pub max_shader_storage_buffer_bindings: GLint,
+    pub max_shader_storage_block_size: GLint,
+}
+
+impl Capabilities {
+    /// Queries the current context.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the calling thread.
+    #[must_use]
+    pub unsafe fn query(gl: &Api) -> Self {
+        unsafe {
+            let extension_count = GLuint::try_from(integer(gl, StateParameterName::NUM_EXTENSIONS))
+                .unwrap_or_default();
+
+            Self {
+                version: (
+                    integer(gl, StateParameterName::MAJOR_VERSION),
+                    integer(gl, StateParameterName::MINOR_VERSION),
+                ),
+                version_string: string(gl.get_string(StringName::VERSION)),
+                glsl_version: string(gl.get_string(StringName::SHADING_LANGUAGE_VERSION)),
+                vendor: string(gl.get_string(StringName::VENDOR)),
+                renderer: string(gl.get_string(StringName::RENDERER)),
+                extensions: (0..extension_count)
+                    .map(|index| string(gl.get_stringi(StringName::EXTENSIONS, index)))
+                    .collect(),
+                limits: Limits {
+                    max_texture_size: integer(gl, StateParameterName::MAX_TEXTURE_SIZE),
+                    max_texture_image_units: integer(
+                        gl,
+                        StateParameterName::MAX_TEXTURE_IMAGE_UNITS,
+                    ),
+                    max_combined_texture_image_units: integer(
+                        gl,
+                        StateParameterName::MAX_COMBINED_TEXTURE_IMAGE_UNITS,
+                    ),
+                    max_vertex_attribs: integer(gl, StateParameterName::MAX_VERTEX_ATTRIBS),
+                    max_samples: integer(gl, StateParameterName::MAX_SAMPLES),
+                    max_uniform_buffer_bindings: integer(
+                        gl,
+                        StateParameterName::MAX_UNIFORM_BUFFER_BINDINGS,
+                    ),
+                    max_uniform_block_size: integer(gl, StateParameterName::MAX_UNIFORM_BLOCK_SIZE),
+                    max_shader_storage_buffer_bindings: integer(
+                        gl,
+                        StateParameterName::MAX_SHADER_STORAGE_BUFFER_BINDINGS,
+                    ),
+                    max_shader_storage_block_size: integer(
+                        gl,
+                        StateParameterName::MAX_SHADER_STORAGE_BLOCK_SIZE,
+                    ),
+                },
+            }
+        }
+    }
+
+    /// Whether the context is at least version `major.minor`.
+    #[must_use]
+    pub fn supports(&self, major: GLint, minor: GLint) -> bool {
+        self.version >= (major, minor)
+    }
+
+    /// Whether the context supports the extension `name`, e.g. `GL_ARB_bindless_texture`.
+    #[must_use]
+    pub fn has_extension(&self, name: &str) -> bool {
+        self.extensions.contains(name)
+    }
+}
+
+impl std::fmt::Display for Capabilities {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "OpenGL {} (GLSL {}) on {} by {}",
+            self.version_string, self.glsl_version, self.renderer, self.vendor
+        )
+    }
+}
+
+unsafe fn integer(gl: &Api, pname: StateParameterName) -> GLint {
+    let mut value = 0;
+    unsafe { gl.get_integer_v(pname, addr_of_mut!(value)) };
+    value
+}
+
+/// Copies a string returned by `get_string`, null turns into an empty string.
+unsafe fn string(ptr: *const GLubyte) -> String {
+    if ptr.is_null() {
+        return String::new();
+    }
+
+    unsafe { CStr::from_ptr(ptr.cast()) }
+        .to_string_lossy()
+        .into_owned()
+}
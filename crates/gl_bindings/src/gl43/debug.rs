@@ -0,0 +1,333 @@
+use std::{
+    ffi::{c_void, CStr},
+    ptr::addr_of_mut,
+    rc::Rc,
+    sync::{Mutex, PoisonError},
+};
+
+use super::{
+    Api, Buffer, Capability, DebugSeverity, DebugSource, DebugType, Framebuffer, GLchar, GLint,
+    GLsizei, GLuint, ObjectIdentifier, Program, Query, Renderbuffer, Sampler, Shader,
+    StateParameterName, Texture, VertexArray,
+};
+
+/// An object handle that can be named with `Api::label`.
+pub trait Object: Copy {
+    const IDENTIFIER: ObjectIdentifier;
+
+    fn raw(self) -> GLuint;
+}
+
+macro_rules! object {
+    ($($handle:ident => $identifier:ident),* $(,)?) => {
+        $(
+            impl Object for $handle {
+                const IDENTIFIER: ObjectIdentifier = ObjectIdentifier::$identifier;
+
+                fn raw(self) -> GLuint {
+                    self.0
+                }
+            }
+        )*
+    };
+}
+
+object! {
+    Buffer => BUFFER,
+    Shader => SHADER,
+    Program => PROGRAM,
+    VertexArray => VERTEX_ARRAY,
+    Sampler => SAMPLER,
+    Texture => TEXTURE,
+    Renderbuffer => RENDERBUFFER,
+    Framebuffer => FRAMEBUFFER,
+    Query => QUERY,
+}
+
+fn length(text: &str) -> GLsizei {
+    text.len().try_into().expect("debug string should fit")
+}
+
+impl Api {
+    /// Names `object` in debug messages and frame debuggers.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the calling thread and that `object` belongs to it.
+    pub unsafe fn label<T: Object>(&self, object: T, label: &str) {
+        unsafe {
+            self.object_label(
+                T::IDENTIFIER,
+                object.raw(),
+                length(label),
+                label.as_ptr().cast(),
+            );
+        }
+    }
+
+    /// Inserts `message` as an application marker into the debug output.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the calling thread.
+    pub unsafe fn debug_marker(&self, message: &str) {
+        unsafe {
+            self.debug_message_insert(
+                DebugSource::APPLICATION,
+                DebugType::MARKER,
+                0,
+                DebugSeverity::NOTIFICATION,
+                length(message),
+                message.as_ptr().cast(),
+            );
+        }
+    }
+}
+
+/// A message of the debug output, see `DebugCollector` and `Api::debug_message_log`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DebugMessage {
+    pub source: DebugSource,
+    pub kind: DebugType,
+    pub id: GLuint,
+    pub severity: DebugSeverity,
+    pub text: String,
+}
+
+impl std::fmt::Display for DebugMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} from {}: {}",
+            self.id, self.kind, self.source, self.text
+        )
+    }
+}
+
+impl Api {
+    /// Enables or disables the debug messages matching `source`, `kind` and `severity`, use
+    /// `DONT_CARE` to match any. Non-empty `ids` limit it to those messages, which only works
+    /// with a specific source and type and `DebugSeverity::DONT_CARE`.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the calling thread.
+    ///
+    /// # Panics
+    /// If there are more than `GLsizei::MAX` ids.
+    pub unsafe fn set_debug_messages_enabled(
+        &self,
+        source: DebugSource,
+        kind: DebugType,
+        severity: DebugSeverity,
+        ids: &[GLuint],
+        enabled: bool,
+    ) {
+        unsafe {
+            self.debug_message_control(
+                source,
+                kind,
+                severity,
+                ids.len().try_into().expect("debug message ids should fit"),
+                ids.as_ptr(),
+                enabled.into(),
+            );
+        }
+    }
+
+    /// Disables the debug messages of `source` and `kind` with the given `ids`, e.g. known
+    /// noise of a driver. Ids are only unique per source and type.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the calling thread.
+    pub unsafe fn mute_debug_messages(&self, source: DebugSource, kind: DebugType, ids: &[GLuint]) {
+        unsafe {
+            self.set_debug_messages_enabled(source, kind, DebugSeverity::DONT_CARE, ids, false);
+        }
+    }
+
+    /// Takes all messages out of the debug message log, oldest first. The context only logs
+    /// messages while no callback is set.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the calling thread.
+    #[must_use]
+    pub unsafe fn debug_message_log(&self) -> Vec<DebugMessage> {
+        let (mut count, mut max_length): (GLint, GLint) = (0, 0);
+        unsafe {
+            self.get_integer_v(
+                StateParameterName::DEBUG_LOGGED_MESSAGES,
+                addr_of_mut!(count),
+            );
+            self.get_integer_v(
+                StateParameterName::MAX_DEBUG_MESSAGE_LENGTH,
+                addr_of_mut!(max_length),
+            );
+        }
+
+        let Ok(requested) = GLuint::try_from(count) else {
+            return Vec::new();
+        };
+        let count = usize::try_from(count).unwrap_or_default();
+        let max_length = usize::try_from(max_length).unwrap_or_default();
+
+        let mut sources = vec![DebugSource::OTHER; count];
+        let mut kinds = vec![DebugType::OTHER; count];
+        let mut ids = vec![0; count];
+        let mut severities = vec![DebugSeverity::NOTIFICATION; count];
+        let mut lengths = vec![0; count];
+        let mut text = vec![0_u8; count * max_length];
+
+        let fetched = unsafe {
+            self.get_debug_message_log(
+                requested,
+                text.len().try_into().unwrap_or(GLsizei::MAX),
+                sources.as_mut_ptr(),
+                kinds.as_mut_ptr(),
+                ids.as_mut_ptr(),
+                severities.as_mut_ptr(),
+                lengths.as_mut_ptr(),
+                text.as_mut_ptr().cast(),
+            )
+        };
+
+        let mut offset = 0;
+        (0..usize::try_from(fetched).unwrap_or_default())
+            .map(|index| {
+                // the lengths include the nul terminator
+                let length = usize::try_from(lengths[index]).unwrap_or_default();
+                let bytes = &text[offset..offset + length.saturating_sub(1)];
+                offset += length;
+
+                DebugMessage {
+                    source: sources[index],
+                    kind: kinds[index],
+                    id: ids[index],
+                    severity: severities[index],
+                    text: String::from_utf8_lossy(bytes).into_owned(),
+                }
+            })
+            .collect()
+    }
+}
+
+type Messages = Mutex<Vec<DebugMessage>>;
+
+/// Collects the messages of the debug output as `DebugMessage`s until they are taken.
+///
+/// Tests can assert on them and the application can log or display them when it suits it.
+/// Replaces any other debug message callback while alive.
+pub struct DebugCollector {
+    gl: Rc<Api>,
+    /// Boxed so the address handed to the callback stays put, the callback may run on other
+    /// threads unless `Capability::DEBUG_OUTPUT_SYNCHRONOUS` is enabled.
+    messages: Box<Messages>,
+}
+
+impl DebugCollector {
+    /// Enables the debug output and starts collecting. Needs a debug context to receive more
+    /// than the application's own messages on most drivers.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread creating and dropping the collector.
+    #[must_use]
+    pub unsafe fn install(gl: &Rc<Api>) -> Self {
+        let messages = Box::new(Mutex::new(Vec::new()));
+
+        unsafe {
+            gl.enable(Capability::DEBUG_OUTPUT);
+            gl.debug_message_callback(Some(collect), std::ptr::from_ref(&*messages).cast());
+        }
+
+        Self {
+            gl: Rc::clone(gl),
+            messages,
+        }
+    }
+
+    /// Returns the messages collected since the last call, oldest first.
+    #[must_use]
+    pub fn take(&self) -> Vec<DebugMessage> {
+        std::mem::take(&mut *self.messages.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+impl Drop for DebugCollector {
+    fn drop(&mut self) {
+        unsafe { self.gl.debug_message_callback(None, std::ptr::null()) };
+    }
+}
+
+impl std::fmt::Debug for DebugCollector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DebugCollector")
+            .field("messages", &self.messages)
+            .finish_non_exhaustive()
+    }
+}
+
+extern "system" fn collect(
+    source: DebugSource,
+    kind: DebugType,
+    id: GLuint,
+    severity: DebugSeverity,
+    length: GLsizei,
+    message: *const GLchar,
+    user_param: *mut c_void,
+) {
+    // Safety: `DebugCollector` keeps the messages alive while the callback is set
+    let messages = unsafe { &*user_param.cast::<Messages>() };
+
+    // a negative length means the message is nul terminated
+    let text = usize::try_from(length).map_or_else(
+        |_| unsafe { CStr::from_ptr(message) }.to_bytes(),
+        |length| unsafe { std::slice::from_raw_parts(message.cast::<u8>(), length) },
+    );
+
+    messages
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(DebugMessage {
+            source,
+            kind,
+            id,
+            severity,
+            text: String::from_utf8_lossy(text).into_owned(),
+        });
+}
+
+/// A named debug group that closes when dropped, groups the calls in between for frame
+/// debuggers and nests the debug messages.
+#[must_use = "the group is closed when the scope is dropped"]
+pub struct DebugScope<'a> {
+    gl: &'a Api,
+}
+
+impl<'a> DebugScope<'a> {
+    /// Pushes a debug group named `name`.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread creating and dropping the scope.
+    pub unsafe fn new(gl: &'a Api, name: &str) -> Self {
+        unsafe {
+            gl.push_debug_group(
+                DebugSource::APPLICATION,
+                0,
+                length(name),
+                name.as_ptr().cast(),
+            );
+        }
+
+        Self { gl }
+    }
+}
+
+impl Drop for DebugScope<'_> {
+    fn drop(&mut self) {
+        unsafe { self.gl.pop_debug_group() };
+    }
+}
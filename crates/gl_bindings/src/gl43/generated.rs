@@ -0,0 +1,8011 @@
+// Generated by `cargo run -p gl_generator` from `registry/gl.xml` and `registry/gl43.txt`.
+// Do not edit by hand, change the allow-list and regenerate instead.
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Capability(GLenum);
+
+impl Capability {
+    pub const DEBUG_OUTPUT: Self = Self(0x92E0);
+    pub const DEBUG_OUTPUT_SYNCHRONOUS: Self = Self(0x8242);
+    pub const DEPTH: Self = Self(0x0B71);
+    pub const CULL_FACE: Self = Self(0x0B44);
+    pub const BLEND: Self = Self(0x0BE2);
+    pub const STENCIL: Self = Self(0x0B90);
+    pub const SCISSOR: Self = Self(0x0C11);
+    pub const POLYGON_OFFSET_FILL: Self = Self(0x8037);
+}
+
+impl std::fmt::Display for Capability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::DEBUG_OUTPUT => write!(f, "DEBUG_OUTPUT"),
+            Self::DEBUG_OUTPUT_SYNCHRONOUS => write!(f, "DEBUG_OUTPUT_SYNCHRONOUS"),
+            Self::DEPTH => write!(f, "DEPTH"),
+            Self::CULL_FACE => write!(f, "CULL_FACE"),
+            Self::BLEND => write!(f, "BLEND"),
+            Self::STENCIL => write!(f, "STENCIL"),
+            Self::SCISSOR => write!(f, "SCISSOR"),
+            Self::POLYGON_OFFSET_FILL => write!(f, "POLYGON_OFFSET_FILL"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct StateParameterName(GLenum);
+
+impl StateParameterName {
+    pub const MAJOR_VERSION: Self = Self(0x821B);
+    pub const MINOR_VERSION: Self = Self(0x821C);
+    pub const UNIFORM_BUFFER_OFFSET_ALIGNMENT: Self = Self(0x8A34);
+    pub const MAX_UNIFORM_BUFFER_BINDINGS: Self = Self(0x8A2F);
+    pub const MAX_UNIFORM_BLOCK_SIZE: Self = Self(0x8A30);
+    pub const SHADER_STORAGE_BUFFER_OFFSET_ALIGNMENT: Self = Self(0x90DF);
+    pub const MAX_SHADER_STORAGE_BUFFER_BINDINGS: Self = Self(0x90DD);
+    pub const MAX_SHADER_STORAGE_BLOCK_SIZE: Self = Self(0x90DE);
+    pub const NUM_EXTENSIONS: Self = Self(0x821D);
+    pub const MAX_TEXTURE_SIZE: Self = Self(0x0D33);
+    pub const MAX_TEXTURE_IMAGE_UNITS: Self = Self(0x8872);
+    pub const MAX_COMBINED_TEXTURE_IMAGE_UNITS: Self = Self(0x8B4D);
+    pub const MAX_VERTEX_ATTRIBS: Self = Self(0x8869);
+    pub const MAX_SAMPLES: Self = Self(0x8D57);
+    pub const MAX_DEBUG_MESSAGE_LENGTH: Self = Self(0x9143);
+    pub const DEBUG_LOGGED_MESSAGES: Self = Self(0x9145);
+}
+
+impl std::fmt::Display for StateParameterName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::MAJOR_VERSION => write!(f, "MAJOR_VERSION"),
+            Self::MINOR_VERSION => write!(f, "MINOR_VERSION"),
+            Self::UNIFORM_BUFFER_OFFSET_ALIGNMENT => write!(f, "UNIFORM_BUFFER_OFFSET_ALIGNMENT"),
+            Self::MAX_UNIFORM_BUFFER_BINDINGS => write!(f, "MAX_UNIFORM_BUFFER_BINDINGS"),
+            Self::MAX_UNIFORM_BLOCK_SIZE => write!(f, "MAX_UNIFORM_BLOCK_SIZE"),
+            Self::SHADER_STORAGE_BUFFER_OFFSET_ALIGNMENT => {
+                write!(f, "SHADER_STORAGE_BUFFER_OFFSET_ALIGNMENT")
+            }
+            Self::MAX_SHADER_STORAGE_BUFFER_BINDINGS => {
+                write!(f, "MAX_SHADER_STORAGE_BUFFER_BINDINGS")
+            }
+            Self::MAX_SHADER_STORAGE_BLOCK_SIZE => write!(f, "MAX_SHADER_STORAGE_BLOCK_SIZE"),
+            Self::NUM_EXTENSIONS => write!(f, "NUM_EXTENSIONS"),
+            Self::MAX_TEXTURE_SIZE => write!(f, "MAX_TEXTURE_SIZE"),
+            Self::MAX_TEXTURE_IMAGE_UNITS => write!(f, "MAX_TEXTURE_IMAGE_UNITS"),
+            Self::MAX_COMBINED_TEXTURE_IMAGE_UNITS => write!(f, "MAX_COMBINED_TEXTURE_IMAGE_UNITS"),
+            Self::MAX_VERTEX_ATTRIBS => write!(f, "MAX_VERTEX_ATTRIBS"),
+            Self::MAX_SAMPLES => write!(f, "MAX_SAMPLES"),
+            Self::MAX_DEBUG_MESSAGE_LENGTH => write!(f, "MAX_DEBUG_MESSAGE_LENGTH"),
+            Self::DEBUG_LOGGED_MESSAGES => write!(f, "DEBUG_LOGGED_MESSAGES"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ErrorCode(GLenum);
+
+impl ErrorCode {
+    pub const NO_ERROR: Self = Self(0);
+    pub const INVALID_ENUM: Self = Self(0x0500);
+    pub const INVALID_VALUE: Self = Self(0x0501);
+    pub const INVALID_OPERATION: Self = Self(0x0502);
+    pub const INVALID_FRAMEBUFFER_OPERATION: Self = Self(0x0506);
+    pub const OUT_OF_MEMORY: Self = Self(0x0505);
+    pub const STACK_UNDERFLOW: Self = Self(0x0504);
+    pub const STACK_OVERFLOW: Self = Self(0x0503);
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::NO_ERROR => write!(f, "NO_ERROR"),
+            Self::INVALID_ENUM => write!(f, "INVALID_ENUM"),
+            Self::INVALID_VALUE => write!(f, "INVALID_VALUE"),
+            Self::INVALID_OPERATION => write!(f, "INVALID_OPERATION"),
+            Self::INVALID_FRAMEBUFFER_OPERATION => write!(f, "INVALID_FRAMEBUFFER_OPERATION"),
+            Self::OUT_OF_MEMORY => write!(f, "OUT_OF_MEMORY"),
+            Self::STACK_UNDERFLOW => write!(f, "STACK_UNDERFLOW"),
+            Self::STACK_OVERFLOW => write!(f, "STACK_OVERFLOW"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct StringName(GLenum);
+
+impl StringName {
+    pub const VENDOR: Self = Self(0x1F00);
+    pub const RENDERER: Self = Self(0x1F01);
+    pub const VERSION: Self = Self(0x1F02);
+    pub const SHADING_LANGUAGE_VERSION: Self = Self(0x8B8C);
+    pub const EXTENSIONS: Self = Self(0x1F03);
+}
+
+impl std::fmt::Display for StringName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::VENDOR => write!(f, "VENDOR"),
+            Self::RENDERER => write!(f, "RENDERER"),
+            Self::VERSION => write!(f, "VERSION"),
+            Self::SHADING_LANGUAGE_VERSION => write!(f, "SHADING_LANGUAGE_VERSION"),
+            Self::EXTENSIONS => write!(f, "EXTENSIONS"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct DepthFunc(GLenum);
+
+impl DepthFunc {
+    pub const NEVER: Self = Self(0x0200);
+    pub const LESS: Self = Self(0x0201);
+    pub const EQUAL: Self = Self(0x0202);
+    pub const LEQUAL: Self = Self(0x0203);
+    pub const GREATER: Self = Self(0x0204);
+    pub const NOTEQUAL: Self = Self(0x0205);
+    pub const GEQUAL: Self = Self(0x0206);
+    pub const ALWAYS: Self = Self(0x0207);
+}
+
+impl std::fmt::Display for DepthFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::NEVER => write!(f, "NEVER"),
+            Self::LESS => write!(f, "LESS"),
+            Self::EQUAL => write!(f, "EQUAL"),
+            Self::LEQUAL => write!(f, "LEQUAL"),
+            Self::GREATER => write!(f, "GREATER"),
+            Self::NOTEQUAL => write!(f, "NOTEQUAL"),
+            Self::GEQUAL => write!(f, "GEQUAL"),
+            Self::ALWAYS => write!(f, "ALWAYS"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct StencilFunc(GLenum);
+
+impl StencilFunc {
+    pub const NEVER: Self = Self(0x0200);
+    pub const LESS: Self = Self(0x0201);
+    pub const EQUAL: Self = Self(0x0202);
+    pub const LEQUAL: Self = Self(0x0203);
+    pub const GREATER: Self = Self(0x0204);
+    pub const NOTEQUAL: Self = Self(0x0205);
+    pub const GEQUAL: Self = Self(0x0206);
+    pub const ALWAYS: Self = Self(0x0207);
+}
+
+impl std::fmt::Display for StencilFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::NEVER => write!(f, "NEVER"),
+            Self::LESS => write!(f, "LESS"),
+            Self::EQUAL => write!(f, "EQUAL"),
+            Self::LEQUAL => write!(f, "LEQUAL"),
+            Self::GREATER => write!(f, "GREATER"),
+            Self::NOTEQUAL => write!(f, "NOTEQUAL"),
+            Self::GEQUAL => write!(f, "GEQUAL"),
+            Self::ALWAYS => write!(f, "ALWAYS"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct StencilOp(GLenum);
+
+impl StencilOp {
+    pub const KEEP: Self = Self(0x1E00);
+    pub const ZERO: Self = Self(0);
+    pub const REPLACE: Self = Self(0x1E01);
+    pub const INCR: Self = Self(0x1E02);
+    pub const INCR_WRAP: Self = Self(0x8507);
+    pub const DECR: Self = Self(0x1E03);
+    pub const DECR_WRAP: Self = Self(0x8508);
+    pub const INVERT: Self = Self(0x150A);
+}
+
+impl std::fmt::Display for StencilOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::KEEP => write!(f, "KEEP"),
+            Self::ZERO => write!(f, "ZERO"),
+            Self::REPLACE => write!(f, "REPLACE"),
+            Self::INCR => write!(f, "INCR"),
+            Self::INCR_WRAP => write!(f, "INCR_WRAP"),
+            Self::DECR => write!(f, "DECR"),
+            Self::DECR_WRAP => write!(f, "DECR_WRAP"),
+            Self::INVERT => write!(f, "INVERT"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct BlendFactor(GLenum);
+
+impl BlendFactor {
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(1);
+    pub const SRC_COLOR: Self = Self(0x0300);
+    pub const ONE_MINUS_SRC_COLOR: Self = Self(0x0301);
+    pub const DST_COLOR: Self = Self(0x0306);
+    pub const ONE_MINUS_DST_COLOR: Self = Self(0x0307);
+    pub const SRC_ALPHA: Self = Self(0x0302);
+    pub const ONE_MINUS_SRC_ALPHA: Self = Self(0x0303);
+    pub const DST_ALPHA: Self = Self(0x0304);
+    pub const ONE_MINUS_DST_ALPHA: Self = Self(0x0305);
+    pub const CONSTANT_COLOR: Self = Self(0x8001);
+    pub const ONE_MINUS_CONSTANT_COLOR: Self = Self(0x8002);
+    pub const CONSTANT_ALPHA: Self = Self(0x8003);
+    pub const ONE_MINUS_CONSTANT_ALPHA: Self = Self(0x8004);
+    pub const SRC_ALPHA_SATURATE: Self = Self(0x0308);
+}
+
+impl std::fmt::Display for BlendFactor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::ZERO => write!(f, "ZERO"),
+            Self::ONE => write!(f, "ONE"),
+            Self::SRC_COLOR => write!(f, "SRC_COLOR"),
+            Self::ONE_MINUS_SRC_COLOR => write!(f, "ONE_MINUS_SRC_COLOR"),
+            Self::DST_COLOR => write!(f, "DST_COLOR"),
+            Self::ONE_MINUS_DST_COLOR => write!(f, "ONE_MINUS_DST_COLOR"),
+            Self::SRC_ALPHA => write!(f, "SRC_ALPHA"),
+            Self::ONE_MINUS_SRC_ALPHA => write!(f, "ONE_MINUS_SRC_ALPHA"),
+            Self::DST_ALPHA => write!(f, "DST_ALPHA"),
+            Self::ONE_MINUS_DST_ALPHA => write!(f, "ONE_MINUS_DST_ALPHA"),
+            Self::CONSTANT_COLOR => write!(f, "CONSTANT_COLOR"),
+            Self::ONE_MINUS_CONSTANT_COLOR => write!(f, "ONE_MINUS_CONSTANT_COLOR"),
+            Self::CONSTANT_ALPHA => write!(f, "CONSTANT_ALPHA"),
+            Self::ONE_MINUS_CONSTANT_ALPHA => write!(f, "ONE_MINUS_CONSTANT_ALPHA"),
+            Self::SRC_ALPHA_SATURATE => write!(f, "SRC_ALPHA_SATURATE"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct BlendEquation(GLenum);
+
+impl BlendEquation {
+    pub const ADD: Self = Self(0x8006);
+    pub const SUBTRACT: Self = Self(0x800A);
+    pub const REVERSE_SUBTRACT: Self = Self(0x800B);
+    pub const MIN: Self = Self(0x8007);
+    pub const MAX: Self = Self(0x8008);
+}
+
+impl std::fmt::Display for BlendEquation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::ADD => write!(f, "ADD"),
+            Self::SUBTRACT => write!(f, "SUBTRACT"),
+            Self::REVERSE_SUBTRACT => write!(f, "REVERSE_SUBTRACT"),
+            Self::MIN => write!(f, "MIN"),
+            Self::MAX => write!(f, "MAX"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Face(GLenum);
+
+impl Face {
+    pub const FRONT: Self = Self(0x0404);
+    pub const BACK: Self = Self(0x0405);
+    pub const FRONT_AND_BACK: Self = Self(0x0408);
+}
+
+impl std::fmt::Display for Face {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::FRONT => write!(f, "FRONT"),
+            Self::BACK => write!(f, "BACK"),
+            Self::FRONT_AND_BACK => write!(f, "FRONT_AND_BACK"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct FrontFace(GLenum);
+
+impl FrontFace {
+    pub const CW: Self = Self(0x0900);
+    pub const CCW: Self = Self(0x0901);
+}
+
+impl std::fmt::Display for FrontFace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::CW => write!(f, "CW"),
+            Self::CCW => write!(f, "CCW"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct PolygonMode(GLenum);
+
+impl PolygonMode {
+    pub const POINT: Self = Self(0x1B00);
+    pub const LINE: Self = Self(0x1B01);
+    pub const FILL: Self = Self(0x1B02);
+}
+
+impl std::fmt::Display for PolygonMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::POINT => write!(f, "POINT"),
+            Self::LINE => write!(f, "LINE"),
+            Self::FILL => write!(f, "FILL"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct BufferTarget(GLenum);
+
+impl BufferTarget {
+    // GL 3.3
+    pub const ARRAY_BUFFER: Self = Self(0x8892);
+    pub const COPY_READ_BUFFER: Self = Self(0x8F36);
+    pub const COPY_WRITE_BUFFER: Self = Self(0x8F37);
+    pub const ELEMENT_ARRAY_BUFFER: Self = Self(0x8893);
+    pub const UNIFORM_BUFFER: Self = Self(0x8A11);
+    pub const TEXTURE_BUFFER: Self = Self(0x8C2A);
+    pub const PIXEL_PACK_BUFFER: Self = Self(0x88EB);
+    pub const PIXEL_UNPACK_BUFFER: Self = Self(0x88EC);
+    // since 4.3
+    pub const SHADER_STORAGE_BUFFER: Self = Self(0x90D2);
+    pub const DISPATCH_INDIRECT_BUFFER: Self = Self(0x90EE);
+    pub const DRAW_INDIRECT_BUFFER: Self = Self(0x8F3F);
+}
+
+impl std::fmt::Display for BufferTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::ARRAY_BUFFER => write!(f, "ARRAY_BUFFER"),
+            Self::COPY_READ_BUFFER => write!(f, "COPY_READ_BUFFER"),
+            Self::COPY_WRITE_BUFFER => write!(f, "COPY_WRITE_BUFFER"),
+            Self::ELEMENT_ARRAY_BUFFER => write!(f, "ELEMENT_ARRAY_BUFFER"),
+            Self::UNIFORM_BUFFER => write!(f, "UNIFORM_BUFFER"),
+            Self::TEXTURE_BUFFER => write!(f, "TEXTURE_BUFFER"),
+            Self::PIXEL_PACK_BUFFER => write!(f, "PIXEL_PACK_BUFFER"),
+            Self::PIXEL_UNPACK_BUFFER => write!(f, "PIXEL_UNPACK_BUFFER"),
+            Self::SHADER_STORAGE_BUFFER => write!(f, "SHADER_STORAGE_BUFFER"),
+            Self::DISPATCH_INDIRECT_BUFFER => write!(f, "DISPATCH_INDIRECT_BUFFER"),
+            Self::DRAW_INDIRECT_BUFFER => write!(f, "DRAW_INDIRECT_BUFFER"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct BufferUsage(GLenum);
+
+impl BufferUsage {
+    pub const STREAM_DRAW: Self = Self(0x88E0);
+    pub const STREAM_READ: Self = Self(0x88E1);
+    pub const STREAM_COPY: Self = Self(0x88E2);
+    pub const STATIC_DRAW: Self = Self(0x88E4);
+    pub const STATIC_READ: Self = Self(0x88E5);
+    pub const STATIC_COPY: Self = Self(0x88E6);
+    pub const DYNAMIC_DRAW: Self = Self(0x88E8);
+    pub const DYNAMIC_READ: Self = Self(0x88E9);
+    pub const DYNAMIC_COPY: Self = Self(0x88EA);
+}
+
+impl std::fmt::Display for BufferUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::STREAM_DRAW => write!(f, "STREAM_DRAW"),
+            Self::STREAM_READ => write!(f, "STREAM_READ"),
+            Self::STREAM_COPY => write!(f, "STREAM_COPY"),
+            Self::STATIC_DRAW => write!(f, "STATIC_DRAW"),
+            Self::STATIC_READ => write!(f, "STATIC_READ"),
+            Self::STATIC_COPY => write!(f, "STATIC_COPY"),
+            Self::DYNAMIC_DRAW => write!(f, "DYNAMIC_DRAW"),
+            Self::DYNAMIC_READ => write!(f, "DYNAMIC_READ"),
+            Self::DYNAMIC_COPY => write!(f, "DYNAMIC_COPY"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct VertexAttributeKind(GLenum);
+
+impl VertexAttributeKind {
+    pub const BYTE: Self = Self(0x1400);
+    pub const UNSIGNED_BYTE: Self = Self(0x1401);
+    pub const SHORT: Self = Self(0x1402);
+    pub const UNSIGNED_SHORT: Self = Self(0x1403);
+    pub const INT: Self = Self(0x1404);
+    pub const UNSIGNED_INT: Self = Self(0x1405);
+    pub const FLOAT: Self = Self(0x1406);
+    pub const HALF_FLOAT: Self = Self(0x140B);
+    pub const INT_2_10_10_10_REV: Self = Self(0x8D9F);
+    pub const UNSIGNED_INT_2_10_10_10_REV: Self = Self(0x8368);
+}
+
+impl std::fmt::Display for VertexAttributeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::BYTE => write!(f, "BYTE"),
+            Self::UNSIGNED_BYTE => write!(f, "UNSIGNED_BYTE"),
+            Self::SHORT => write!(f, "SHORT"),
+            Self::UNSIGNED_SHORT => write!(f, "UNSIGNED_SHORT"),
+            Self::INT => write!(f, "INT"),
+            Self::UNSIGNED_INT => write!(f, "UNSIGNED_INT"),
+            Self::FLOAT => write!(f, "FLOAT"),
+            Self::HALF_FLOAT => write!(f, "HALF_FLOAT"),
+            Self::INT_2_10_10_10_REV => write!(f, "INT_2_10_10_10_REV"),
+            Self::UNSIGNED_INT_2_10_10_10_REV => write!(f, "UNSIGNED_INT_2_10_10_10_REV"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct BufferStorageFlags(GLbitField);
+
+impl BufferStorageFlags {
+    pub const DYNAMIC_STORAGE: Self = Self(0x0100);
+    pub const MAP_READ: Self = Self(0x0001);
+    pub const MAP_WRITE: Self = Self(0x0002);
+    pub const MAP_PERSISTENT: Self = Self(0x0040);
+    pub const MAP_COHERENT: Self = Self(0x0080);
+    pub const CLIENT_STORAGE: Self = Self(0x0200);
+}
+
+impl std::fmt::Display for BufferStorageFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut separator = "";
+        for (flag, name) in [
+            (Self::DYNAMIC_STORAGE, "DYNAMIC_STORAGE"),
+            (Self::MAP_READ, "MAP_READ"),
+            (Self::MAP_WRITE, "MAP_WRITE"),
+            (Self::MAP_PERSISTENT, "MAP_PERSISTENT"),
+            (Self::MAP_COHERENT, "MAP_COHERENT"),
+            (Self::CLIENT_STORAGE, "CLIENT_STORAGE"),
+        ] {
+            if self.0 & flag.0 == flag.0 {
+                write!(f, "{separator}{name}")?;
+                separator = " | ";
+            }
+        }
+        if separator.is_empty() {
+            write!(f, "NONE")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::ops::BitOr for BufferStorageFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for BufferStorageFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct MapAccess(GLbitField);
+
+impl MapAccess {
+    pub const READ: Self = Self(0x0001);
+    pub const WRITE: Self = Self(0x0002);
+    pub const INVALIDATE_RANGE: Self = Self(0x0004);
+    pub const INVALIDATE_BUFFER: Self = Self(0x0008);
+    pub const FLUSH_EXPLICIT: Self = Self(0x0010);
+    pub const UNSYNCHRONIZED: Self = Self(0x0020);
+    pub const PERSISTENT: Self = Self(0x0040);
+    pub const COHERENT: Self = Self(0x0080);
+}
+
+impl std::fmt::Display for MapAccess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut separator = "";
+        for (flag, name) in [
+            (Self::READ, "READ"),
+            (Self::WRITE, "WRITE"),
+            (Self::INVALIDATE_RANGE, "INVALIDATE_RANGE"),
+            (Self::INVALIDATE_BUFFER, "INVALIDATE_BUFFER"),
+            (Self::FLUSH_EXPLICIT, "FLUSH_EXPLICIT"),
+            (Self::UNSYNCHRONIZED, "UNSYNCHRONIZED"),
+            (Self::PERSISTENT, "PERSISTENT"),
+            (Self::COHERENT, "COHERENT"),
+        ] {
+            if self.0 & flag.0 == flag.0 {
+                write!(f, "{separator}{name}")?;
+                separator = " | ";
+            }
+        }
+        if separator.is_empty() {
+            write!(f, "NONE")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::ops::BitOr for MapAccess {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for MapAccess {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct SyncCondition(GLenum);
+
+impl SyncCondition {
+    pub const GPU_COMMANDS_COMPLETE: Self = Self(0x9117);
+}
+
+impl std::fmt::Display for SyncCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::GPU_COMMANDS_COMPLETE => write!(f, "GPU_COMMANDS_COMPLETE"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct SyncWaitFlags(GLbitField);
+
+impl SyncWaitFlags {
+    pub const FLUSH_COMMANDS: Self = Self(0x0001);
+}
+
+impl std::fmt::Display for SyncWaitFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut separator = "";
+        for (flag, name) in [(Self::FLUSH_COMMANDS, "FLUSH_COMMANDS")] {
+            if self.0 & flag.0 == flag.0 {
+                write!(f, "{separator}{name}")?;
+                separator = " | ";
+            }
+        }
+        if separator.is_empty() {
+            write!(f, "NONE")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::ops::BitOr for SyncWaitFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for SyncWaitFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct SyncStatus(GLenum);
+
+impl SyncStatus {
+    pub const ALREADY_SIGNALED: Self = Self(0x911A);
+    pub const TIMEOUT_EXPIRED: Self = Self(0x911B);
+    pub const CONDITION_SATISFIED: Self = Self(0x911C);
+    pub const WAIT_FAILED: Self = Self(0x911D);
+}
+
+impl std::fmt::Display for SyncStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::ALREADY_SIGNALED => write!(f, "ALREADY_SIGNALED"),
+            Self::TIMEOUT_EXPIRED => write!(f, "TIMEOUT_EXPIRED"),
+            Self::CONDITION_SATISFIED => write!(f, "CONDITION_SATISFIED"),
+            Self::WAIT_FAILED => write!(f, "WAIT_FAILED"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ClearMask(GLbitField);
+
+impl ClearMask {
+    pub const COLOR: Self = Self(0x4000);
+    pub const DEPTH: Self = Self(0x0100);
+    pub const STENCIL: Self = Self(0x0400);
+}
+
+impl std::fmt::Display for ClearMask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut separator = "";
+        for (flag, name) in [
+            (Self::COLOR, "COLOR"),
+            (Self::DEPTH, "DEPTH"),
+            (Self::STENCIL, "STENCIL"),
+        ] {
+            if self.0 & flag.0 == flag.0 {
+                write!(f, "{separator}{name}")?;
+                separator = " | ";
+            }
+        }
+        if separator.is_empty() {
+            write!(f, "NONE")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::ops::BitOr for ClearMask {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for ClearMask {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Primitive(GLenum);
+
+impl Primitive {
+    pub const POINTS: Self = Self(0x0000);
+    pub const LINES: Self = Self(0x0001);
+    pub const LINE_STRIP: Self = Self(0x0003);
+    pub const LINE_LOOP: Self = Self(0x0002);
+    pub const TRIANGLES: Self = Self(0x0004);
+    pub const TRIANGLE_STRIP: Self = Self(0x0005);
+    pub const TRIANGLE_FAN: Self = Self(0x0006);
+    pub const LINES_ADJACENCY: Self = Self(0x000A);
+    pub const LINE_STRIP_ADJACENCY: Self = Self(0x000B);
+    pub const TRIANGLES_ADJACENCY: Self = Self(0x000C);
+    pub const TRIANGLE_STRIP_ADJACENCY: Self = Self(0x000D);
+    pub const PATCHES: Self = Self(0x000E);
+}
+
+impl std::fmt::Display for Primitive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::POINTS => write!(f, "POINTS"),
+            Self::LINES => write!(f, "LINES"),
+            Self::LINE_STRIP => write!(f, "LINE_STRIP"),
+            Self::LINE_LOOP => write!(f, "LINE_LOOP"),
+            Self::TRIANGLES => write!(f, "TRIANGLES"),
+            Self::TRIANGLE_STRIP => write!(f, "TRIANGLE_STRIP"),
+            Self::TRIANGLE_FAN => write!(f, "TRIANGLE_FAN"),
+            Self::LINES_ADJACENCY => write!(f, "LINES_ADJACENCY"),
+            Self::LINE_STRIP_ADJACENCY => write!(f, "LINE_STRIP_ADJACENCY"),
+            Self::TRIANGLES_ADJACENCY => write!(f, "TRIANGLES_ADJACENCY"),
+            Self::TRIANGLE_STRIP_ADJACENCY => write!(f, "TRIANGLE_STRIP_ADJACENCY"),
+            Self::PATCHES => write!(f, "PATCHES"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ElementKind(GLenum);
+
+impl ElementKind {
+    pub const UNSIGNED_BYTE: Self = Self(0x1401);
+    pub const UNSIGNED_SHORT: Self = Self(0x1403);
+    pub const UNSIGNED_INT: Self = Self(0x1405);
+}
+
+impl std::fmt::Display for ElementKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::UNSIGNED_BYTE => write!(f, "UNSIGNED_BYTE"),
+            Self::UNSIGNED_SHORT => write!(f, "UNSIGNED_SHORT"),
+            Self::UNSIGNED_INT => write!(f, "UNSIGNED_INT"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ShaderKind(GLenum);
+
+impl ShaderKind {
+    pub const FRAGMENT: Self = Self(0x8B30);
+    pub const VERTEX: Self = Self(0x8B31);
+    pub const GEOMETRY: Self = Self(0x8DD9);
+    pub const TESS_CONTROL: Self = Self(0x8E88);
+    pub const TESS_EVALUATION: Self = Self(0x8E87);
+    pub const COMPUTE: Self = Self(0x91B9);
+}
+
+impl std::fmt::Display for ShaderKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::FRAGMENT => write!(f, "FRAGMENT"),
+            Self::VERTEX => write!(f, "VERTEX"),
+            Self::GEOMETRY => write!(f, "GEOMETRY"),
+            Self::TESS_CONTROL => write!(f, "TESS_CONTROL"),
+            Self::TESS_EVALUATION => write!(f, "TESS_EVALUATION"),
+            Self::COMPUTE => write!(f, "COMPUTE"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct PatchParameterName(GLenum);
+
+impl PatchParameterName {
+    pub const PATCH_VERTICES: Self = Self(0x8E72);
+}
+
+impl std::fmt::Display for PatchParameterName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::PATCH_VERTICES => write!(f, "PATCH_VERTICES"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ShaderParameterName(GLenum);
+
+impl ShaderParameterName {
+    pub const COMPILE_STATUS: Self = Self(0x8B81);
+    pub const INFO_LOG_LENGTH: Self = Self(0x8B84);
+}
+
+impl std::fmt::Display for ShaderParameterName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::COMPILE_STATUS => write!(f, "COMPILE_STATUS"),
+            Self::INFO_LOG_LENGTH => write!(f, "INFO_LOG_LENGTH"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ProgramParameterName(GLenum);
+
+impl ProgramParameterName {
+    pub const LINK_STATUS: Self = Self(0x8B82);
+    pub const INFO_LOG_LENGTH: Self = Self(0x8B84);
+}
+
+impl std::fmt::Display for ProgramParameterName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::LINK_STATUS => write!(f, "LINK_STATUS"),
+            Self::INFO_LOG_LENGTH => write!(f, "INFO_LOG_LENGTH"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ProgramInterfaceParameterName(GLenum);
+
+impl ProgramInterfaceParameterName {
+    pub const ACTIVE_RESOURCES: Self = Self(0x92F5);
+    pub const MAX_NAME_LENGTH: Self = Self(0x92F6);
+    pub const MAX_NUM_ACTIVE_VARIABLES: Self = Self(0x92F7);
+}
+
+impl std::fmt::Display for ProgramInterfaceParameterName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::ACTIVE_RESOURCES => write!(f, "ACTIVE_RESOURCES"),
+            Self::MAX_NAME_LENGTH => write!(f, "MAX_NAME_LENGTH"),
+            Self::MAX_NUM_ACTIVE_VARIABLES => write!(f, "MAX_NUM_ACTIVE_VARIABLES"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ProgramInterface(GLenum);
+
+impl ProgramInterface {
+    pub const UNIFORM: Self = Self(0x92E1);
+    pub const UNIFORM_BLOCK: Self = Self(0x92E2);
+    pub const PROGRAM_INPUT: Self = Self(0x92E3);
+    pub const PROGRAM_OUTPUT: Self = Self(0x92E4);
+    pub const SHADER_STORAGE_BLOCK: Self = Self(0x92E6);
+}
+
+impl std::fmt::Display for ProgramInterface {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::UNIFORM => write!(f, "UNIFORM"),
+            Self::UNIFORM_BLOCK => write!(f, "UNIFORM_BLOCK"),
+            Self::PROGRAM_INPUT => write!(f, "PROGRAM_INPUT"),
+            Self::PROGRAM_OUTPUT => write!(f, "PROGRAM_OUTPUT"),
+            Self::SHADER_STORAGE_BLOCK => write!(f, "SHADER_STORAGE_BLOCK"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ProgramResourceProperty(GLenum);
+
+impl ProgramResourceProperty {
+    pub const NAME_LENGTH: Self = Self(0x92F9);
+    pub const ARRAY_SIZE: Self = Self(0x92FB);
+    pub const KIND: Self = Self(0x92FA);
+    pub const OFFSET: Self = Self(0x92FC);
+    pub const BLOCK_INDEX: Self = Self(0x92FD);
+    pub const LOCATION: Self = Self(0x930E);
+    pub const BUFFER_BINDING: Self = Self(0x9302);
+    pub const BUFFER_DATA_SIZE: Self = Self(0x9303);
+}
+
+impl std::fmt::Display for ProgramResourceProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::NAME_LENGTH => write!(f, "NAME_LENGTH"),
+            Self::ARRAY_SIZE => write!(f, "ARRAY_SIZE"),
+            Self::KIND => write!(f, "KIND"),
+            Self::OFFSET => write!(f, "OFFSET"),
+            Self::BLOCK_INDEX => write!(f, "BLOCK_INDEX"),
+            Self::LOCATION => write!(f, "LOCATION"),
+            Self::BUFFER_BINDING => write!(f, "BUFFER_BINDING"),
+            Self::BUFFER_DATA_SIZE => write!(f, "BUFFER_DATA_SIZE"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ShaderDataKind(GLenum);
+
+impl ShaderDataKind {
+    pub const FLOAT: Self = Self(0x1406);
+    pub const FLOAT_VEC2: Self = Self(0x8B50);
+    pub const FLOAT_VEC3: Self = Self(0x8B51);
+    pub const FLOAT_VEC4: Self = Self(0x8B52);
+    pub const INT: Self = Self(0x1404);
+    pub const INT_VEC2: Self = Self(0x8B53);
+    pub const INT_VEC3: Self = Self(0x8B54);
+    pub const INT_VEC4: Self = Self(0x8B55);
+    pub const UNSIGNED_INT: Self = Self(0x1405);
+    pub const UNSIGNED_INT_VEC2: Self = Self(0x8DC6);
+    pub const UNSIGNED_INT_VEC3: Self = Self(0x8DC7);
+    pub const UNSIGNED_INT_VEC4: Self = Self(0x8DC8);
+    pub const BOOL: Self = Self(0x8B56);
+    pub const BOOL_VEC2: Self = Self(0x8B57);
+    pub const BOOL_VEC3: Self = Self(0x8B58);
+    pub const BOOL_VEC4: Self = Self(0x8B59);
+    pub const FLOAT_MAT2: Self = Self(0x8B5A);
+    pub const FLOAT_MAT3: Self = Self(0x8B5B);
+    pub const FLOAT_MAT4: Self = Self(0x8B5C);
+    pub const SAMPLER_2D: Self = Self(0x8B5E);
+    pub const SAMPLER_3D: Self = Self(0x8B5F);
+    pub const SAMPLER_CUBE: Self = Self(0x8B60);
+    pub const SAMPLER_2D_SHADOW: Self = Self(0x8B62);
+    pub const SAMPLER_2D_ARRAY: Self = Self(0x8DC1);
+    pub const IMAGE_2D: Self = Self(0x904D);
+}
+
+impl std::fmt::Display for ShaderDataKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::FLOAT => write!(f, "FLOAT"),
+            Self::FLOAT_VEC2 => write!(f, "FLOAT_VEC2"),
+            Self::FLOAT_VEC3 => write!(f, "FLOAT_VEC3"),
+            Self::FLOAT_VEC4 => write!(f, "FLOAT_VEC4"),
+            Self::INT => write!(f, "INT"),
+            Self::INT_VEC2 => write!(f, "INT_VEC2"),
+            Self::INT_VEC3 => write!(f, "INT_VEC3"),
+            Self::INT_VEC4 => write!(f, "INT_VEC4"),
+            Self::UNSIGNED_INT => write!(f, "UNSIGNED_INT"),
+            Self::UNSIGNED_INT_VEC2 => write!(f, "UNSIGNED_INT_VEC2"),
+            Self::UNSIGNED_INT_VEC3 => write!(f, "UNSIGNED_INT_VEC3"),
+            Self::UNSIGNED_INT_VEC4 => write!(f, "UNSIGNED_INT_VEC4"),
+            Self::BOOL => write!(f, "BOOL"),
+            Self::BOOL_VEC2 => write!(f, "BOOL_VEC2"),
+            Self::BOOL_VEC3 => write!(f, "BOOL_VEC3"),
+            Self::BOOL_VEC4 => write!(f, "BOOL_VEC4"),
+            Self::FLOAT_MAT2 => write!(f, "FLOAT_MAT2"),
+            Self::FLOAT_MAT3 => write!(f, "FLOAT_MAT3"),
+            Self::FLOAT_MAT4 => write!(f, "FLOAT_MAT4"),
+            Self::SAMPLER_2D => write!(f, "SAMPLER_2D"),
+            Self::SAMPLER_3D => write!(f, "SAMPLER_3D"),
+            Self::SAMPLER_CUBE => write!(f, "SAMPLER_CUBE"),
+            Self::SAMPLER_2D_SHADOW => write!(f, "SAMPLER_2D_SHADOW"),
+            Self::SAMPLER_2D_ARRAY => write!(f, "SAMPLER_2D_ARRAY"),
+            Self::IMAGE_2D => write!(f, "IMAGE_2D"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct TextureTarget(GLenum);
+
+impl TextureTarget {
+    pub const TEXTURE_2D: Self = Self(0x0DE1);
+    pub const TEXTURE_3D: Self = Self(0x806F);
+    pub const TEXTURE_2D_ARRAY: Self = Self(0x8C1A);
+    pub const TEXTURE_CUBE_MAP: Self = Self(0x8513);
+    pub const TEXTURE_CUBE_MAP_POSITIVE_X: Self = Self(0x8515);
+    pub const TEXTURE_CUBE_MAP_NEGATIVE_X: Self = Self(0x8516);
+    pub const TEXTURE_CUBE_MAP_POSITIVE_Y: Self = Self(0x8517);
+    pub const TEXTURE_CUBE_MAP_NEGATIVE_Y: Self = Self(0x8518);
+    pub const TEXTURE_CUBE_MAP_POSITIVE_Z: Self = Self(0x8519);
+    pub const TEXTURE_CUBE_MAP_NEGATIVE_Z: Self = Self(0x851A);
+}
+
+impl std::fmt::Display for TextureTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::TEXTURE_2D => write!(f, "TEXTURE_2D"),
+            Self::TEXTURE_3D => write!(f, "TEXTURE_3D"),
+            Self::TEXTURE_2D_ARRAY => write!(f, "TEXTURE_2D_ARRAY"),
+            Self::TEXTURE_CUBE_MAP => write!(f, "TEXTURE_CUBE_MAP"),
+            Self::TEXTURE_CUBE_MAP_POSITIVE_X => write!(f, "TEXTURE_CUBE_MAP_POSITIVE_X"),
+            Self::TEXTURE_CUBE_MAP_NEGATIVE_X => write!(f, "TEXTURE_CUBE_MAP_NEGATIVE_X"),
+            Self::TEXTURE_CUBE_MAP_POSITIVE_Y => write!(f, "TEXTURE_CUBE_MAP_POSITIVE_Y"),
+            Self::TEXTURE_CUBE_MAP_NEGATIVE_Y => write!(f, "TEXTURE_CUBE_MAP_NEGATIVE_Y"),
+            Self::TEXTURE_CUBE_MAP_POSITIVE_Z => write!(f, "TEXTURE_CUBE_MAP_POSITIVE_Z"),
+            Self::TEXTURE_CUBE_MAP_NEGATIVE_Z => write!(f, "TEXTURE_CUBE_MAP_NEGATIVE_Z"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct InternalFormat(GLenum);
+
+impl InternalFormat {
+    pub const R8: Self = Self(0x8229);
+    pub const RG8: Self = Self(0x822B);
+    pub const RGB8: Self = Self(0x8051);
+    pub const RGBA8: Self = Self(0x8058);
+    pub const R16: Self = Self(0x822A);
+    pub const RG16: Self = Self(0x822C);
+    pub const RGB16: Self = Self(0x8054);
+    pub const RGBA16: Self = Self(0x805B);
+    pub const SRGB8: Self = Self(0x8C41);
+    pub const SRGB8_ALPHA8: Self = Self(0x8C43);
+    pub const R16F: Self = Self(0x822D);
+    pub const RG16F: Self = Self(0x822F);
+    pub const RGB16F: Self = Self(0x881B);
+    pub const RGBA16F: Self = Self(0x881A);
+    pub const R32F: Self = Self(0x822E);
+    pub const RG32F: Self = Self(0x8230);
+    pub const RGB32F: Self = Self(0x8815);
+    pub const RGBA32F: Self = Self(0x8814);
+    pub const R11F_G11F_B10F: Self = Self(0x8C3A);
+    pub const DEPTH_COMPONENT16: Self = Self(0x81A5);
+    pub const DEPTH_COMPONENT24: Self = Self(0x81A6);
+    pub const DEPTH_COMPONENT32F: Self = Self(0x8CAC);
+    pub const DEPTH24_STENCIL8: Self = Self(0x88F0);
+    pub const DEPTH32F_STENCIL8: Self = Self(0x8CAD);
+    pub const COMPRESSED_RED_RGTC1: Self = Self(0x8DBB);
+    pub const COMPRESSED_RG_RGTC2: Self = Self(0x8DBD);
+    pub const COMPRESSED_RGBA_BPTC_UNORM: Self = Self(0x8E8C);
+    pub const COMPRESSED_SRGB_ALPHA_BPTC_UNORM: Self = Self(0x8E8D);
+    pub const COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT: Self = Self(0x8E8F);
+    pub const COMPRESSED_RGB8_ETC2: Self = Self(0x9274);
+    pub const COMPRESSED_RGBA8_ETC2_EAC: Self = Self(0x9278);
+}
+
+impl std::fmt::Display for InternalFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::R8 => write!(f, "R8"),
+            Self::RG8 => write!(f, "RG8"),
+            Self::RGB8 => write!(f, "RGB8"),
+            Self::RGBA8 => write!(f, "RGBA8"),
+            Self::R16 => write!(f, "R16"),
+            Self::RG16 => write!(f, "RG16"),
+            Self::RGB16 => write!(f, "RGB16"),
+            Self::RGBA16 => write!(f, "RGBA16"),
+            Self::SRGB8 => write!(f, "SRGB8"),
+            Self::SRGB8_ALPHA8 => write!(f, "SRGB8_ALPHA8"),
+            Self::R16F => write!(f, "R16F"),
+            Self::RG16F => write!(f, "RG16F"),
+            Self::RGB16F => write!(f, "RGB16F"),
+            Self::RGBA16F => write!(f, "RGBA16F"),
+            Self::R32F => write!(f, "R32F"),
+            Self::RG32F => write!(f, "RG32F"),
+            Self::RGB32F => write!(f, "RGB32F"),
+            Self::RGBA32F => write!(f, "RGBA32F"),
+            Self::R11F_G11F_B10F => write!(f, "R11F_G11F_B10F"),
+            Self::DEPTH_COMPONENT16 => write!(f, "DEPTH_COMPONENT16"),
+            Self::DEPTH_COMPONENT24 => write!(f, "DEPTH_COMPONENT24"),
+            Self::DEPTH_COMPONENT32F => write!(f, "DEPTH_COMPONENT32F"),
+            Self::DEPTH24_STENCIL8 => write!(f, "DEPTH24_STENCIL8"),
+            Self::DEPTH32F_STENCIL8 => write!(f, "DEPTH32F_STENCIL8"),
+            Self::COMPRESSED_RED_RGTC1 => write!(f, "COMPRESSED_RED_RGTC1"),
+            Self::COMPRESSED_RG_RGTC2 => write!(f, "COMPRESSED_RG_RGTC2"),
+            Self::COMPRESSED_RGBA_BPTC_UNORM => write!(f, "COMPRESSED_RGBA_BPTC_UNORM"),
+            Self::COMPRESSED_SRGB_ALPHA_BPTC_UNORM => write!(f, "COMPRESSED_SRGB_ALPHA_BPTC_UNORM"),
+            Self::COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT => {
+                write!(f, "COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT")
+            }
+            Self::COMPRESSED_RGB8_ETC2 => write!(f, "COMPRESSED_RGB8_ETC2"),
+            Self::COMPRESSED_RGBA8_ETC2_EAC => write!(f, "COMPRESSED_RGBA8_ETC2_EAC"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct TextureFormat(GLenum);
+
+impl TextureFormat {
+    pub const RED: Self = Self(0x1903);
+    pub const RG: Self = Self(0x8227);
+    pub const RGB: Self = Self(0x1907);
+    pub const BGR: Self = Self(0x80E0);
+    pub const RGBA: Self = Self(0x1908);
+    pub const BGRA: Self = Self(0x80E1);
+    pub const DEPTH_COMPONENT: Self = Self(0x1902);
+    pub const DEPTH_STENCIL: Self = Self(0x84F9);
+}
+
+impl std::fmt::Display for TextureFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::RED => write!(f, "RED"),
+            Self::RG => write!(f, "RG"),
+            Self::RGB => write!(f, "RGB"),
+            Self::BGR => write!(f, "BGR"),
+            Self::RGBA => write!(f, "RGBA"),
+            Self::BGRA => write!(f, "BGRA"),
+            Self::DEPTH_COMPONENT => write!(f, "DEPTH_COMPONENT"),
+            Self::DEPTH_STENCIL => write!(f, "DEPTH_STENCIL"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct TextureDataFormat(GLenum);
+
+impl TextureDataFormat {
+    pub const U8: Self = Self(0x1401);
+    pub const U16: Self = Self(0x1403);
+    pub const U32: Self = Self(0x1405);
+    pub const F16: Self = Self(0x140B);
+    pub const F32: Self = Self(0x1406);
+    pub const U24_8: Self = Self(0x84FA);
+}
+
+impl std::fmt::Display for TextureDataFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::U8 => write!(f, "U8"),
+            Self::U16 => write!(f, "U16"),
+            Self::U32 => write!(f, "U32"),
+            Self::F16 => write!(f, "F16"),
+            Self::F32 => write!(f, "F32"),
+            Self::U24_8 => write!(f, "U24_8"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct PixelStoreParameter(GLenum);
+
+impl PixelStoreParameter {
+    pub const UNPACK_ALIGNMENT: Self = Self(0x0CF5);
+    pub const UNPACK_ROW_LENGTH: Self = Self(0x0CF2);
+    pub const UNPACK_IMAGE_HEIGHT: Self = Self(0x806E);
+    pub const UNPACK_SKIP_ROWS: Self = Self(0x0CF3);
+    pub const UNPACK_SKIP_PIXELS: Self = Self(0x0CF4);
+    pub const UNPACK_SKIP_IMAGES: Self = Self(0x806D);
+    pub const PACK_ALIGNMENT: Self = Self(0x0D05);
+    pub const PACK_ROW_LENGTH: Self = Self(0x0D02);
+    pub const PACK_SKIP_ROWS: Self = Self(0x0D03);
+    pub const PACK_SKIP_PIXELS: Self = Self(0x0D04);
+}
+
+impl std::fmt::Display for PixelStoreParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::UNPACK_ALIGNMENT => write!(f, "UNPACK_ALIGNMENT"),
+            Self::UNPACK_ROW_LENGTH => write!(f, "UNPACK_ROW_LENGTH"),
+            Self::UNPACK_IMAGE_HEIGHT => write!(f, "UNPACK_IMAGE_HEIGHT"),
+            Self::UNPACK_SKIP_ROWS => write!(f, "UNPACK_SKIP_ROWS"),
+            Self::UNPACK_SKIP_PIXELS => write!(f, "UNPACK_SKIP_PIXELS"),
+            Self::UNPACK_SKIP_IMAGES => write!(f, "UNPACK_SKIP_IMAGES"),
+            Self::PACK_ALIGNMENT => write!(f, "PACK_ALIGNMENT"),
+            Self::PACK_ROW_LENGTH => write!(f, "PACK_ROW_LENGTH"),
+            Self::PACK_SKIP_ROWS => write!(f, "PACK_SKIP_ROWS"),
+            Self::PACK_SKIP_PIXELS => write!(f, "PACK_SKIP_PIXELS"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct SamplerParameterName(GLenum);
+
+impl SamplerParameterName {
+    pub const TEXTURE_MAG_FILTER: Self = Self(0x2800);
+    pub const TEXTURE_MIN_FILTER: Self = Self(0x2801);
+    pub const TEXTURE_WRAP_S: Self = Self(0x2802);
+    pub const TEXTURE_WRAP_T: Self = Self(0x2803);
+    pub const TEXTURE_WRAP_R: Self = Self(0x8072);
+    pub const TEXTURE_MIN_LOD: Self = Self(0x813A);
+    pub const TEXTURE_MAX_LOD: Self = Self(0x813B);
+    pub const TEXTURE_LOD_BIAS: Self = Self(0x8501);
+    pub const TEXTURE_BORDER_COLOR: Self = Self(0x1004);
+    pub const TEXTURE_COMPARE_MODE: Self = Self(0x884C);
+    pub const TEXTURE_COMPARE_FUNC: Self = Self(0x884D);
+    pub const TEXTURE_MAX_ANISOTROPY: Self = Self(0x84FE);
+}
+
+impl std::fmt::Display for SamplerParameterName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::TEXTURE_MAG_FILTER => write!(f, "TEXTURE_MAG_FILTER"),
+            Self::TEXTURE_MIN_FILTER => write!(f, "TEXTURE_MIN_FILTER"),
+            Self::TEXTURE_WRAP_S => write!(f, "TEXTURE_WRAP_S"),
+            Self::TEXTURE_WRAP_T => write!(f, "TEXTURE_WRAP_T"),
+            Self::TEXTURE_WRAP_R => write!(f, "TEXTURE_WRAP_R"),
+            Self::TEXTURE_MIN_LOD => write!(f, "TEXTURE_MIN_LOD"),
+            Self::TEXTURE_MAX_LOD => write!(f, "TEXTURE_MAX_LOD"),
+            Self::TEXTURE_LOD_BIAS => write!(f, "TEXTURE_LOD_BIAS"),
+            Self::TEXTURE_BORDER_COLOR => write!(f, "TEXTURE_BORDER_COLOR"),
+            Self::TEXTURE_COMPARE_MODE => write!(f, "TEXTURE_COMPARE_MODE"),
+            Self::TEXTURE_COMPARE_FUNC => write!(f, "TEXTURE_COMPARE_FUNC"),
+            Self::TEXTURE_MAX_ANISOTROPY => write!(f, "TEXTURE_MAX_ANISOTROPY"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct SamplerParameterValue(GLint);
+
+impl SamplerParameterValue {
+    pub const NEAREST: Self = Self(0x2600);
+    pub const LINEAR: Self = Self(0x2601);
+    pub const NONE: Self = Self(0);
+    pub const COMPARE_REF_TO_TEXTURE: Self = Self(0x884E);
+}
+
+impl std::fmt::Display for SamplerParameterValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::NEAREST => write!(f, "NEAREST"),
+            Self::LINEAR => write!(f, "LINEAR"),
+            Self::NONE => write!(f, "NONE"),
+            Self::COMPARE_REF_TO_TEXTURE => write!(f, "COMPARE_REF_TO_TEXTURE"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct MinFilter(GLint);
+
+impl MinFilter {
+    pub const NEAREST: Self = Self(0x2600);
+    pub const LINEAR: Self = Self(0x2601);
+    pub const NEAREST_MIPMAP_NEAREST: Self = Self(0x2700);
+    pub const LINEAR_MIPMAP_NEAREST: Self = Self(0x2701);
+    pub const NEAREST_MIPMAP_LINEAR: Self = Self(0x2702);
+    pub const LINEAR_MIPMAP_LINEAR: Self = Self(0x2703);
+}
+
+impl std::fmt::Display for MinFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::NEAREST => write!(f, "NEAREST"),
+            Self::LINEAR => write!(f, "LINEAR"),
+            Self::NEAREST_MIPMAP_NEAREST => write!(f, "NEAREST_MIPMAP_NEAREST"),
+            Self::LINEAR_MIPMAP_NEAREST => write!(f, "LINEAR_MIPMAP_NEAREST"),
+            Self::NEAREST_MIPMAP_LINEAR => write!(f, "NEAREST_MIPMAP_LINEAR"),
+            Self::LINEAR_MIPMAP_LINEAR => write!(f, "LINEAR_MIPMAP_LINEAR"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct MagFilter(GLint);
+
+impl MagFilter {
+    pub const NEAREST: Self = Self(0x2600);
+    pub const LINEAR: Self = Self(0x2601);
+}
+
+impl std::fmt::Display for MagFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::NEAREST => write!(f, "NEAREST"),
+            Self::LINEAR => write!(f, "LINEAR"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct WrapMode(GLint);
+
+impl WrapMode {
+    pub const REPEAT: Self = Self(0x2901);
+    pub const MIRRORED_REPEAT: Self = Self(0x8370);
+    pub const CLAMP_TO_EDGE: Self = Self(0x812F);
+    pub const CLAMP_TO_BORDER: Self = Self(0x812D);
+    pub const MIRROR_CLAMP_TO_EDGE: Self = Self(0x8743);
+}
+
+impl std::fmt::Display for WrapMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::REPEAT => write!(f, "REPEAT"),
+            Self::MIRRORED_REPEAT => write!(f, "MIRRORED_REPEAT"),
+            Self::CLAMP_TO_EDGE => write!(f, "CLAMP_TO_EDGE"),
+            Self::CLAMP_TO_BORDER => write!(f, "CLAMP_TO_BORDER"),
+            Self::MIRROR_CLAMP_TO_EDGE => write!(f, "MIRROR_CLAMP_TO_EDGE"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct CompareFunc(GLint);
+
+impl CompareFunc {
+    pub const NEVER: Self = Self(0x0200);
+    pub const LESS: Self = Self(0x0201);
+    pub const EQUAL: Self = Self(0x0202);
+    pub const LEQUAL: Self = Self(0x0203);
+    pub const GREATER: Self = Self(0x0204);
+    pub const NOTEQUAL: Self = Self(0x0205);
+    pub const GEQUAL: Self = Self(0x0206);
+    pub const ALWAYS: Self = Self(0x0207);
+}
+
+impl std::fmt::Display for CompareFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::NEVER => write!(f, "NEVER"),
+            Self::LESS => write!(f, "LESS"),
+            Self::EQUAL => write!(f, "EQUAL"),
+            Self::LEQUAL => write!(f, "LEQUAL"),
+            Self::GREATER => write!(f, "GREATER"),
+            Self::NOTEQUAL => write!(f, "NOTEQUAL"),
+            Self::GEQUAL => write!(f, "GEQUAL"),
+            Self::ALWAYS => write!(f, "ALWAYS"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct FramebufferTarget(GLenum);
+
+impl FramebufferTarget {
+    pub const FRAMEBUFFER: Self = Self(0x8D40);
+    pub const DRAW_FRAMEBUFFER: Self = Self(0x8CA9);
+    pub const READ_FRAMEBUFFER: Self = Self(0x8CA8);
+}
+
+impl std::fmt::Display for FramebufferTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::FRAMEBUFFER => write!(f, "FRAMEBUFFER"),
+            Self::DRAW_FRAMEBUFFER => write!(f, "DRAW_FRAMEBUFFER"),
+            Self::READ_FRAMEBUFFER => write!(f, "READ_FRAMEBUFFER"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct FramebufferAttachment(GLenum);
+
+impl FramebufferAttachment {
+    pub const COLOR_ATTACHMENT0: Self = Self(0x8CE0);
+    pub const COLOR_ATTACHMENT1: Self = Self(0x8CE1);
+    pub const COLOR_ATTACHMENT2: Self = Self(0x8CE2);
+    pub const COLOR_ATTACHMENT3: Self = Self(0x8CE3);
+    pub const COLOR_ATTACHMENT4: Self = Self(0x8CE4);
+    pub const COLOR_ATTACHMENT5: Self = Self(0x8CE5);
+    pub const COLOR_ATTACHMENT6: Self = Self(0x8CE6);
+    pub const COLOR_ATTACHMENT7: Self = Self(0x8CE7);
+    pub const DEPTH_ATTACHMENT: Self = Self(0x8D00);
+    pub const STENCIL_ATTACHMENT: Self = Self(0x8D20);
+    pub const DEPTH_STENCIL_ATTACHMENT: Self = Self(0x821A);
+}
+
+impl std::fmt::Display for FramebufferAttachment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::COLOR_ATTACHMENT0 => write!(f, "COLOR_ATTACHMENT0"),
+            Self::COLOR_ATTACHMENT1 => write!(f, "COLOR_ATTACHMENT1"),
+            Self::COLOR_ATTACHMENT2 => write!(f, "COLOR_ATTACHMENT2"),
+            Self::COLOR_ATTACHMENT3 => write!(f, "COLOR_ATTACHMENT3"),
+            Self::COLOR_ATTACHMENT4 => write!(f, "COLOR_ATTACHMENT4"),
+            Self::COLOR_ATTACHMENT5 => write!(f, "COLOR_ATTACHMENT5"),
+            Self::COLOR_ATTACHMENT6 => write!(f, "COLOR_ATTACHMENT6"),
+            Self::COLOR_ATTACHMENT7 => write!(f, "COLOR_ATTACHMENT7"),
+            Self::DEPTH_ATTACHMENT => write!(f, "DEPTH_ATTACHMENT"),
+            Self::STENCIL_ATTACHMENT => write!(f, "STENCIL_ATTACHMENT"),
+            Self::DEPTH_STENCIL_ATTACHMENT => write!(f, "DEPTH_STENCIL_ATTACHMENT"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct FramebufferStatus(GLenum);
+
+impl FramebufferStatus {
+    pub const COMPLETE: Self = Self(0x8CD5);
+    pub const UNDEFINED: Self = Self(0x8219);
+    pub const INCOMPLETE_ATTACHMENT: Self = Self(0x8CD6);
+    pub const INCOMPLETE_MISSING_ATTACHMENT: Self = Self(0x8CD7);
+    pub const INCOMPLETE_DRAW_BUFFER: Self = Self(0x8CDB);
+    pub const INCOMPLETE_READ_BUFFER: Self = Self(0x8CDC);
+    pub const UNSUPPORTED: Self = Self(0x8CDD);
+    pub const INCOMPLETE_MULTISAMPLE: Self = Self(0x8D56);
+    pub const INCOMPLETE_LAYER_TARGETS: Self = Self(0x8DA8);
+}
+
+impl std::fmt::Display for FramebufferStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::COMPLETE => write!(f, "COMPLETE"),
+            Self::UNDEFINED => write!(f, "UNDEFINED"),
+            Self::INCOMPLETE_ATTACHMENT => write!(f, "INCOMPLETE_ATTACHMENT"),
+            Self::INCOMPLETE_MISSING_ATTACHMENT => write!(f, "INCOMPLETE_MISSING_ATTACHMENT"),
+            Self::INCOMPLETE_DRAW_BUFFER => write!(f, "INCOMPLETE_DRAW_BUFFER"),
+            Self::INCOMPLETE_READ_BUFFER => write!(f, "INCOMPLETE_READ_BUFFER"),
+            Self::UNSUPPORTED => write!(f, "UNSUPPORTED"),
+            Self::INCOMPLETE_MULTISAMPLE => write!(f, "INCOMPLETE_MULTISAMPLE"),
+            Self::INCOMPLETE_LAYER_TARGETS => write!(f, "INCOMPLETE_LAYER_TARGETS"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ColorBuffer(GLenum);
+
+impl ColorBuffer {
+    pub const NONE: Self = Self(0);
+    pub const FRONT: Self = Self(0x0404);
+    pub const BACK: Self = Self(0x0405);
+    pub const COLOR_ATTACHMENT0: Self = Self(0x8CE0);
+    pub const COLOR_ATTACHMENT1: Self = Self(0x8CE1);
+    pub const COLOR_ATTACHMENT2: Self = Self(0x8CE2);
+    pub const COLOR_ATTACHMENT3: Self = Self(0x8CE3);
+    pub const COLOR_ATTACHMENT4: Self = Self(0x8CE4);
+    pub const COLOR_ATTACHMENT5: Self = Self(0x8CE5);
+    pub const COLOR_ATTACHMENT6: Self = Self(0x8CE6);
+    pub const COLOR_ATTACHMENT7: Self = Self(0x8CE7);
+}
+
+impl std::fmt::Display for ColorBuffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::NONE => write!(f, "NONE"),
+            Self::FRONT => write!(f, "FRONT"),
+            Self::BACK => write!(f, "BACK"),
+            Self::COLOR_ATTACHMENT0 => write!(f, "COLOR_ATTACHMENT0"),
+            Self::COLOR_ATTACHMENT1 => write!(f, "COLOR_ATTACHMENT1"),
+            Self::COLOR_ATTACHMENT2 => write!(f, "COLOR_ATTACHMENT2"),
+            Self::COLOR_ATTACHMENT3 => write!(f, "COLOR_ATTACHMENT3"),
+            Self::COLOR_ATTACHMENT4 => write!(f, "COLOR_ATTACHMENT4"),
+            Self::COLOR_ATTACHMENT5 => write!(f, "COLOR_ATTACHMENT5"),
+            Self::COLOR_ATTACHMENT6 => write!(f, "COLOR_ATTACHMENT6"),
+            Self::COLOR_ATTACHMENT7 => write!(f, "COLOR_ATTACHMENT7"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct RenderbufferTarget(GLenum);
+
+impl RenderbufferTarget {
+    pub const RENDERBUFFER: Self = Self(0x8D41);
+}
+
+impl std::fmt::Display for RenderbufferTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::RENDERBUFFER => write!(f, "RENDERBUFFER"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct BlitFilter(GLenum);
+
+impl BlitFilter {
+    pub const NEAREST: Self = Self(0x2600);
+    pub const LINEAR: Self = Self(0x2601);
+}
+
+impl std::fmt::Display for BlitFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::NEAREST => write!(f, "NEAREST"),
+            Self::LINEAR => write!(f, "LINEAR"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct MemoryBarrier(GLbitField);
+
+impl MemoryBarrier {
+    pub const VERTEX_ATTRIB_ARRAY: Self = Self(0x0001);
+    pub const ELEMENT_ARRAY: Self = Self(0x0002);
+    pub const UNIFORM: Self = Self(0x0004);
+    pub const TEXTURE_FETCH: Self = Self(0x0008);
+    pub const SHADER_IMAGE_ACCESS: Self = Self(0x0020);
+    pub const COMMAND: Self = Self(0x0040);
+    pub const PIXEL_BUFFER: Self = Self(0x0080);
+    pub const TEXTURE_UPDATE: Self = Self(0x0100);
+    pub const BUFFER_UPDATE: Self = Self(0x0200);
+    pub const FRAMEBUFFER: Self = Self(0x0400);
+    pub const TRANSFORM_FEEDBACK: Self = Self(0x0800);
+    pub const ATOMIC_COUNTER: Self = Self(0x1000);
+    pub const SHADER_STORAGE: Self = Self(0x2000);
+}
+
+impl std::fmt::Display for MemoryBarrier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut separator = "";
+        for (flag, name) in [
+            (Self::VERTEX_ATTRIB_ARRAY, "VERTEX_ATTRIB_ARRAY"),
+            (Self::ELEMENT_ARRAY, "ELEMENT_ARRAY"),
+            (Self::UNIFORM, "UNIFORM"),
+            (Self::TEXTURE_FETCH, "TEXTURE_FETCH"),
+            (Self::SHADER_IMAGE_ACCESS, "SHADER_IMAGE_ACCESS"),
+            (Self::COMMAND, "COMMAND"),
+            (Self::PIXEL_BUFFER, "PIXEL_BUFFER"),
+            (Self::TEXTURE_UPDATE, "TEXTURE_UPDATE"),
+            (Self::BUFFER_UPDATE, "BUFFER_UPDATE"),
+            (Self::FRAMEBUFFER, "FRAMEBUFFER"),
+            (Self::TRANSFORM_FEEDBACK, "TRANSFORM_FEEDBACK"),
+            (Self::ATOMIC_COUNTER, "ATOMIC_COUNTER"),
+            (Self::SHADER_STORAGE, "SHADER_STORAGE"),
+        ] {
+            if self.0 & flag.0 == flag.0 {
+                write!(f, "{separator}{name}")?;
+                separator = " | ";
+            }
+        }
+        if separator.is_empty() {
+            write!(f, "NONE")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::ops::BitOr for MemoryBarrier {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for MemoryBarrier {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ImageAccess(GLenum);
+
+impl ImageAccess {
+    pub const READ_ONLY: Self = Self(0x88B8);
+    pub const WRITE_ONLY: Self = Self(0x88B9);
+    pub const READ_WRITE: Self = Self(0x88BA);
+}
+
+impl std::fmt::Display for ImageAccess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::READ_ONLY => write!(f, "READ_ONLY"),
+            Self::WRITE_ONLY => write!(f, "WRITE_ONLY"),
+            Self::READ_WRITE => write!(f, "READ_WRITE"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct DebugSource(GLenum);
+
+impl DebugSource {
+    pub const API: Self = Self(0x8246);
+    pub const WINDOW_SYSTEM: Self = Self(0x8247);
+    pub const SHADER_COMPILER: Self = Self(0x8248);
+    pub const THIRD_PARTY: Self = Self(0x8249);
+    pub const APPLICATION: Self = Self(0x824A);
+    pub const OTHER: Self = Self(0x824B);
+    pub const DONT_CARE: Self = Self(0x1100);
+}
+
+impl std::fmt::Display for DebugSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::API => write!(f, "API"),
+            Self::WINDOW_SYSTEM => write!(f, "WINDOW_SYSTEM"),
+            Self::SHADER_COMPILER => write!(f, "SHADER_COMPILER"),
+            Self::THIRD_PARTY => write!(f, "THIRD_PARTY"),
+            Self::APPLICATION => write!(f, "APPLICATION"),
+            Self::OTHER => write!(f, "OTHER"),
+            Self::DONT_CARE => write!(f, "DONT_CARE"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct DebugType(GLenum);
+
+impl DebugType {
+    pub const ERROR: Self = Self(0x824C);
+    pub const DEPRECATED_BEHAVIOUR: Self = Self(0x824D);
+    pub const UNDEFINED_BEHAVIOUR: Self = Self(0x824E);
+    pub const PORTABILITY: Self = Self(0x824F);
+    pub const PERFORMANCE: Self = Self(0x8250);
+    pub const OTHER: Self = Self(0x8251);
+    pub const MARKER: Self = Self(0x8268);
+    pub const PUSH_GROUP: Self = Self(0x8269);
+    pub const POP_GROUP: Self = Self(0x826A);
+    pub const DONT_CARE: Self = Self(0x1100);
+}
+
+impl std::fmt::Display for DebugType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::ERROR => write!(f, "ERROR"),
+            Self::DEPRECATED_BEHAVIOUR => write!(f, "DEPRECATED_BEHAVIOUR"),
+            Self::UNDEFINED_BEHAVIOUR => write!(f, "UNDEFINED_BEHAVIOUR"),
+            Self::PORTABILITY => write!(f, "PORTABILITY"),
+            Self::PERFORMANCE => write!(f, "PERFORMANCE"),
+            Self::OTHER => write!(f, "OTHER"),
+            Self::MARKER => write!(f, "MARKER"),
+            Self::PUSH_GROUP => write!(f, "PUSH_GROUP"),
+            Self::POP_GROUP => write!(f, "POP_GROUP"),
+            Self::DONT_CARE => write!(f, "DONT_CARE"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct DebugSeverity(GLenum);
+
+impl DebugSeverity {
+    pub const HIGH: Self = Self(0x9146);
+    pub const MEDIUM: Self = Self(0x9147);
+    pub const LOW: Self = Self(0x9148);
+    pub const NOTIFICATION: Self = Self(0x826B);
+    pub const DONT_CARE: Self = Self(0x1100);
+}
+
+impl std::fmt::Display for DebugSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::HIGH => write!(f, "HIGH"),
+            Self::MEDIUM => write!(f, "MEDIUM"),
+            Self::LOW => write!(f, "LOW"),
+            Self::NOTIFICATION => write!(f, "NOTIFICATION"),
+            Self::DONT_CARE => write!(f, "DONT_CARE"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ObjectIdentifier(GLenum);
+
+impl ObjectIdentifier {
+    pub const BUFFER: Self = Self(0x82E0);
+    pub const SHADER: Self = Self(0x82E1);
+    pub const PROGRAM: Self = Self(0x82E2);
+    pub const VERTEX_ARRAY: Self = Self(0x8074);
+    pub const SAMPLER: Self = Self(0x82E6);
+    pub const TEXTURE: Self = Self(0x1702);
+    pub const RENDERBUFFER: Self = Self(0x8D41);
+    pub const FRAMEBUFFER: Self = Self(0x8D40);
+    pub const QUERY: Self = Self(0x82E3);
+}
+
+impl std::fmt::Display for ObjectIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::BUFFER => write!(f, "BUFFER"),
+            Self::SHADER => write!(f, "SHADER"),
+            Self::PROGRAM => write!(f, "PROGRAM"),
+            Self::VERTEX_ARRAY => write!(f, "VERTEX_ARRAY"),
+            Self::SAMPLER => write!(f, "SAMPLER"),
+            Self::TEXTURE => write!(f, "TEXTURE"),
+            Self::RENDERBUFFER => write!(f, "RENDERBUFFER"),
+            Self::FRAMEBUFFER => write!(f, "FRAMEBUFFER"),
+            Self::QUERY => write!(f, "QUERY"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct QueryTarget(GLenum);
+
+impl QueryTarget {
+    pub const SAMPLES_PASSED: Self = Self(0x8914);
+    pub const ANY_SAMPLES_PASSED: Self = Self(0x8C2F);
+    pub const ANY_SAMPLES_PASSED_CONSERVATIVE: Self = Self(0x8D6A);
+    pub const TIME_ELAPSED: Self = Self(0x88BF);
+    pub const PRIMITIVES_GENERATED: Self = Self(0x8C87);
+}
+
+impl std::fmt::Display for QueryTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::SAMPLES_PASSED => write!(f, "SAMPLES_PASSED"),
+            Self::ANY_SAMPLES_PASSED => write!(f, "ANY_SAMPLES_PASSED"),
+            Self::ANY_SAMPLES_PASSED_CONSERVATIVE => write!(f, "ANY_SAMPLES_PASSED_CONSERVATIVE"),
+            Self::TIME_ELAPSED => write!(f, "TIME_ELAPSED"),
+            Self::PRIMITIVES_GENERATED => write!(f, "PRIMITIVES_GENERATED"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct QueryCounterTarget(GLenum);
+
+impl QueryCounterTarget {
+    pub const TIMESTAMP: Self = Self(0x8E28);
+}
+
+impl std::fmt::Display for QueryCounterTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::TIMESTAMP => write!(f, "TIMESTAMP"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct QueryParameter(GLenum);
+
+impl QueryParameter {
+    pub const QUERY_RESULT: Self = Self(0x8866);
+    pub const QUERY_RESULT_AVAILABLE: Self = Self(0x8867);
+}
+
+impl std::fmt::Display for QueryParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::QUERY_RESULT => write!(f, "QUERY_RESULT"),
+            Self::QUERY_RESULT_AVAILABLE => write!(f, "QUERY_RESULT_AVAILABLE"),
+            _ => write!(f, "UNKNOWN({:#X})", self.0),
+        }
+    }
+}
+
+/// Bindings to a curated subset of OpenGL 4.3
+#[allow(clippy::struct_field_names)]
+pub struct Api {
+    // 4.3 API
+    // compute
+    dispatch_compute_ptr:
+        unsafe extern "system" fn(num_groups_x: GLuint, num_groups_y: GLuint, num_groups_z: GLuint),
+    dispatch_compute_indirect_ptr: unsafe extern "system" fn(indirect: GLintptr),
+    memory_barrier_ptr: unsafe extern "system" fn(barriers: MemoryBarrier),
+    bind_image_texture_ptr: unsafe extern "system" fn(
+        unit: GLuint,
+        texture: Texture,
+        level: GLint,
+        layered: GLboolean,
+        layer: GLint,
+        access: ImageAccess,
+        format: InternalFormat,
+    ),
+    debug_message_callback_ptr: unsafe extern "system" fn(
+        callback: Option<DebugMessageCallback>,
+        user_param: *const std::ffi::c_void,
+    ),
+    debug_message_control_ptr: unsafe extern "system" fn(
+        source: DebugSource,
+        kind: DebugType,
+        severity: DebugSeverity,
+        count: GLsizei,
+        ids: *const GLuint,
+        enabled: GLboolean,
+    ),
+    get_debug_message_log_ptr: unsafe extern "system" fn(
+        count: GLuint,
+        buf_size: GLsizei,
+        sources: *mut DebugSource,
+        types: *mut DebugType,
+        ids: *mut GLuint,
+        severities: *mut DebugSeverity,
+        lengths: *mut GLsizei,
+        message_log: *mut GLchar,
+    ) -> GLuint,
+    debug_message_insert_ptr: unsafe extern "system" fn(
+        source: DebugSource,
+        kind: DebugType,
+        id: GLuint,
+        severity: DebugSeverity,
+        length: GLsizei,
+        buf: *const GLchar,
+    ),
+    push_debug_group_ptr: unsafe extern "system" fn(
+        source: DebugSource,
+        id: GLuint,
+        length: GLsizei,
+        message: *const GLchar,
+    ),
+    pop_debug_group_ptr: unsafe extern "system" fn(),
+    object_label_ptr: unsafe extern "system" fn(
+        identifier: ObjectIdentifier,
+        name: GLuint,
+        length: GLsizei,
+        label: *const GLchar,
+    ),
+    // vertex arrays
+    vertex_attrib_format_ptr: unsafe extern "system" fn(
+        attribindex: AttributeIndex,
+        size: AttributeComponents,
+        kind: VertexAttributeKind,
+        normalized: GLboolean,
+        relativeoffset: GLuint,
+    ),
+    vertex_attrib_i_format_ptr: unsafe extern "system" fn(
+        attribindex: AttributeIndex,
+        size: AttributeComponents,
+        kind: VertexAttributeKind,
+        relativeoffset: GLuint,
+    ),
+    bind_vertex_buffer_ptr: unsafe extern "system" fn(
+        bindingindex: BufferBindingIndex,
+        buffer: Buffer,
+        offset: GLintptr,
+        stride: GLsizei,
+    ),
+    vertex_binding_divisor_ptr:
+        unsafe extern "system" fn(bindingindex: BufferBindingIndex, divisor: GLuint),
+    vertex_attrib_binding_ptr:
+        unsafe extern "system" fn(attribindex: AttributeIndex, bindingindex: BufferBindingIndex),
+    // programs
+    get_program_interface_iv_ptr: unsafe extern "system" fn(
+        program: Program,
+        program_interface: ProgramInterface,
+        pname: ProgramInterfaceParameterName,
+        params: *mut GLint,
+    ),
+    get_program_resource_iv_ptr: unsafe extern "system" fn(
+        program: Program,
+        program_interface: ProgramInterface,
+        index: GLuint,
+        prop_count: GLsizei,
+        props: *const ProgramResourceProperty,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        params: *mut GLint,
+    ),
+    get_program_resource_name_ptr: unsafe extern "system" fn(
+        program: Program,
+        program_interface: ProgramInterface,
+        index: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        name: *mut GLchar,
+    ),
+    get_program_resource_index_ptr: unsafe extern "system" fn(
+        program: Program,
+        program_interface: ProgramInterface,
+        name: *const GLchar,
+    ) -> GLuint,
+    shader_storage_block_binding_ptr: unsafe extern "system" fn(
+        program: Program,
+        storage_block_index: GLuint,
+        storage_block_binding: GLuint,
+    ),
+    // previous versions
+    // state
+    enable_ptr: unsafe extern "system" fn(cap: Capability),
+    disable_ptr: unsafe extern "system" fn(cap: Capability),
+    depth_func_ptr: unsafe extern "system" fn(func: DepthFunc),
+    depth_mask_ptr: unsafe extern "system" fn(flag: GLboolean),
+    color_mask_ptr: unsafe extern "system" fn(
+        red: GLboolean,
+        green: GLboolean,
+        blue: GLboolean,
+        alpha: GLboolean,
+    ),
+    cull_face_ptr: unsafe extern "system" fn(mode: Face),
+    front_face_ptr: unsafe extern "system" fn(mode: FrontFace),
+    polygon_mode_ptr: unsafe extern "system" fn(face: Face, mode: PolygonMode),
+    polygon_offset_ptr: unsafe extern "system" fn(factor: GLfloat, units: GLfloat),
+    scissor_ptr: unsafe extern "system" fn(x: GLint, y: GLint, width: GLsizei, height: GLsizei),
+    blend_func_ptr: unsafe extern "system" fn(sfactor: BlendFactor, dfactor: BlendFactor),
+    blend_func_separate_ptr: unsafe extern "system" fn(
+        sfactor_rgb: BlendFactor,
+        dfactor_rgb: BlendFactor,
+        sfactor_alpha: BlendFactor,
+        dfactor_alpha: BlendFactor,
+    ),
+    blend_equation_ptr: unsafe extern "system" fn(mode: BlendEquation),
+    blend_equation_separate_ptr:
+        unsafe extern "system" fn(mode_rgb: BlendEquation, mode_alpha: BlendEquation),
+    blend_color_ptr:
+        unsafe extern "system" fn(red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat),
+    stencil_func_ptr: unsafe extern "system" fn(func: StencilFunc, reference: GLint, mask: GLuint),
+    stencil_op_ptr: unsafe extern "system" fn(fail: StencilOp, zfail: StencilOp, zpass: StencilOp),
+    stencil_mask_ptr: unsafe extern "system" fn(mask: GLuint),
+    clear_ptr: unsafe extern "system" fn(mask: ClearMask),
+    clear_color_ptr:
+        unsafe extern "system" fn(red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat),
+    viewport_ptr: unsafe extern "system" fn(x: GLint, y: GLint, width: GLsizei, height: GLsizei),
+    get_integer_v_ptr: unsafe extern "system" fn(pname: StateParameterName, data: *mut GLint),
+    get_error_ptr: unsafe extern "system" fn() -> ErrorCode,
+    get_string_ptr: unsafe extern "system" fn(name: StringName) -> *const GLubyte,
+    get_stringi_ptr: unsafe extern "system" fn(name: StringName, index: GLuint) -> *const GLubyte,
+    // draw
+    draw_elements_ptr: unsafe extern "system" fn(
+        mode: Primitive,
+        count: GLsizei,
+        kind: ElementKind,
+        indices: *const std::ffi::c_void,
+    ),
+    draw_elements_base_vertex_ptr: unsafe extern "system" fn(
+        mode: Primitive,
+        count: GLsizei,
+        kind: ElementKind,
+        indices: *const std::ffi::c_void,
+        basevertex: GLint,
+    ),
+    draw_arrays_ptr: unsafe extern "system" fn(mode: Primitive, first: GLint, count: GLsizei),
+    draw_arrays_instanced_ptr: unsafe extern "system" fn(
+        mode: Primitive,
+        first: GLint,
+        count: GLsizei,
+        instancecount: GLsizei,
+    ),
+    draw_elements_instanced_base_vertex_base_instance_ptr: unsafe extern "system" fn(
+        mode: Primitive,
+        count: GLsizei,
+        kind: ElementKind,
+        indices: *const std::ffi::c_void,
+        instancecount: GLsizei,
+        basevertex: GLint,
+        baseinstance: GLuint,
+    ),
+    multi_draw_elements_indirect_ptr: unsafe extern "system" fn(
+        mode: Primitive,
+        kind: ElementKind,
+        indirect: *const std::ffi::c_void,
+        drawcount: GLsizei,
+        stride: GLsizei,
+    ),
+    patch_parameter_i_ptr: unsafe extern "system" fn(pname: PatchParameterName, value: GLint),
+    // vertex arrays
+    gen_vertex_arrays_ptr: unsafe extern "system" fn(n: GLsizei, arrays: *mut VertexArray),
+    bind_vertex_array_ptr: unsafe extern "system" fn(array: VertexArray),
+    enable_vertex_attrib_array_ptr: unsafe extern "system" fn(index: AttributeIndex),
+    delete_vertex_arrays_ptr: unsafe extern "system" fn(n: GLsizei, arrays: *const VertexArray),
+    // buffers
+    gen_buffers_ptr: unsafe extern "system" fn(n: GLsizei, buffers: *mut Buffer),
+    bind_buffer_ptr: unsafe extern "system" fn(target: BufferTarget, buffer: Buffer),
+    buffer_data_ptr: unsafe extern "system" fn(
+        target: BufferTarget,
+        size: GLsizeiptr,
+        data: *const std::ffi::c_void,
+        usage: BufferUsage,
+    ),
+    buffer_sub_data_ptr: unsafe extern "system" fn(
+        target: BufferTarget,
+        offset: GLintptr,
+        size: GLsizeiptr,
+        data: *const std::ffi::c_void,
+    ),
+    map_buffer_range_ptr: unsafe extern "system" fn(
+        target: BufferTarget,
+        offset: GLintptr,
+        length: GLsizeiptr,
+        access: MapAccess,
+    ) -> *mut std::ffi::c_void,
+    unmap_buffer_ptr: unsafe extern "system" fn(target: BufferTarget) -> GLboolean,
+    flush_mapped_buffer_range_ptr:
+        unsafe extern "system" fn(target: BufferTarget, offset: GLintptr, length: GLsizeiptr),
+    get_buffer_sub_data_ptr: unsafe extern "system" fn(
+        target: BufferTarget,
+        offset: GLintptr,
+        size: GLsizeiptr,
+        data: *mut std::ffi::c_void,
+    ),
+    // sync
+    fence_sync_ptr:
+        unsafe extern "system" fn(condition: SyncCondition, flags: GLbitField) -> GLsync,
+    client_wait_sync_ptr: unsafe extern "system" fn(
+        sync: GLsync,
+        flags: SyncWaitFlags,
+        timeout: GLuint64,
+    ) -> SyncStatus,
+    delete_sync_ptr: unsafe extern "system" fn(sync: GLsync),
+    flush_ptr: unsafe extern "system" fn(),
+    finish_ptr: unsafe extern "system" fn(),
+    bind_buffer_base_ptr:
+        unsafe extern "system" fn(target: BufferTarget, index: GLuint, buffer: Buffer),
+    bind_buffer_range_ptr: unsafe extern "system" fn(
+        target: BufferTarget,
+        index: GLuint,
+        buffer: Buffer,
+        offset: GLintptr,
+        size: GLsizeiptr,
+    ),
+    delete_buffers_ptr: unsafe extern "system" fn(n: GLsizei, buffers: *const Buffer),
+    // shaders
+    create_shader_ptr: unsafe extern "system" fn(kind: ShaderKind) -> Shader,
+    shader_source_ptr: unsafe extern "system" fn(
+        shader: Shader,
+        count: GLsizei,
+        string: *const *const GLchar,
+        length: *const GLint,
+    ),
+    compile_shader_ptr: unsafe extern "system" fn(shader: Shader),
+    delete_shader_ptr: unsafe extern "system" fn(shader: Shader),
+    get_shader_iv_ptr:
+        unsafe extern "system" fn(shader: Shader, pname: ShaderParameterName, params: *mut GLint),
+    get_shader_info_log_ptr: unsafe extern "system" fn(
+        shader: Shader,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        info_log: *mut GLchar,
+    ),
+    // programs
+    create_program_ptr: unsafe extern "system" fn() -> Program,
+    attach_shader_ptr: unsafe extern "system" fn(program: Program, shader: Shader),
+    link_program_ptr: unsafe extern "system" fn(program: Program),
+    get_program_iv_ptr: unsafe extern "system" fn(
+        program: Program,
+        pname: ProgramParameterName,
+        params: *mut GLint,
+    ),
+    get_program_info_log_ptr: unsafe extern "system" fn(
+        program: Program,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        info_log: *mut GLchar,
+    ),
+    detach_shader_ptr: unsafe extern "system" fn(program: Program, shader: Shader),
+    use_program_ptr: unsafe extern "system" fn(program: Program),
+    delete_program_ptr: unsafe extern "system" fn(program: Program),
+    get_uniform_block_index_ptr:
+        unsafe extern "system" fn(program: Program, uniform_block_name: *const GLchar) -> GLuint,
+    uniform_block_binding_ptr: unsafe extern "system" fn(
+        program: Program,
+        uniform_block_index: GLuint,
+        uniform_block_binding: GLuint,
+    ),
+    uniform1_i_ptr: unsafe extern "system" fn(location: GLint, v0: GLint),
+    uniform1_f_ptr: unsafe extern "system" fn(location: GLint, v0: GLfloat),
+    uniform1_ui_ptr: unsafe extern "system" fn(location: GLint, v0: GLuint),
+    uniform1_fv_ptr:
+        unsafe extern "system" fn(location: GLint, count: GLsizei, value: *const GLfloat),
+    uniform2_fv_ptr:
+        unsafe extern "system" fn(location: GLint, count: GLsizei, value: *const GLfloat),
+    uniform3_fv_ptr:
+        unsafe extern "system" fn(location: GLint, count: GLsizei, value: *const GLfloat),
+    uniform4_fv_ptr:
+        unsafe extern "system" fn(location: GLint, count: GLsizei, value: *const GLfloat),
+    uniform1_iv_ptr:
+        unsafe extern "system" fn(location: GLint, count: GLsizei, value: *const GLint),
+    uniform2_iv_ptr:
+        unsafe extern "system" fn(location: GLint, count: GLsizei, value: *const GLint),
+    uniform3_iv_ptr:
+        unsafe extern "system" fn(location: GLint, count: GLsizei, value: *const GLint),
+    uniform4_iv_ptr:
+        unsafe extern "system" fn(location: GLint, count: GLsizei, value: *const GLint),
+    uniform1_uiv_ptr:
+        unsafe extern "system" fn(location: GLint, count: GLsizei, value: *const GLuint),
+    uniform2_uiv_ptr:
+        unsafe extern "system" fn(location: GLint, count: GLsizei, value: *const GLuint),
+    uniform3_uiv_ptr:
+        unsafe extern "system" fn(location: GLint, count: GLsizei, value: *const GLuint),
+    uniform4_uiv_ptr:
+        unsafe extern "system" fn(location: GLint, count: GLsizei, value: *const GLuint),
+    uniform_matrix3_fv_ptr: unsafe extern "system" fn(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ),
+    uniform_matrix4_fv_ptr: unsafe extern "system" fn(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ),
+    // set uniforms of `program` without binding it first
+    program_uniform1_i_ptr: unsafe extern "system" fn(program: Program, location: GLint, v0: GLint),
+    program_uniform1_f_ptr:
+        unsafe extern "system" fn(program: Program, location: GLint, v0: GLfloat),
+    program_uniform1_ui_ptr:
+        unsafe extern "system" fn(program: Program, location: GLint, v0: GLuint),
+    program_uniform1_fv_ptr: unsafe extern "system" fn(
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLfloat,
+    ),
+    program_uniform2_fv_ptr: unsafe extern "system" fn(
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLfloat,
+    ),
+    program_uniform3_fv_ptr: unsafe extern "system" fn(
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLfloat,
+    ),
+    program_uniform4_fv_ptr: unsafe extern "system" fn(
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLfloat,
+    ),
+    program_uniform1_iv_ptr: unsafe extern "system" fn(
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLint,
+    ),
+    program_uniform2_iv_ptr: unsafe extern "system" fn(
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLint,
+    ),
+    program_uniform3_iv_ptr: unsafe extern "system" fn(
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLint,
+    ),
+    program_uniform4_iv_ptr: unsafe extern "system" fn(
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLint,
+    ),
+    program_uniform1_uiv_ptr: unsafe extern "system" fn(
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLuint,
+    ),
+    program_uniform2_uiv_ptr: unsafe extern "system" fn(
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLuint,
+    ),
+    program_uniform3_uiv_ptr: unsafe extern "system" fn(
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLuint,
+    ),
+    program_uniform4_uiv_ptr: unsafe extern "system" fn(
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLuint,
+    ),
+    program_uniform_matrix3_fv_ptr: unsafe extern "system" fn(
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ),
+    program_uniform_matrix4_fv_ptr: unsafe extern "system" fn(
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ),
+    // textures
+    gen_textures_ptr: unsafe extern "system" fn(n: GLsizei, textures: *mut Texture),
+    active_texture_ptr: unsafe extern "system" fn(texture: TextureUnit),
+    bind_texture_ptr: unsafe extern "system" fn(target: TextureTarget, texture: Texture),
+    tex_image_2d_ptr: unsafe extern "system" fn(
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        border: Border,
+        format: TextureFormat,
+        kind: TextureDataFormat,
+        pixels: *const std::ffi::c_void,
+    ),
+    tex_image_3d_ptr: unsafe extern "system" fn(
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        border: Border,
+        format: TextureFormat,
+        kind: TextureDataFormat,
+        pixels: *const std::ffi::c_void,
+    ),
+    compressed_tex_image_2d_ptr: unsafe extern "system" fn(
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        border: Border,
+        image_size: GLsizei,
+        data: *const std::ffi::c_void,
+    ),
+    tex_storage_2d_ptr: unsafe extern "system" fn(
+        target: TextureTarget,
+        levels: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ),
+    tex_storage_3d_ptr: unsafe extern "system" fn(
+        target: TextureTarget,
+        levels: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+    ),
+    tex_sub_image_2d_ptr: unsafe extern "system" fn(
+        target: TextureTarget,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: TextureFormat,
+        kind: TextureDataFormat,
+        pixels: *const std::ffi::c_void,
+    ),
+    tex_sub_image_3d_ptr: unsafe extern "system" fn(
+        target: TextureTarget,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        format: TextureFormat,
+        kind: TextureDataFormat,
+        pixels: *const std::ffi::c_void,
+    ),
+    generate_mipmap_ptr: unsafe extern "system" fn(target: TextureTarget),
+    pixel_store_i_ptr: unsafe extern "system" fn(pname: PixelStoreParameter, param: GLint),
+    delete_textures_ptr: unsafe extern "system" fn(n: GLsizei, textures: *const Texture),
+    // queries
+    gen_queries_ptr: unsafe extern "system" fn(n: GLsizei, ids: *mut Query),
+    begin_query_ptr: unsafe extern "system" fn(target: QueryTarget, id: Query),
+    end_query_ptr: unsafe extern "system" fn(target: QueryTarget),
+    query_counter_ptr: unsafe extern "system" fn(id: Query, target: QueryCounterTarget),
+    get_query_object_iv_ptr:
+        unsafe extern "system" fn(id: Query, pname: QueryParameter, params: *mut GLint),
+    get_query_object_uiv_ptr:
+        unsafe extern "system" fn(id: Query, pname: QueryParameter, params: *mut GLuint),
+    get_query_object_i64v_ptr:
+        unsafe extern "system" fn(id: Query, pname: QueryParameter, params: *mut GLint64),
+    get_query_object_ui64v_ptr:
+        unsafe extern "system" fn(id: Query, pname: QueryParameter, params: *mut GLuint64),
+    delete_queries_ptr: unsafe extern "system" fn(n: GLsizei, ids: *const Query),
+    // samplers
+    gen_samplers_ptr: unsafe extern "system" fn(count: GLsizei, samplers: *mut Sampler),
+    bind_sampler_ptr: unsafe extern "system" fn(unit: GLuint, sampler: Sampler),
+    sampler_parameter_i_ptr: unsafe extern "system" fn(
+        sampler: Sampler,
+        pname: SamplerParameterName,
+        param: SamplerParameterValue,
+    ),
+    sampler_parameter_f_ptr:
+        unsafe extern "system" fn(sampler: Sampler, pname: SamplerParameterName, param: GLfloat),
+    sampler_parameter_fv_ptr: unsafe extern "system" fn(
+        sampler: Sampler,
+        pname: SamplerParameterName,
+        param: *const GLfloat,
+    ),
+    delete_samplers_ptr: unsafe extern "system" fn(count: GLsizei, samplers: *const Sampler),
+    // framebuffers
+    gen_framebuffers_ptr: unsafe extern "system" fn(n: GLsizei, framebuffers: *mut Framebuffer),
+    bind_framebuffer_ptr:
+        unsafe extern "system" fn(target: FramebufferTarget, framebuffer: Framebuffer),
+    framebuffer_texture_2d_ptr: unsafe extern "system" fn(
+        target: FramebufferTarget,
+        attachment: FramebufferAttachment,
+        textarget: TextureTarget,
+        texture: Texture,
+        level: GLint,
+    ),
+    framebuffer_renderbuffer_ptr: unsafe extern "system" fn(
+        target: FramebufferTarget,
+        attachment: FramebufferAttachment,
+        renderbuffertarget: RenderbufferTarget,
+        renderbuffer: Renderbuffer,
+    ),
+    check_framebuffer_status_ptr:
+        unsafe extern "system" fn(target: FramebufferTarget) -> FramebufferStatus,
+    draw_buffers_ptr: unsafe extern "system" fn(n: GLsizei, bufs: *const ColorBuffer),
+    read_buffer_ptr: unsafe extern "system" fn(src: ColorBuffer),
+    read_pixels_ptr: unsafe extern "system" fn(
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: TextureFormat,
+        kind: TextureDataFormat,
+        pixels: *mut std::ffi::c_void,
+    ),
+    blit_framebuffer_ptr: unsafe extern "system" fn(
+        src_x0: GLint,
+        src_y0: GLint,
+        src_x1: GLint,
+        src_y1: GLint,
+        dst_x0: GLint,
+        dst_y0: GLint,
+        dst_x1: GLint,
+        dst_y1: GLint,
+        mask: ClearMask,
+        filter: BlitFilter,
+    ),
+    delete_framebuffers_ptr:
+        unsafe extern "system" fn(n: GLsizei, framebuffers: *const Framebuffer),
+    // renderbuffers
+    gen_renderbuffers_ptr: unsafe extern "system" fn(n: GLsizei, renderbuffers: *mut Renderbuffer),
+    bind_renderbuffer_ptr:
+        unsafe extern "system" fn(target: RenderbufferTarget, renderbuffer: Renderbuffer),
+    renderbuffer_storage_ptr: unsafe extern "system" fn(
+        target: RenderbufferTarget,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ),
+    delete_renderbuffers_ptr:
+        unsafe extern "system" fn(n: GLsizei, renderbuffers: *const Renderbuffer),
+    missing: Vec<&'static str>,
+}
+
+impl Api {
+    /// Loads all function pointers using a context function loader,
+    /// also called `ProcAddress`. Works with GLFW, SDL2 any pretty much any OpenGL context.
+    ///
+    /// Functions the loader returns a null pointer for are listed by `missing_functions` and
+    /// replaced with stand-ins. Calling one panics inside an `extern "system"` function, which
+    /// aborts the whole process, see `try_with_loader` to fail at load time instead.
+    ///
+    /// # Safety
+    /// Unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers.
+    ///
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader(loader: &impl Fn(&str) -> *const std::ffi::c_void) -> Self {
+        let mut missing = Vec::new();
+        Self {
+            // 4.3 API
+            // compute
+            dispatch_compute_ptr: loader.load_or(
+                "glDispatchCompute",
+                &mut missing,
+                missing_dispatch_compute,
+            ),
+            dispatch_compute_indirect_ptr: loader.load_or(
+                "glDispatchComputeIndirect",
+                &mut missing,
+                missing_dispatch_compute_indirect,
+            ),
+            memory_barrier_ptr: loader.load_or(
+                "glMemoryBarrier",
+                &mut missing,
+                missing_memory_barrier,
+            ),
+            bind_image_texture_ptr: loader.load_or(
+                "glBindImageTexture",
+                &mut missing,
+                missing_bind_image_texture,
+            ),
+            debug_message_callback_ptr: loader.load_or(
+                "glDebugMessageCallback",
+                &mut missing,
+                missing_debug_message_callback,
+            ),
+            debug_message_control_ptr: loader.load_or(
+                "glDebugMessageControl",
+                &mut missing,
+                missing_debug_message_control,
+            ),
+            get_debug_message_log_ptr: loader.load_or(
+                "glGetDebugMessageLog",
+                &mut missing,
+                missing_get_debug_message_log,
+            ),
+            debug_message_insert_ptr: loader.load_or(
+                "glDebugMessageInsert",
+                &mut missing,
+                missing_debug_message_insert,
+            ),
+            push_debug_group_ptr: loader.load_or(
+                "glPushDebugGroup",
+                &mut missing,
+                missing_push_debug_group,
+            ),
+            pop_debug_group_ptr: loader.load_or(
+                "glPopDebugGroup",
+                &mut missing,
+                missing_pop_debug_group,
+            ),
+            object_label_ptr: loader.load_or("glObjectLabel", &mut missing, missing_object_label),
+            // vertex arrays
+            vertex_attrib_format_ptr: loader.load_or(
+                "glVertexAttribFormat",
+                &mut missing,
+                missing_vertex_attrib_format,
+            ),
+            vertex_attrib_i_format_ptr: loader.load_or(
+                "glVertexAttribIFormat",
+                &mut missing,
+                missing_vertex_attrib_i_format,
+            ),
+            bind_vertex_buffer_ptr: loader.load_or(
+                "glBindVertexBuffer",
+                &mut missing,
+                missing_bind_vertex_buffer,
+            ),
+            vertex_binding_divisor_ptr: loader.load_or(
+                "glVertexBindingDivisor",
+                &mut missing,
+                missing_vertex_binding_divisor,
+            ),
+            vertex_attrib_binding_ptr: loader.load_or(
+                "glVertexAttribBinding",
+                &mut missing,
+                missing_vertex_attrib_binding,
+            ),
+            // programs
+            get_program_interface_iv_ptr: loader.load_or(
+                "glGetProgramInterfaceiv",
+                &mut missing,
+                missing_get_program_interface_iv,
+            ),
+            get_program_resource_iv_ptr: loader.load_or(
+                "glGetProgramResourceiv",
+                &mut missing,
+                missing_get_program_resource_iv,
+            ),
+            get_program_resource_name_ptr: loader.load_or(
+                "glGetProgramResourceName",
+                &mut missing,
+                missing_get_program_resource_name,
+            ),
+            get_program_resource_index_ptr: loader.load_or(
+                "glGetProgramResourceIndex",
+                &mut missing,
+                missing_get_program_resource_index,
+            ),
+            shader_storage_block_binding_ptr: loader.load_or(
+                "glShaderStorageBlockBinding",
+                &mut missing,
+                missing_shader_storage_block_binding,
+            ),
+            // previous versions
+            // state
+            enable_ptr: loader.load_or("glEnable", &mut missing, missing_enable),
+            disable_ptr: loader.load_or("glDisable", &mut missing, missing_disable),
+            depth_func_ptr: loader.load_or("glDepthFunc", &mut missing, missing_depth_func),
+            depth_mask_ptr: loader.load_or("glDepthMask", &mut missing, missing_depth_mask),
+            color_mask_ptr: loader.load_or("glColorMask", &mut missing, missing_color_mask),
+            cull_face_ptr: loader.load_or("glCullFace", &mut missing, missing_cull_face),
+            front_face_ptr: loader.load_or("glFrontFace", &mut missing, missing_front_face),
+            polygon_mode_ptr: loader.load_or("glPolygonMode", &mut missing, missing_polygon_mode),
+            polygon_offset_ptr: loader.load_or(
+                "glPolygonOffset",
+                &mut missing,
+                missing_polygon_offset,
+            ),
+            scissor_ptr: loader.load_or("glScissor", &mut missing, missing_scissor),
+            blend_func_ptr: loader.load_or("glBlendFunc", &mut missing, missing_blend_func),
+            blend_func_separate_ptr: loader.load_or(
+                "glBlendFuncSeparate",
+                &mut missing,
+                missing_blend_func_separate,
+            ),
+            blend_equation_ptr: loader.load_or(
+                "glBlendEquation",
+                &mut missing,
+                missing_blend_equation,
+            ),
+            blend_equation_separate_ptr: loader.load_or(
+                "glBlendEquationSeparate",
+                &mut missing,
+                missing_blend_equation_separate,
+            ),
+            blend_color_ptr: loader.load_or("glBlendColor", &mut missing, missing_blend_color),
+            stencil_func_ptr: loader.load_or("glStencilFunc", &mut missing, missing_stencil_func),
+            stencil_op_ptr: loader.load_or("glStencilOp", &mut missing, missing_stencil_op),
+            stencil_mask_ptr: loader.load_or("glStencilMask", &mut missing, missing_stencil_mask),
+            clear_ptr: loader.load_or("glClear", &mut missing, missing_clear),
+            clear_color_ptr: loader.load_or("glClearColor", &mut missing, missing_clear_color),
+            viewport_ptr: loader.load_or("glViewport", &mut missing, missing_viewport),
+            get_integer_v_ptr: loader.load_or("glGetIntegerv", &mut missing, missing_get_integer_v),
+            get_error_ptr: loader.load_or("glGetError", &mut missing, missing_get_error),
+            get_string_ptr: loader.load_or("glGetString", &mut missing, missing_get_string),
+            get_stringi_ptr: loader.load_or("glGetStringi", &mut missing, missing_get_stringi),
+            // draw
+            draw_elements_ptr: loader.load_or(
+                "glDrawElements",
+                &mut missing,
+                missing_draw_elements,
+            ),
+            draw_elements_base_vertex_ptr: loader.load_or(
+                "glDrawElementsBaseVertex",
+                &mut missing,
+                missing_draw_elements_base_vertex,
+            ),
+            draw_arrays_ptr: loader.load_or("glDrawArrays", &mut missing, missing_draw_arrays),
+            draw_arrays_instanced_ptr: loader.load_or(
+                "glDrawArraysInstanced",
+                &mut missing,
+                missing_draw_arrays_instanced,
+            ),
+            draw_elements_instanced_base_vertex_base_instance_ptr: loader.load_or(
+                "glDrawElementsInstancedBaseVertexBaseInstance",
+                &mut missing,
+                missing_draw_elements_instanced_base_vertex_base_instance,
+            ),
+            multi_draw_elements_indirect_ptr: loader.load_or(
+                "glMultiDrawElementsIndirect",
+                &mut missing,
+                missing_multi_draw_elements_indirect,
+            ),
+            patch_parameter_i_ptr: loader.load_or(
+                "glPatchParameteri",
+                &mut missing,
+                missing_patch_parameter_i,
+            ),
+            // vertex arrays
+            gen_vertex_arrays_ptr: loader.load_or(
+                "glGenVertexArrays",
+                &mut missing,
+                missing_gen_vertex_arrays,
+            ),
+            bind_vertex_array_ptr: loader.load_or(
+                "glBindVertexArray",
+                &mut missing,
+                missing_bind_vertex_array,
+            ),
+            enable_vertex_attrib_array_ptr: loader.load_or(
+                "glEnableVertexAttribArray",
+                &mut missing,
+                missing_enable_vertex_attrib_array,
+            ),
+            delete_vertex_arrays_ptr: loader.load_or(
+                "glDeleteVertexArrays",
+                &mut missing,
+                missing_delete_vertex_arrays,
+            ),
+            // buffers
+            gen_buffers_ptr: loader.load_or("glGenBuffers", &mut missing, missing_gen_buffers),
+            bind_buffer_ptr: loader.load_or("glBindBuffer", &mut missing, missing_bind_buffer),
+            buffer_data_ptr: loader.load_or("glBufferData", &mut missing, missing_buffer_data),
+            buffer_sub_data_ptr: loader.load_or(
+                "glBufferSubData",
+                &mut missing,
+                missing_buffer_sub_data,
+            ),
+            map_buffer_range_ptr: loader.load_or(
+                "glMapBufferRange",
+                &mut missing,
+                missing_map_buffer_range,
+            ),
+            unmap_buffer_ptr: loader.load_or("glUnmapBuffer", &mut missing, missing_unmap_buffer),
+            flush_mapped_buffer_range_ptr: loader.load_or(
+                "glFlushMappedBufferRange",
+                &mut missing,
+                missing_flush_mapped_buffer_range,
+            ),
+            get_buffer_sub_data_ptr: loader.load_or(
+                "glGetBufferSubData",
+                &mut missing,
+                missing_get_buffer_sub_data,
+            ),
+            // sync
+            fence_sync_ptr: loader.load_or("glFenceSync", &mut missing, missing_fence_sync),
+            client_wait_sync_ptr: loader.load_or(
+                "glClientWaitSync",
+                &mut missing,
+                missing_client_wait_sync,
+            ),
+            delete_sync_ptr: loader.load_or("glDeleteSync", &mut missing, missing_delete_sync),
+            flush_ptr: loader.load_or("glFlush", &mut missing, missing_flush),
+            finish_ptr: loader.load_or("glFinish", &mut missing, missing_finish),
+            bind_buffer_base_ptr: loader.load_or(
+                "glBindBufferBase",
+                &mut missing,
+                missing_bind_buffer_base,
+            ),
+            bind_buffer_range_ptr: loader.load_or(
+                "glBindBufferRange",
+                &mut missing,
+                missing_bind_buffer_range,
+            ),
+            delete_buffers_ptr: loader.load_or(
+                "glDeleteBuffers",
+                &mut missing,
+                missing_delete_buffers,
+            ),
+            // shaders
+            create_shader_ptr: loader.load_or(
+                "glCreateShader",
+                &mut missing,
+                missing_create_shader,
+            ),
+            shader_source_ptr: loader.load_or(
+                "glShaderSource",
+                &mut missing,
+                missing_shader_source,
+            ),
+            compile_shader_ptr: loader.load_or(
+                "glCompileShader",
+                &mut missing,
+                missing_compile_shader,
+            ),
+            delete_shader_ptr: loader.load_or(
+                "glDeleteShader",
+                &mut missing,
+                missing_delete_shader,
+            ),
+            get_shader_iv_ptr: loader.load_or("glGetShaderiv", &mut missing, missing_get_shader_iv),
+            get_shader_info_log_ptr: loader.load_or(
+                "glGetShaderInfoLog",
+                &mut missing,
+                missing_get_shader_info_log,
+            ),
+            // programs
+            create_program_ptr: loader.load_or(
+                "glCreateProgram",
+                &mut missing,
+                missing_create_program,
+            ),
+            attach_shader_ptr: loader.load_or(
+                "glAttachShader",
+                &mut missing,
+                missing_attach_shader,
+            ),
+            link_program_ptr: loader.load_or("glLinkProgram", &mut missing, missing_link_program),
+            get_program_iv_ptr: loader.load_or(
+                "glGetProgramiv",
+                &mut missing,
+                missing_get_program_iv,
+            ),
+            get_program_info_log_ptr: loader.load_or(
+                "glGetProgramInfoLog",
+                &mut missing,
+                missing_get_program_info_log,
+            ),
+            detach_shader_ptr: loader.load_or(
+                "glDetachShader",
+                &mut missing,
+                missing_detach_shader,
+            ),
+            use_program_ptr: loader.load_or("glUseProgram", &mut missing, missing_use_program),
+            delete_program_ptr: loader.load_or(
+                "glDeleteProgram",
+                &mut missing,
+                missing_delete_program,
+            ),
+            get_uniform_block_index_ptr: loader.load_or(
+                "glGetUniformBlockIndex",
+                &mut missing,
+                missing_get_uniform_block_index,
+            ),
+            uniform_block_binding_ptr: loader.load_or(
+                "glUniformBlockBinding",
+                &mut missing,
+                missing_uniform_block_binding,
+            ),
+            uniform1_i_ptr: loader.load_or("glUniform1i", &mut missing, missing_uniform1_i),
+            uniform1_f_ptr: loader.load_or("glUniform1f", &mut missing, missing_uniform1_f),
+            uniform1_ui_ptr: loader.load_or("glUniform1ui", &mut missing, missing_uniform1_ui),
+            uniform1_fv_ptr: loader.load_or("glUniform1fv", &mut missing, missing_uniform1_fv),
+            uniform2_fv_ptr: loader.load_or("glUniform2fv", &mut missing, missing_uniform2_fv),
+            uniform3_fv_ptr: loader.load_or("glUniform3fv", &mut missing, missing_uniform3_fv),
+            uniform4_fv_ptr: loader.load_or("glUniform4fv", &mut missing, missing_uniform4_fv),
+            uniform1_iv_ptr: loader.load_or("glUniform1iv", &mut missing, missing_uniform1_iv),
+            uniform2_iv_ptr: loader.load_or("glUniform2iv", &mut missing, missing_uniform2_iv),
+            uniform3_iv_ptr: loader.load_or("glUniform3iv", &mut missing, missing_uniform3_iv),
+            uniform4_iv_ptr: loader.load_or("glUniform4iv", &mut missing, missing_uniform4_iv),
+            uniform1_uiv_ptr: loader.load_or("glUniform1uiv", &mut missing, missing_uniform1_uiv),
+            uniform2_uiv_ptr: loader.load_or("glUniform2uiv", &mut missing, missing_uniform2_uiv),
+            uniform3_uiv_ptr: loader.load_or("glUniform3uiv", &mut missing, missing_uniform3_uiv),
+            uniform4_uiv_ptr: loader.load_or("glUniform4uiv", &mut missing, missing_uniform4_uiv),
+            uniform_matrix3_fv_ptr: loader.load_or(
+                "glUniformMatrix3fv",
+                &mut missing,
+                missing_uniform_matrix3_fv,
+            ),
+            uniform_matrix4_fv_ptr: loader.load_or(
+                "glUniformMatrix4fv",
+                &mut missing,
+                missing_uniform_matrix4_fv,
+            ),
+            // set uniforms of `program` without binding it first
+            program_uniform1_i_ptr: loader.load_or(
+                "glProgramUniform1i",
+                &mut missing,
+                missing_program_uniform1_i,
+            ),
+            program_uniform1_f_ptr: loader.load_or(
+                "glProgramUniform1f",
+                &mut missing,
+                missing_program_uniform1_f,
+            ),
+            program_uniform1_ui_ptr: loader.load_or(
+                "glProgramUniform1ui",
+                &mut missing,
+                missing_program_uniform1_ui,
+            ),
+            program_uniform1_fv_ptr: loader.load_or(
+                "glProgramUniform1fv",
+                &mut missing,
+                missing_program_uniform1_fv,
+            ),
+            program_uniform2_fv_ptr: loader.load_or(
+                "glProgramUniform2fv",
+                &mut missing,
+                missing_program_uniform2_fv,
+            ),
+            program_uniform3_fv_ptr: loader.load_or(
+                "glProgramUniform3fv",
+                &mut missing,
+                missing_program_uniform3_fv,
+            ),
+            program_uniform4_fv_ptr: loader.load_or(
+                "glProgramUniform4fv",
+                &mut missing,
+                missing_program_uniform4_fv,
+            ),
+            program_uniform1_iv_ptr: loader.load_or(
+                "glProgramUniform1iv",
+                &mut missing,
+                missing_program_uniform1_iv,
+            ),
+            program_uniform2_iv_ptr: loader.load_or(
+                "glProgramUniform2iv",
+                &mut missing,
+                missing_program_uniform2_iv,
+            ),
+            program_uniform3_iv_ptr: loader.load_or(
+                "glProgramUniform3iv",
+                &mut missing,
+                missing_program_uniform3_iv,
+            ),
+            program_uniform4_iv_ptr: loader.load_or(
+                "glProgramUniform4iv",
+                &mut missing,
+                missing_program_uniform4_iv,
+            ),
+            program_uniform1_uiv_ptr: loader.load_or(
+                "glProgramUniform1uiv",
+                &mut missing,
+                missing_program_uniform1_uiv,
+            ),
+            program_uniform2_uiv_ptr: loader.load_or(
+                "glProgramUniform2uiv",
+                &mut missing,
+                missing_program_uniform2_uiv,
+            ),
+            program_uniform3_uiv_ptr: loader.load_or(
+                "glProgramUniform3uiv",
+                &mut missing,
+                missing_program_uniform3_uiv,
+            ),
+            program_uniform4_uiv_ptr: loader.load_or(
+                "glProgramUniform4uiv",
+                &mut missing,
+                missing_program_uniform4_uiv,
+            ),
+            program_uniform_matrix3_fv_ptr: loader.load_or(
+                "glProgramUniformMatrix3fv",
+                &mut missing,
+                missing_program_uniform_matrix3_fv,
+            ),
+            program_uniform_matrix4_fv_ptr: loader.load_or(
+                "glProgramUniformMatrix4fv",
+                &mut missing,
+                missing_program_uniform_matrix4_fv,
+            ),
+            // textures
+            gen_textures_ptr: loader.load_or("glGenTextures", &mut missing, missing_gen_textures),
+            active_texture_ptr: loader.load_or(
+                "glActiveTexture",
+                &mut missing,
+                missing_active_texture,
+            ),
+            bind_texture_ptr: loader.load_or("glBindTexture", &mut missing, missing_bind_texture),
+            tex_image_2d_ptr: loader.load_or("glTexImage2D", &mut missing, missing_tex_image_2d),
+            tex_image_3d_ptr: loader.load_or("glTexImage3D", &mut missing, missing_tex_image_3d),
+            compressed_tex_image_2d_ptr: loader.load_or(
+                "glCompressedTexImage2D",
+                &mut missing,
+                missing_compressed_tex_image_2d,
+            ),
+            tex_storage_2d_ptr: loader.load_or(
+                "glTexStorage2D",
+                &mut missing,
+                missing_tex_storage_2d,
+            ),
+            tex_storage_3d_ptr: loader.load_or(
+                "glTexStorage3D",
+                &mut missing,
+                missing_tex_storage_3d,
+            ),
+            tex_sub_image_2d_ptr: loader.load_or(
+                "glTexSubImage2D",
+                &mut missing,
+                missing_tex_sub_image_2d,
+            ),
+            tex_sub_image_3d_ptr: loader.load_or(
+                "glTexSubImage3D",
+                &mut missing,
+                missing_tex_sub_image_3d,
+            ),
+            generate_mipmap_ptr: loader.load_or(
+                "glGenerateMipmap",
+                &mut missing,
+                missing_generate_mipmap,
+            ),
+            pixel_store_i_ptr: loader.load_or("glPixelStorei", &mut missing, missing_pixel_store_i),
+            delete_textures_ptr: loader.load_or(
+                "glDeleteTextures",
+                &mut missing,
+                missing_delete_textures,
+            ),
+            // queries
+            gen_queries_ptr: loader.load_or("glGenQueries", &mut missing, missing_gen_queries),
+            begin_query_ptr: loader.load_or("glBeginQuery", &mut missing, missing_begin_query),
+            end_query_ptr: loader.load_or("glEndQuery", &mut missing, missing_end_query),
+            query_counter_ptr: loader.load_or(
+                "glQueryCounter",
+                &mut missing,
+                missing_query_counter,
+            ),
+            get_query_object_iv_ptr: loader.load_or(
+                "glGetQueryObjectiv",
+                &mut missing,
+                missing_get_query_object_iv,
+            ),
+            get_query_object_uiv_ptr: loader.load_or(
+                "glGetQueryObjectuiv",
+                &mut missing,
+                missing_get_query_object_uiv,
+            ),
+            get_query_object_i64v_ptr: loader.load_or(
+                "glGetQueryObjecti64v",
+                &mut missing,
+                missing_get_query_object_i64v,
+            ),
+            get_query_object_ui64v_ptr: loader.load_or(
+                "glGetQueryObjectui64v",
+                &mut missing,
+                missing_get_query_object_ui64v,
+            ),
+            delete_queries_ptr: loader.load_or(
+                "glDeleteQueries",
+                &mut missing,
+                missing_delete_queries,
+            ),
+            // samplers
+            gen_samplers_ptr: loader.load_or("glGenSamplers", &mut missing, missing_gen_samplers),
+            bind_sampler_ptr: loader.load_or("glBindSampler", &mut missing, missing_bind_sampler),
+            sampler_parameter_i_ptr: loader.load_or(
+                "glSamplerParameteri",
+                &mut missing,
+                missing_sampler_parameter_i,
+            ),
+            sampler_parameter_f_ptr: loader.load_or(
+                "glSamplerParameterf",
+                &mut missing,
+                missing_sampler_parameter_f,
+            ),
+            sampler_parameter_fv_ptr: loader.load_or(
+                "glSamplerParameterfv",
+                &mut missing,
+                missing_sampler_parameter_fv,
+            ),
+            delete_samplers_ptr: loader.load_or(
+                "glDeleteSamplers",
+                &mut missing,
+                missing_delete_samplers,
+            ),
+            // framebuffers
+            gen_framebuffers_ptr: loader.load_or(
+                "glGenFramebuffers",
+                &mut missing,
+                missing_gen_framebuffers,
+            ),
+            bind_framebuffer_ptr: loader.load_or(
+                "glBindFramebuffer",
+                &mut missing,
+                missing_bind_framebuffer,
+            ),
+            framebuffer_texture_2d_ptr: loader.load_or(
+                "glFramebufferTexture2D",
+                &mut missing,
+                missing_framebuffer_texture_2d,
+            ),
+            framebuffer_renderbuffer_ptr: loader.load_or(
+                "glFramebufferRenderbuffer",
+                &mut missing,
+                missing_framebuffer_renderbuffer,
+            ),
+            check_framebuffer_status_ptr: loader.load_or(
+                "glCheckFramebufferStatus",
+                &mut missing,
+                missing_check_framebuffer_status,
+            ),
+            draw_buffers_ptr: loader.load_or("glDrawBuffers", &mut missing, missing_draw_buffers),
+            read_buffer_ptr: loader.load_or("glReadBuffer", &mut missing, missing_read_buffer),
+            read_pixels_ptr: loader.load_or("glReadPixels", &mut missing, missing_read_pixels),
+            blit_framebuffer_ptr: loader.load_or(
+                "glBlitFramebuffer",
+                &mut missing,
+                missing_blit_framebuffer,
+            ),
+            delete_framebuffers_ptr: loader.load_or(
+                "glDeleteFramebuffers",
+                &mut missing,
+                missing_delete_framebuffers,
+            ),
+            // renderbuffers
+            gen_renderbuffers_ptr: loader.load_or(
+                "glGenRenderbuffers",
+                &mut missing,
+                missing_gen_renderbuffers,
+            ),
+            bind_renderbuffer_ptr: loader.load_or(
+                "glBindRenderbuffer",
+                &mut missing,
+                missing_bind_renderbuffer,
+            ),
+            renderbuffer_storage_ptr: loader.load_or(
+                "glRenderbufferStorage",
+                &mut missing,
+                missing_renderbuffer_storage,
+            ),
+            delete_renderbuffers_ptr: loader.load_or(
+                "glDeleteRenderbuffers",
+                &mut missing,
+                missing_delete_renderbuffers,
+            ),
+            missing,
+        }
+    }
+
+    /// Loads all function pointers like `with_loader`, but fails with the functions the loader
+    /// returned a null pointer for instead of putting in stand-ins that abort when called.
+    ///
+    /// # Safety
+    /// See `with_loader`.
+    pub unsafe fn try_with_loader(
+        loader: &impl Fn(&str) -> *const std::ffi::c_void,
+    ) -> Result<Self, crate::Error> {
+        let api = unsafe { Self::with_loader(loader) };
+        if api.missing.is_empty() {
+            Ok(api)
+        } else {
+            Err(crate::Error::FailedToLoad(api.missing))
+        }
+    }
+
+    /// The functions the context doesn't provide, calling them aborts the process.
+    #[must_use]
+    pub fn missing_functions(&self) -> &[&'static str] {
+        &self.missing
+    }
+
+    // 4.3 API
+
+    // compute
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::similar_names)]
+    pub unsafe fn dispatch_compute(
+        &self,
+        num_groups_x: GLuint,
+        num_groups_y: GLuint,
+        num_groups_z: GLuint,
+    ) {
+        unsafe {
+            (self.dispatch_compute_ptr)(num_groups_x, num_groups_y, num_groups_z);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glDispatchCompute(num_groups_x: {num_groups_x:?}, num_groups_y: {num_groups_y:?}, num_groups_z: {num_groups_z:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Dispatches with the group counts read from the `DISPATCH_INDIRECT_BUFFER` at `indirect`.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn dispatch_compute_indirect(&self, indirect: GLintptr) {
+        unsafe {
+            (self.dispatch_compute_indirect_ptr)(indirect);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDispatchComputeIndirect(indirect: {indirect:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn memory_barrier(&self, barriers: MemoryBarrier) {
+        unsafe {
+            (self.memory_barrier_ptr)(barriers);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glMemoryBarrier(barriers: {barriers:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn bind_image_texture(
+        &self,
+        unit: GLuint,
+        texture: Texture,
+        level: GLint,
+        layered: GLboolean,
+        layer: GLint,
+        access: ImageAccess,
+        format: InternalFormat,
+    ) {
+        unsafe {
+            (self.bind_image_texture_ptr)(unit, texture, level, layered, layer, access, format);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glBindImageTexture(unit: {unit:?}, texture: {texture:?}, level: {level:?}, layered: {layered:?}, layer: {layer:?}, access: {access:?}, format: {format:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Set the debug message callback.
+    /// Make sure to `enable` `Capability::DEBUG_OUTPUT` and to use a debug context.
+    ///
+    /// The caller has to make sure that the `DebugMessageCallback` and `user_param` will be
+    /// valid for the entirety they are bound.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn debug_message_callback(
+        &self,
+        callback: Option<DebugMessageCallback>,
+        user_param: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            (self.debug_message_callback_ptr)(callback, user_param);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!(
+                    "glDebugMessageCallback(callback: {callback:?}, user_param: {user_param:?})"
+                )
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Enables or disables the messages matching `source`, `kind` and `severity`, limited to
+    /// the `count` ids if there are any. `DONT_CARE` matches any source, type or severity, ids
+    /// require a specific source and type and `DebugSeverity::DONT_CARE`.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn debug_message_control(
+        &self,
+        source: DebugSource,
+        kind: DebugType,
+        severity: DebugSeverity,
+        count: GLsizei,
+        ids: *const GLuint,
+        enabled: GLboolean,
+    ) {
+        unsafe {
+            (self.debug_message_control_ptr)(source, kind, severity, count, ids, enabled);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glDebugMessageControl(source: {source:?}, kind: {kind:?}, severity: {severity:?}, count: {count:?}, ids: {ids:?}, enabled: {enabled:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Takes up to `count` messages out of the log kept while no callback is set, returns how
+    /// many. The texts are stored back to back, each with a nul terminator.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn get_debug_message_log(
+        &self,
+        count: GLuint,
+        buf_size: GLsizei,
+        sources: *mut DebugSource,
+        types: *mut DebugType,
+        ids: *mut GLuint,
+        severities: *mut DebugSeverity,
+        lengths: *mut GLsizei,
+        message_log: *mut GLchar,
+    ) -> GLuint {
+        let result = unsafe {
+            (self.get_debug_message_log_ptr)(
+                count,
+                buf_size,
+                sources,
+                types,
+                ids,
+                severities,
+                lengths,
+                message_log,
+            )
+        };
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glGetDebugMessageLog(count: {count:?}, buf_size: {buf_size:?}, sources: {sources:?}, types: {types:?}, ids: {ids:?}, severities: {severities:?}, lengths: {lengths:?}, message_log: {message_log:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+        result
+    }
+
+    /// Inserts a message into the debug output, shows up as a marker in frame debuggers.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn debug_message_insert(
+        &self,
+        source: DebugSource,
+        kind: DebugType,
+        id: GLuint,
+        severity: DebugSeverity,
+        length: GLsizei,
+        buf: *const GLchar,
+    ) {
+        unsafe {
+            (self.debug_message_insert_ptr)(source, kind, id, severity, length, buf);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glDebugMessageInsert(source: {source:?}, kind: {kind:?}, id: {id:?}, severity: {severity:?}, length: {length:?}, buf: {buf:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Opens a named group of calls for frame debuggers, see `DebugScope`.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn push_debug_group(
+        &self,
+        source: DebugSource,
+        id: GLuint,
+        length: GLsizei,
+        message: *const GLchar,
+    ) {
+        unsafe {
+            (self.push_debug_group_ptr)(source, id, length, message);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glPushDebugGroup(source: {source:?}, id: {id:?}, length: {length:?}, message: {message:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn pop_debug_group(&self) {
+        unsafe {
+            (self.pop_debug_group_ptr)();
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || String::from("glPopDebugGroup()"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Names an object for debug messages and frame debuggers, see `Api::label`.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn object_label(
+        &self,
+        identifier: ObjectIdentifier,
+        name: GLuint,
+        length: GLsizei,
+        label: *const GLchar,
+    ) {
+        unsafe {
+            (self.object_label_ptr)(identifier, name, length, label);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glObjectLabel(identifier: {identifier:?}, name: {name:?}, length: {length:?}, label: {label:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // vertex arrays
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn vertex_attrib_format(
+        &self,
+        attribindex: AttributeIndex,
+        size: AttributeComponents,
+        kind: VertexAttributeKind,
+        normalized: GLboolean,
+        relativeoffset: GLuint,
+    ) {
+        unsafe {
+            (self.vertex_attrib_format_ptr)(attribindex, size, kind, normalized, relativeoffset);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glVertexAttribFormat(attribindex: {attribindex:?}, size: {size:?}, kind: {kind:?}, normalized: {normalized:?}, relativeoffset: {relativeoffset:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Like `vertex_attrib_format`, but the shader reads the integers as they are, as `int` or
+    /// `uint` vectors. Only takes the integer kinds.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn vertex_attrib_i_format(
+        &self,
+        attribindex: AttributeIndex,
+        size: AttributeComponents,
+        kind: VertexAttributeKind,
+        relativeoffset: GLuint,
+    ) {
+        unsafe {
+            (self.vertex_attrib_i_format_ptr)(attribindex, size, kind, relativeoffset);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glVertexAttribIFormat(attribindex: {attribindex:?}, size: {size:?}, kind: {kind:?}, relativeoffset: {relativeoffset:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn bind_vertex_buffer(
+        &self,
+        bindingindex: BufferBindingIndex,
+        buffer: Buffer,
+        offset: GLintptr,
+        stride: GLsizei,
+    ) {
+        unsafe {
+            (self.bind_vertex_buffer_ptr)(bindingindex, buffer, offset, stride);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glBindVertexBuffer(bindingindex: {bindingindex:?}, buffer: {buffer:?}, offset: {offset:?}, stride: {stride:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Advances the attributes of the binding once every `divisor` instances instead of
+    /// once per vertex, zero turns instancing off again.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn vertex_binding_divisor(&self, bindingindex: BufferBindingIndex, divisor: GLuint) {
+        unsafe {
+            (self.vertex_binding_divisor_ptr)(bindingindex, divisor);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!(
+                    "glVertexBindingDivisor(bindingindex: {bindingindex:?}, divisor: {divisor:?})"
+                )
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn vertex_attrib_binding(
+        &self,
+        attribindex: AttributeIndex,
+        bindingindex: BufferBindingIndex,
+    ) {
+        unsafe {
+            (self.vertex_attrib_binding_ptr)(attribindex, bindingindex);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glVertexAttribBinding(attribindex: {attribindex:?}, bindingindex: {bindingindex:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // programs
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn get_program_interface_iv(
+        &self,
+        program: Program,
+        program_interface: ProgramInterface,
+        pname: ProgramInterfaceParameterName,
+        params: *mut GLint,
+    ) {
+        unsafe {
+            (self.get_program_interface_iv_ptr)(program, program_interface, pname, params);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glGetProgramInterfaceiv(program: {program:?}, program_interface: {program_interface:?}, pname: {pname:?}, params: {params:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn get_program_resource_iv(
+        &self,
+        program: Program,
+        program_interface: ProgramInterface,
+        index: GLuint,
+        prop_count: GLsizei,
+        props: *const ProgramResourceProperty,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        params: *mut GLint,
+    ) {
+        unsafe {
+            (self.get_program_resource_iv_ptr)(
+                program,
+                program_interface,
+                index,
+                prop_count,
+                props,
+                buf_size,
+                length,
+                params,
+            );
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glGetProgramResourceiv(program: {program:?}, program_interface: {program_interface:?}, index: {index:?}, prop_count: {prop_count:?}, props: {props:?}, buf_size: {buf_size:?}, length: {length:?}, params: {params:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn get_program_resource_name(
+        &self,
+        program: Program,
+        program_interface: ProgramInterface,
+        index: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        name: *mut GLchar,
+    ) {
+        unsafe {
+            (self.get_program_resource_name_ptr)(
+                program,
+                program_interface,
+                index,
+                buf_size,
+                length,
+                name,
+            );
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glGetProgramResourceName(program: {program:?}, program_interface: {program_interface:?}, index: {index:?}, buf_size: {buf_size:?}, length: {length:?}, name: {name:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[must_use]
+    pub unsafe fn get_program_resource_index(
+        &self,
+        program: Program,
+        program_interface: ProgramInterface,
+        name: *const GLchar,
+    ) -> GLuint {
+        let result =
+            unsafe { (self.get_program_resource_index_ptr)(program, program_interface, name) };
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glGetProgramResourceIndex(program: {program:?}, program_interface: {program_interface:?}, name: {name:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+        result
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn shader_storage_block_binding(
+        &self,
+        program: Program,
+        storage_block_index: GLuint,
+        storage_block_binding: GLuint,
+    ) {
+        unsafe {
+            (self.shader_storage_block_binding_ptr)(
+                program,
+                storage_block_index,
+                storage_block_binding,
+            );
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glShaderStorageBlockBinding(program: {program:?}, storage_block_index: {storage_block_index:?}, storage_block_binding: {storage_block_binding:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // previous versions
+
+    // state
+
+    /// Enables certain state or context capabilities.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn enable(&self, cap: Capability) {
+        unsafe {
+            (self.enable_ptr)(cap);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glEnable(cap: {cap:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Disables certain state or context capabilities.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn disable(&self, cap: Capability) {
+        unsafe {
+            (self.disable_ptr)(cap);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDisable(cap: {cap:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn depth_func(&self, func: DepthFunc) {
+        unsafe {
+            (self.depth_func_ptr)(func);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDepthFunc(func: {func:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn depth_mask(&self, flag: GLboolean) {
+        unsafe {
+            (self.depth_mask_ptr)(flag);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDepthMask(flag: {flag:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn color_mask(
+        &self,
+        red: GLboolean,
+        green: GLboolean,
+        blue: GLboolean,
+        alpha: GLboolean,
+    ) {
+        unsafe {
+            (self.color_mask_ptr)(red, green, blue, alpha);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!(
+                    "glColorMask(red: {red:?}, green: {green:?}, blue: {blue:?}, alpha: {alpha:?})"
+                )
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn cull_face(&self, mode: Face) {
+        unsafe {
+            (self.cull_face_ptr)(mode);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glCullFace(mode: {mode:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn front_face(&self, mode: FrontFace) {
+        unsafe {
+            (self.front_face_ptr)(mode);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glFrontFace(mode: {mode:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Core profiles only accept `Face::FRONT_AND_BACK`.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn polygon_mode(&self, face: Face, mode: PolygonMode) {
+        unsafe {
+            (self.polygon_mode_ptr)(face, mode);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glPolygonMode(face: {face:?}, mode: {mode:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn polygon_offset(&self, factor: GLfloat, units: GLfloat) {
+        unsafe {
+            (self.polygon_offset_ptr)(factor, units);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glPolygonOffset(factor: {factor:?}, units: {units:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::similar_names)]
+    pub unsafe fn scissor(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+        unsafe {
+            (self.scissor_ptr)(x, y, width, height);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glScissor(x: {x:?}, y: {y:?}, width: {width:?}, height: {height:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::similar_names)]
+    pub unsafe fn blend_func(&self, sfactor: BlendFactor, dfactor: BlendFactor) {
+        unsafe {
+            (self.blend_func_ptr)(sfactor, dfactor);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glBlendFunc(sfactor: {sfactor:?}, dfactor: {dfactor:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::similar_names)]
+    pub unsafe fn blend_func_separate(
+        &self,
+        sfactor_rgb: BlendFactor,
+        dfactor_rgb: BlendFactor,
+        sfactor_alpha: BlendFactor,
+        dfactor_alpha: BlendFactor,
+    ) {
+        unsafe {
+            (self.blend_func_separate_ptr)(sfactor_rgb, dfactor_rgb, sfactor_alpha, dfactor_alpha);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glBlendFuncSeparate(sfactor_rgb: {sfactor_rgb:?}, dfactor_rgb: {dfactor_rgb:?}, sfactor_alpha: {sfactor_alpha:?}, dfactor_alpha: {dfactor_alpha:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn blend_equation(&self, mode: BlendEquation) {
+        unsafe {
+            (self.blend_equation_ptr)(mode);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glBlendEquation(mode: {mode:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn blend_equation_separate(
+        &self,
+        mode_rgb: BlendEquation,
+        mode_alpha: BlendEquation,
+    ) {
+        unsafe {
+            (self.blend_equation_separate_ptr)(mode_rgb, mode_alpha);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!(
+                    "glBlendEquationSeparate(mode_rgb: {mode_rgb:?}, mode_alpha: {mode_alpha:?})"
+                )
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn blend_color(&self, red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+        unsafe {
+            (self.blend_color_ptr)(red, green, blue, alpha);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glBlendColor(red: {red:?}, green: {green:?}, blue: {blue:?}, alpha: {alpha:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn stencil_func(&self, func: StencilFunc, reference: GLint, mask: GLuint) {
+        unsafe {
+            (self.stencil_func_ptr)(func, reference, mask);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glStencilFunc(func: {func:?}, reference: {reference:?}, mask: {mask:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn stencil_op(&self, fail: StencilOp, zfail: StencilOp, zpass: StencilOp) {
+        unsafe {
+            (self.stencil_op_ptr)(fail, zfail, zpass);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glStencilOp(fail: {fail:?}, zfail: {zfail:?}, zpass: {zpass:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn stencil_mask(&self, mask: GLuint) {
+        unsafe {
+            (self.stencil_mask_ptr)(mask);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glStencilMask(mask: {mask:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn clear(&self, mask: ClearMask) {
+        unsafe {
+            (self.clear_ptr)(mask);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glClear(mask: {mask:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Sets the clear color
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn clear_color(&self, red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+        unsafe {
+            (self.clear_color_ptr)(red, green, blue, alpha);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glClearColor(red: {red:?}, green: {green:?}, blue: {blue:?}, alpha: {alpha:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::similar_names)]
+    pub unsafe fn viewport(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+        unsafe {
+            (self.viewport_ptr)(x, y, width, height);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glViewport(x: {x:?}, y: {y:?}, width: {width:?}, height: {height:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn get_integer_v(&self, pname: StateParameterName, data: *mut GLint) {
+        unsafe {
+            (self.get_integer_v_ptr)(pname, data);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGetIntegerv(pname: {pname:?}, data: {data:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Returns and clears one of the recorded error flags, `NO_ERROR` once all are cleared.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[must_use]
+    pub unsafe fn get_error(&self) -> ErrorCode {
+        unsafe { (self.get_error_ptr)() }
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[must_use]
+    pub unsafe fn get_string(&self, name: StringName) -> *const GLubyte {
+        let result = unsafe { (self.get_string_ptr)(name) };
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGetString(name: {name:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+        result
+    }
+
+    /// Only accepts `StringName::EXTENSIONS`, with `index` below `NUM_EXTENSIONS`.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[must_use]
+    pub unsafe fn get_stringi(&self, name: StringName, index: GLuint) -> *const GLubyte {
+        let result = unsafe { (self.get_stringi_ptr)(name, index) };
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGetStringi(name: {name:?}, index: {index:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+        result
+    }
+
+    // draw
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn draw_elements(
+        &self,
+        mode: Primitive,
+        count: GLsizei,
+        kind: ElementKind,
+        indices: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            (self.draw_elements_ptr)(mode, count, kind, indices);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glDrawElements(mode: {mode:?}, count: {count:?}, kind: {kind:?}, indices: {indices:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn draw_elements_base_vertex(
+        &self,
+        mode: Primitive,
+        count: GLsizei,
+        kind: ElementKind,
+        indices: *const std::ffi::c_void,
+        basevertex: GLint,
+    ) {
+        unsafe {
+            (self.draw_elements_base_vertex_ptr)(mode, count, kind, indices, basevertex);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glDrawElementsBaseVertex(mode: {mode:?}, count: {count:?}, kind: {kind:?}, indices: {indices:?}, basevertex: {basevertex:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn draw_arrays(&self, mode: Primitive, first: GLint, count: GLsizei) {
+        unsafe {
+            (self.draw_arrays_ptr)(mode, first, count);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDrawArrays(mode: {mode:?}, first: {first:?}, count: {count:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn draw_arrays_instanced(
+        &self,
+        mode: Primitive,
+        first: GLint,
+        count: GLsizei,
+        instancecount: GLsizei,
+    ) {
+        unsafe {
+            (self.draw_arrays_instanced_ptr)(mode, first, count, instancecount);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glDrawArraysInstanced(mode: {mode:?}, first: {first:?}, count: {count:?}, instancecount: {instancecount:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn draw_elements_instanced_base_vertex_base_instance(
+        &self,
+        mode: Primitive,
+        count: GLsizei,
+        kind: ElementKind,
+        indices: *const std::ffi::c_void,
+        instancecount: GLsizei,
+        basevertex: GLint,
+        baseinstance: GLuint,
+    ) {
+        unsafe {
+            (self.draw_elements_instanced_base_vertex_base_instance_ptr)(
+                mode,
+                count,
+                kind,
+                indices,
+                instancecount,
+                basevertex,
+                baseinstance,
+            );
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glDrawElementsInstancedBaseVertexBaseInstance(mode: {mode:?}, count: {count:?}, kind: {kind:?}, indices: {indices:?}, instancecount: {instancecount:?}, basevertex: {basevertex:?}, baseinstance: {baseinstance:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Draws `drawcount` `DrawElementsIndirectCommand`s read from the `DRAW_INDIRECT_BUFFER`,
+    /// `indirect` is the byte offset of the first one.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn multi_draw_elements_indirect(
+        &self,
+        mode: Primitive,
+        kind: ElementKind,
+        indirect: *const std::ffi::c_void,
+        drawcount: GLsizei,
+        stride: GLsizei,
+    ) {
+        unsafe {
+            (self.multi_draw_elements_indirect_ptr)(mode, kind, indirect, drawcount, stride);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glMultiDrawElementsIndirect(mode: {mode:?}, kind: {kind:?}, indirect: {indirect:?}, drawcount: {drawcount:?}, stride: {stride:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Sets the number of vertices per patch for `Primitive::PATCHES` draws, the default is 3.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn patch_parameter_i(&self, pname: PatchParameterName, value: GLint) {
+        unsafe {
+            (self.patch_parameter_i_ptr)(pname, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glPatchParameteri(pname: {pname:?}, value: {value:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // vertex arrays
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn gen_vertex_arrays(&self, n: GLsizei, arrays: *mut VertexArray) {
+        unsafe {
+            (self.gen_vertex_arrays_ptr)(n, arrays);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGenVertexArrays(n: {n:?}, arrays: {arrays:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn bind_vertex_array(&self, array: VertexArray) {
+        unsafe {
+            (self.bind_vertex_array_ptr)(array);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glBindVertexArray(array: {array:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn enable_vertex_attrib_array(&self, index: AttributeIndex) {
+        unsafe {
+            (self.enable_vertex_attrib_array_ptr)(index);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glEnableVertexAttribArray(index: {index:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn delete_vertex_arrays(&self, n: GLsizei, arrays: *const VertexArray) {
+        unsafe {
+            (self.delete_vertex_arrays_ptr)(n, arrays);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDeleteVertexArrays(n: {n:?}, arrays: {arrays:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // buffers
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn gen_buffers(&self, n: GLsizei, buffers: *mut Buffer) {
+        unsafe {
+            (self.gen_buffers_ptr)(n, buffers);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGenBuffers(n: {n:?}, buffers: {buffers:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn bind_buffer(&self, target: BufferTarget, buffer: Buffer) {
+        unsafe {
+            (self.bind_buffer_ptr)(target, buffer);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glBindBuffer(target: {target:?}, buffer: {buffer:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn buffer_data(
+        &self,
+        target: BufferTarget,
+        size: GLsizeiptr,
+        data: *const std::ffi::c_void,
+        usage: BufferUsage,
+    ) {
+        unsafe {
+            (self.buffer_data_ptr)(target, size, data, usage);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glBufferData(target: {target:?}, size: {size:?}, data: {data:?}, usage: {usage:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn buffer_sub_data(
+        &self,
+        target: BufferTarget,
+        offset: GLintptr,
+        size: GLsizeiptr,
+        data: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            (self.buffer_sub_data_ptr)(target, offset, size, data);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glBufferSubData(target: {target:?}, offset: {offset:?}, size: {size:?}, data: {data:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[must_use]
+    pub unsafe fn map_buffer_range(
+        &self,
+        target: BufferTarget,
+        offset: GLintptr,
+        length: GLsizeiptr,
+        access: MapAccess,
+    ) -> *mut std::ffi::c_void {
+        let result = unsafe { (self.map_buffer_range_ptr)(target, offset, length, access) };
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glMapBufferRange(target: {target:?}, offset: {offset:?}, length: {length:?}, access: {access:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+        result
+    }
+
+    /// Returns `GLboolean::FALSE` if the contents got corrupted while mapped.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[must_use]
+    pub unsafe fn unmap_buffer(&self, target: BufferTarget) -> GLboolean {
+        let result = unsafe { (self.unmap_buffer_ptr)(target) };
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUnmapBuffer(target: {target:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+        result
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn flush_mapped_buffer_range(
+        &self,
+        target: BufferTarget,
+        offset: GLintptr,
+        length: GLsizeiptr,
+    ) {
+        unsafe {
+            (self.flush_mapped_buffer_range_ptr)(target, offset, length);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glFlushMappedBufferRange(target: {target:?}, offset: {offset:?}, length: {length:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn get_buffer_sub_data(
+        &self,
+        target: BufferTarget,
+        offset: GLintptr,
+        size: GLsizeiptr,
+        data: *mut std::ffi::c_void,
+    ) {
+        unsafe {
+            (self.get_buffer_sub_data_ptr)(target, offset, size, data);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glGetBufferSubData(target: {target:?}, offset: {offset:?}, size: {size:?}, data: {data:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // sync
+
+    /// `flags` has to be zero.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[must_use]
+    pub unsafe fn fence_sync(&self, condition: SyncCondition, flags: GLbitField) -> GLsync {
+        let result = unsafe { (self.fence_sync_ptr)(condition, flags) };
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glFenceSync(condition: {condition:?}, flags: {flags:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+        result
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[must_use]
+    pub unsafe fn client_wait_sync(
+        &self,
+        sync: GLsync,
+        flags: SyncWaitFlags,
+        timeout: GLuint64,
+    ) -> SyncStatus {
+        let result = unsafe { (self.client_wait_sync_ptr)(sync, flags, timeout) };
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glClientWaitSync(sync: {sync:?}, flags: {flags:?}, timeout: {timeout:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+        result
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn delete_sync(&self, sync: GLsync) {
+        unsafe {
+            (self.delete_sync_ptr)(sync);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDeleteSync(sync: {sync:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn flush(&self) {
+        unsafe {
+            (self.flush_ptr)();
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || String::from("glFlush()"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Blocks until all previous commands completed, prefer fences outside of tests and tools.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn finish(&self) {
+        unsafe {
+            (self.finish_ptr)();
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || String::from("glFinish()"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Binds the buffer to the indexed binding point `index` of a `UNIFORM_BUFFER` or
+    /// `SHADER_STORAGE_BUFFER` target, as well as to the generic binding of `target`.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn bind_buffer_base(&self, target: BufferTarget, index: GLuint, buffer: Buffer) {
+        unsafe {
+            (self.bind_buffer_base_ptr)(target, index, buffer);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!(
+                    "glBindBufferBase(target: {target:?}, index: {index:?}, buffer: {buffer:?})"
+                )
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Like `bind_buffer_base`, but only binds `size` bytes starting at `offset`, which has to
+    /// be a multiple of the `*_OFFSET_ALIGNMENT` of the target.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn bind_buffer_range(
+        &self,
+        target: BufferTarget,
+        index: GLuint,
+        buffer: Buffer,
+        offset: GLintptr,
+        size: GLsizeiptr,
+    ) {
+        unsafe {
+            (self.bind_buffer_range_ptr)(target, index, buffer, offset, size);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glBindBufferRange(target: {target:?}, index: {index:?}, buffer: {buffer:?}, offset: {offset:?}, size: {size:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn delete_buffers(&self, n: GLsizei, buffers: *const Buffer) {
+        unsafe {
+            (self.delete_buffers_ptr)(n, buffers);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDeleteBuffers(n: {n:?}, buffers: {buffers:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // shaders
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[must_use]
+    pub unsafe fn create_shader(&self, kind: ShaderKind) -> Shader {
+        let result = unsafe { (self.create_shader_ptr)(kind) };
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glCreateShader(kind: {kind:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+        result
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn shader_source(
+        &self,
+        shader: Shader,
+        count: GLsizei,
+        string: *const *const GLchar,
+        length: *const GLint,
+    ) {
+        unsafe {
+            (self.shader_source_ptr)(shader, count, string, length);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glShaderSource(shader: {shader:?}, count: {count:?}, string: {string:?}, length: {length:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn compile_shader(&self, shader: Shader) {
+        unsafe {
+            (self.compile_shader_ptr)(shader);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glCompileShader(shader: {shader:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn delete_shader(&self, shader: Shader) {
+        unsafe {
+            (self.delete_shader_ptr)(shader);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDeleteShader(shader: {shader:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn get_shader_iv(
+        &self,
+        shader: Shader,
+        pname: ShaderParameterName,
+        params: *mut GLint,
+    ) {
+        unsafe {
+            (self.get_shader_iv_ptr)(shader, pname, params);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGetShaderiv(shader: {shader:?}, pname: {pname:?}, params: {params:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn get_shader_info_log(
+        &self,
+        shader: Shader,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        info_log: *mut GLchar,
+    ) {
+        unsafe {
+            (self.get_shader_info_log_ptr)(shader, buf_size, length, info_log);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glGetShaderInfoLog(shader: {shader:?}, buf_size: {buf_size:?}, length: {length:?}, info_log: {info_log:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // programs
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[must_use]
+    pub unsafe fn create_program(&self) -> Program {
+        let result = unsafe { (self.create_program_ptr)() };
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || String::from("glCreateProgram()"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+        result
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn attach_shader(&self, program: Program, shader: Shader) {
+        unsafe {
+            (self.attach_shader_ptr)(program, shader);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glAttachShader(program: {program:?}, shader: {shader:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn link_program(&self, program: Program) {
+        unsafe {
+            (self.link_program_ptr)(program);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glLinkProgram(program: {program:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn get_program_iv(
+        &self,
+        program: Program,
+        pname: ProgramParameterName,
+        params: *mut GLint,
+    ) {
+        unsafe {
+            (self.get_program_iv_ptr)(program, pname, params);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!(
+                    "glGetProgramiv(program: {program:?}, pname: {pname:?}, params: {params:?})"
+                )
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn get_program_info_log(
+        &self,
+        program: Program,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        info_log: *mut GLchar,
+    ) {
+        unsafe {
+            (self.get_program_info_log_ptr)(program, buf_size, length, info_log);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glGetProgramInfoLog(program: {program:?}, buf_size: {buf_size:?}, length: {length:?}, info_log: {info_log:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn detach_shader(&self, program: Program, shader: Shader) {
+        unsafe {
+            (self.detach_shader_ptr)(program, shader);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDetachShader(program: {program:?}, shader: {shader:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn use_program(&self, program: Program) {
+        unsafe {
+            (self.use_program_ptr)(program);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUseProgram(program: {program:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn delete_program(&self, program: Program) {
+        unsafe {
+            (self.delete_program_ptr)(program);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDeleteProgram(program: {program:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[must_use]
+    pub unsafe fn get_uniform_block_index(
+        &self,
+        program: Program,
+        uniform_block_name: *const GLchar,
+    ) -> GLuint {
+        let result = unsafe { (self.get_uniform_block_index_ptr)(program, uniform_block_name) };
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glGetUniformBlockIndex(program: {program:?}, uniform_block_name: {uniform_block_name:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+        result
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform_block_binding(
+        &self,
+        program: Program,
+        uniform_block_index: GLuint,
+        uniform_block_binding: GLuint,
+    ) {
+        unsafe {
+            (self.uniform_block_binding_ptr)(program, uniform_block_index, uniform_block_binding);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glUniformBlockBinding(program: {program:?}, uniform_block_index: {uniform_block_index:?}, uniform_block_binding: {uniform_block_binding:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform1_i(&self, location: GLint, v0: GLint) {
+        unsafe {
+            (self.uniform1_i_ptr)(location, v0);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUniform1i(location: {location:?}, v0: {v0:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform1_f(&self, location: GLint, v0: GLfloat) {
+        unsafe {
+            (self.uniform1_f_ptr)(location, v0);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUniform1f(location: {location:?}, v0: {v0:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform1_ui(&self, location: GLint, v0: GLuint) {
+        unsafe {
+            (self.uniform1_ui_ptr)(location, v0);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUniform1ui(location: {location:?}, v0: {v0:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform1_fv(&self, location: GLint, count: GLsizei, value: *const GLfloat) {
+        unsafe {
+            (self.uniform1_fv_ptr)(location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUniform1fv(location: {location:?}, count: {count:?}, value: {value:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform2_fv(&self, location: GLint, count: GLsizei, value: *const GLfloat) {
+        unsafe {
+            (self.uniform2_fv_ptr)(location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUniform2fv(location: {location:?}, count: {count:?}, value: {value:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform3_fv(&self, location: GLint, count: GLsizei, value: *const GLfloat) {
+        unsafe {
+            (self.uniform3_fv_ptr)(location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUniform3fv(location: {location:?}, count: {count:?}, value: {value:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform4_fv(&self, location: GLint, count: GLsizei, value: *const GLfloat) {
+        unsafe {
+            (self.uniform4_fv_ptr)(location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUniform4fv(location: {location:?}, count: {count:?}, value: {value:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform1_iv(&self, location: GLint, count: GLsizei, value: *const GLint) {
+        unsafe {
+            (self.uniform1_iv_ptr)(location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUniform1iv(location: {location:?}, count: {count:?}, value: {value:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform2_iv(&self, location: GLint, count: GLsizei, value: *const GLint) {
+        unsafe {
+            (self.uniform2_iv_ptr)(location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUniform2iv(location: {location:?}, count: {count:?}, value: {value:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform3_iv(&self, location: GLint, count: GLsizei, value: *const GLint) {
+        unsafe {
+            (self.uniform3_iv_ptr)(location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUniform3iv(location: {location:?}, count: {count:?}, value: {value:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform4_iv(&self, location: GLint, count: GLsizei, value: *const GLint) {
+        unsafe {
+            (self.uniform4_iv_ptr)(location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUniform4iv(location: {location:?}, count: {count:?}, value: {value:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform1_uiv(&self, location: GLint, count: GLsizei, value: *const GLuint) {
+        unsafe {
+            (self.uniform1_uiv_ptr)(location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUniform1uiv(location: {location:?}, count: {count:?}, value: {value:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform2_uiv(&self, location: GLint, count: GLsizei, value: *const GLuint) {
+        unsafe {
+            (self.uniform2_uiv_ptr)(location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUniform2uiv(location: {location:?}, count: {count:?}, value: {value:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform3_uiv(&self, location: GLint, count: GLsizei, value: *const GLuint) {
+        unsafe {
+            (self.uniform3_uiv_ptr)(location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUniform3uiv(location: {location:?}, count: {count:?}, value: {value:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform4_uiv(&self, location: GLint, count: GLsizei, value: *const GLuint) {
+        unsafe {
+            (self.uniform4_uiv_ptr)(location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glUniform4uiv(location: {location:?}, count: {count:?}, value: {value:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform_matrix3_fv(
+        &self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) {
+        unsafe {
+            (self.uniform_matrix3_fv_ptr)(location, count, transpose, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glUniformMatrix3fv(location: {location:?}, count: {count:?}, transpose: {transpose:?}, value: {value:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn uniform_matrix4_fv(
+        &self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) {
+        unsafe {
+            (self.uniform_matrix4_fv_ptr)(location, count, transpose, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glUniformMatrix4fv(location: {location:?}, count: {count:?}, transpose: {transpose:?}, value: {value:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // set uniforms of `program` without binding it first
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform1_i(&self, program: Program, location: GLint, v0: GLint) {
+        unsafe {
+            (self.program_uniform1_i_ptr)(program, location, v0);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!(
+                    "glProgramUniform1i(program: {program:?}, location: {location:?}, v0: {v0:?})"
+                )
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform1_f(&self, program: Program, location: GLint, v0: GLfloat) {
+        unsafe {
+            (self.program_uniform1_f_ptr)(program, location, v0);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!(
+                    "glProgramUniform1f(program: {program:?}, location: {location:?}, v0: {v0:?})"
+                )
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform1_ui(&self, program: Program, location: GLint, v0: GLuint) {
+        unsafe {
+            (self.program_uniform1_ui_ptr)(program, location, v0);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!(
+                    "glProgramUniform1ui(program: {program:?}, location: {location:?}, v0: {v0:?})"
+                )
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform1_fv(
+        &self,
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLfloat,
+    ) {
+        unsafe {
+            (self.program_uniform1_fv_ptr)(program, location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glProgramUniform1fv(program: {program:?}, location: {location:?}, count: {count:?}, value: {value:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform2_fv(
+        &self,
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLfloat,
+    ) {
+        unsafe {
+            (self.program_uniform2_fv_ptr)(program, location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glProgramUniform2fv(program: {program:?}, location: {location:?}, count: {count:?}, value: {value:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform3_fv(
+        &self,
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLfloat,
+    ) {
+        unsafe {
+            (self.program_uniform3_fv_ptr)(program, location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glProgramUniform3fv(program: {program:?}, location: {location:?}, count: {count:?}, value: {value:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform4_fv(
+        &self,
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLfloat,
+    ) {
+        unsafe {
+            (self.program_uniform4_fv_ptr)(program, location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glProgramUniform4fv(program: {program:?}, location: {location:?}, count: {count:?}, value: {value:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform1_iv(
+        &self,
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLint,
+    ) {
+        unsafe {
+            (self.program_uniform1_iv_ptr)(program, location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glProgramUniform1iv(program: {program:?}, location: {location:?}, count: {count:?}, value: {value:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform2_iv(
+        &self,
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLint,
+    ) {
+        unsafe {
+            (self.program_uniform2_iv_ptr)(program, location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glProgramUniform2iv(program: {program:?}, location: {location:?}, count: {count:?}, value: {value:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform3_iv(
+        &self,
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLint,
+    ) {
+        unsafe {
+            (self.program_uniform3_iv_ptr)(program, location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glProgramUniform3iv(program: {program:?}, location: {location:?}, count: {count:?}, value: {value:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform4_iv(
+        &self,
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLint,
+    ) {
+        unsafe {
+            (self.program_uniform4_iv_ptr)(program, location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glProgramUniform4iv(program: {program:?}, location: {location:?}, count: {count:?}, value: {value:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform1_uiv(
+        &self,
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLuint,
+    ) {
+        unsafe {
+            (self.program_uniform1_uiv_ptr)(program, location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glProgramUniform1uiv(program: {program:?}, location: {location:?}, count: {count:?}, value: {value:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform2_uiv(
+        &self,
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLuint,
+    ) {
+        unsafe {
+            (self.program_uniform2_uiv_ptr)(program, location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glProgramUniform2uiv(program: {program:?}, location: {location:?}, count: {count:?}, value: {value:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform3_uiv(
+        &self,
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLuint,
+    ) {
+        unsafe {
+            (self.program_uniform3_uiv_ptr)(program, location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glProgramUniform3uiv(program: {program:?}, location: {location:?}, count: {count:?}, value: {value:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform4_uiv(
+        &self,
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLuint,
+    ) {
+        unsafe {
+            (self.program_uniform4_uiv_ptr)(program, location, count, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glProgramUniform4uiv(program: {program:?}, location: {location:?}, count: {count:?}, value: {value:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform_matrix3_fv(
+        &self,
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) {
+        unsafe {
+            (self.program_uniform_matrix3_fv_ptr)(program, location, count, transpose, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glProgramUniformMatrix3fv(program: {program:?}, location: {location:?}, count: {count:?}, transpose: {transpose:?}, value: {value:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn program_uniform_matrix4_fv(
+        &self,
+        program: Program,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) {
+        unsafe {
+            (self.program_uniform_matrix4_fv_ptr)(program, location, count, transpose, value);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glProgramUniformMatrix4fv(program: {program:?}, location: {location:?}, count: {count:?}, transpose: {transpose:?}, value: {value:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // textures
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn gen_textures(&self, n: GLsizei, textures: *mut Texture) {
+        unsafe {
+            (self.gen_textures_ptr)(n, textures);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGenTextures(n: {n:?}, textures: {textures:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn active_texture(&self, texture: TextureUnit) {
+        unsafe {
+            (self.active_texture_ptr)(texture);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glActiveTexture(texture: {texture:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn bind_texture(&self, target: TextureTarget, texture: Texture) {
+        unsafe {
+            (self.bind_texture_ptr)(target, texture);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glBindTexture(target: {target:?}, texture: {texture:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn tex_image_2d(
+        &self,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        border: Border,
+        format: TextureFormat,
+        kind: TextureDataFormat,
+        pixels: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            (self.tex_image_2d_ptr)(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                format,
+                kind,
+                pixels,
+            );
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glTexImage2D(target: {target:?}, level: {level:?}, internalformat: {internalformat:?}, width: {width:?}, height: {height:?}, border: {border:?}, format: {format:?}, kind: {kind:?}, pixels: {pixels:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn tex_image_3d(
+        &self,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        border: Border,
+        format: TextureFormat,
+        kind: TextureDataFormat,
+        pixels: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            (self.tex_image_3d_ptr)(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                depth,
+                border,
+                format,
+                kind,
+                pixels,
+            );
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glTexImage3D(target: {target:?}, level: {level:?}, internalformat: {internalformat:?}, width: {width:?}, height: {height:?}, depth: {depth:?}, border: {border:?}, format: {format:?}, kind: {kind:?}, pixels: {pixels:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn compressed_tex_image_2d(
+        &self,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        border: Border,
+        image_size: GLsizei,
+        data: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            (self.compressed_tex_image_2d_ptr)(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                image_size,
+                data,
+            );
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glCompressedTexImage2D(target: {target:?}, level: {level:?}, internalformat: {internalformat:?}, width: {width:?}, height: {height:?}, border: {border:?}, image_size: {image_size:?}, data: {data:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Allocates immutable storage for all `levels` of the texture.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn tex_storage_2d(
+        &self,
+        target: TextureTarget,
+        levels: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ) {
+        unsafe {
+            (self.tex_storage_2d_ptr)(target, levels, internalformat, width, height);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glTexStorage2D(target: {target:?}, levels: {levels:?}, internalformat: {internalformat:?}, width: {width:?}, height: {height:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Allocates immutable storage for all `levels` of the texture, `depth` is the number of
+    /// layers for array textures.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn tex_storage_3d(
+        &self,
+        target: TextureTarget,
+        levels: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+    ) {
+        unsafe {
+            (self.tex_storage_3d_ptr)(target, levels, internalformat, width, height, depth);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glTexStorage3D(target: {target:?}, levels: {levels:?}, internalformat: {internalformat:?}, width: {width:?}, height: {height:?}, depth: {depth:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::similar_names)]
+    pub unsafe fn tex_sub_image_2d(
+        &self,
+        target: TextureTarget,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: TextureFormat,
+        kind: TextureDataFormat,
+        pixels: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            (self.tex_sub_image_2d_ptr)(
+                target, level, xoffset, yoffset, width, height, format, kind, pixels,
+            );
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glTexSubImage2D(target: {target:?}, level: {level:?}, xoffset: {xoffset:?}, yoffset: {yoffset:?}, width: {width:?}, height: {height:?}, format: {format:?}, kind: {kind:?}, pixels: {pixels:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::similar_names)]
+    pub unsafe fn tex_sub_image_3d(
+        &self,
+        target: TextureTarget,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        format: TextureFormat,
+        kind: TextureDataFormat,
+        pixels: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            (self.tex_sub_image_3d_ptr)(
+                target, level, xoffset, yoffset, zoffset, width, height, depth, format, kind,
+                pixels,
+            );
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glTexSubImage3D(target: {target:?}, level: {level:?}, xoffset: {xoffset:?}, yoffset: {yoffset:?}, zoffset: {zoffset:?}, width: {width:?}, height: {height:?}, depth: {depth:?}, format: {format:?}, kind: {kind:?}, pixels: {pixels:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn generate_mipmap(&self, target: TextureTarget) {
+        unsafe {
+            (self.generate_mipmap_ptr)(target);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGenerateMipmap(target: {target:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Sets how pixel rows are laid out in client memory, e.g. `UNPACK_ALIGNMENT` to 1 for
+    /// tightly packed RGB rows.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn pixel_store_i(&self, pname: PixelStoreParameter, param: GLint) {
+        unsafe {
+            (self.pixel_store_i_ptr)(pname, param);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glPixelStorei(pname: {pname:?}, param: {param:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn delete_textures(&self, n: GLsizei, textures: *const Texture) {
+        unsafe {
+            (self.delete_textures_ptr)(n, textures);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDeleteTextures(n: {n:?}, textures: {textures:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // queries
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn gen_queries(&self, n: GLsizei, ids: *mut Query) {
+        unsafe {
+            (self.gen_queries_ptr)(n, ids);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGenQueries(n: {n:?}, ids: {ids:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn begin_query(&self, target: QueryTarget, id: Query) {
+        unsafe {
+            (self.begin_query_ptr)(target, id);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glBeginQuery(target: {target:?}, id: {id:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn end_query(&self, target: QueryTarget) {
+        unsafe {
+            (self.end_query_ptr)(target);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glEndQuery(target: {target:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Records the GPU time in nanoseconds once all previous commands finished.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn query_counter(&self, id: Query, target: QueryCounterTarget) {
+        unsafe {
+            (self.query_counter_ptr)(id, target);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glQueryCounter(id: {id:?}, target: {target:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn get_query_object_iv(&self, id: Query, pname: QueryParameter, params: *mut GLint) {
+        unsafe {
+            (self.get_query_object_iv_ptr)(id, pname, params);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGetQueryObjectiv(id: {id:?}, pname: {pname:?}, params: {params:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn get_query_object_uiv(
+        &self,
+        id: Query,
+        pname: QueryParameter,
+        params: *mut GLuint,
+    ) {
+        unsafe {
+            (self.get_query_object_uiv_ptr)(id, pname, params);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGetQueryObjectuiv(id: {id:?}, pname: {pname:?}, params: {params:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn get_query_object_i64v(
+        &self,
+        id: Query,
+        pname: QueryParameter,
+        params: *mut GLint64,
+    ) {
+        unsafe {
+            (self.get_query_object_i64v_ptr)(id, pname, params);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGetQueryObjecti64v(id: {id:?}, pname: {pname:?}, params: {params:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Reading `QUERY_RESULT` blocks until the result is available, check
+    /// `QUERY_RESULT_AVAILABLE` first to avoid stalling.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn get_query_object_ui64v(
+        &self,
+        id: Query,
+        pname: QueryParameter,
+        params: *mut GLuint64,
+    ) {
+        unsafe {
+            (self.get_query_object_ui64v_ptr)(id, pname, params);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGetQueryObjectui64v(id: {id:?}, pname: {pname:?}, params: {params:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn delete_queries(&self, n: GLsizei, ids: *const Query) {
+        unsafe {
+            (self.delete_queries_ptr)(n, ids);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDeleteQueries(n: {n:?}, ids: {ids:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // samplers
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn gen_samplers(&self, count: GLsizei, samplers: *mut Sampler) {
+        unsafe {
+            (self.gen_samplers_ptr)(count, samplers);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGenSamplers(count: {count:?}, samplers: {samplers:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn bind_sampler(&self, unit: GLuint, sampler: Sampler) {
+        unsafe {
+            (self.bind_sampler_ptr)(unit, sampler);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glBindSampler(unit: {unit:?}, sampler: {sampler:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// See `sampler_parameter` for typed values.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn sampler_parameter_i(
+        &self,
+        sampler: Sampler,
+        pname: SamplerParameterName,
+        param: SamplerParameterValue,
+    ) {
+        unsafe {
+            (self.sampler_parameter_i_ptr)(sampler, pname, param);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!(
+                    "glSamplerParameteri(sampler: {sampler:?}, pname: {pname:?}, param: {param:?})"
+                )
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn sampler_parameter_f(
+        &self,
+        sampler: Sampler,
+        pname: SamplerParameterName,
+        param: GLfloat,
+    ) {
+        unsafe {
+            (self.sampler_parameter_f_ptr)(sampler, pname, param);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!(
+                    "glSamplerParameterf(sampler: {sampler:?}, pname: {pname:?}, param: {param:?})"
+                )
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn sampler_parameter_fv(
+        &self,
+        sampler: Sampler,
+        pname: SamplerParameterName,
+        param: *const GLfloat,
+    ) {
+        unsafe {
+            (self.sampler_parameter_fv_ptr)(sampler, pname, param);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glSamplerParameterfv(sampler: {sampler:?}, pname: {pname:?}, param: {param:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn delete_samplers(&self, count: GLsizei, samplers: *const Sampler) {
+        unsafe {
+            (self.delete_samplers_ptr)(count, samplers);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDeleteSamplers(count: {count:?}, samplers: {samplers:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // framebuffers
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn gen_framebuffers(&self, n: GLsizei, framebuffers: *mut Framebuffer) {
+        unsafe {
+            (self.gen_framebuffers_ptr)(n, framebuffers);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGenFramebuffers(n: {n:?}, framebuffers: {framebuffers:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn bind_framebuffer(&self, target: FramebufferTarget, framebuffer: Framebuffer) {
+        unsafe {
+            (self.bind_framebuffer_ptr)(target, framebuffer);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glBindFramebuffer(target: {target:?}, framebuffer: {framebuffer:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn framebuffer_texture_2d(
+        &self,
+        target: FramebufferTarget,
+        attachment: FramebufferAttachment,
+        textarget: TextureTarget,
+        texture: Texture,
+        level: GLint,
+    ) {
+        unsafe {
+            (self.framebuffer_texture_2d_ptr)(target, attachment, textarget, texture, level);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glFramebufferTexture2D(target: {target:?}, attachment: {attachment:?}, textarget: {textarget:?}, texture: {texture:?}, level: {level:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn framebuffer_renderbuffer(
+        &self,
+        target: FramebufferTarget,
+        attachment: FramebufferAttachment,
+        renderbuffertarget: RenderbufferTarget,
+        renderbuffer: Renderbuffer,
+    ) {
+        unsafe {
+            (self.framebuffer_renderbuffer_ptr)(
+                target,
+                attachment,
+                renderbuffertarget,
+                renderbuffer,
+            );
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glFramebufferRenderbuffer(target: {target:?}, attachment: {attachment:?}, renderbuffertarget: {renderbuffertarget:?}, renderbuffer: {renderbuffer:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[must_use]
+    pub unsafe fn check_framebuffer_status(&self, target: FramebufferTarget) -> FramebufferStatus {
+        let result = unsafe { (self.check_framebuffer_status_ptr)(target) };
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glCheckFramebufferStatus(target: {target:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+        result
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn draw_buffers(&self, n: GLsizei, bufs: *const ColorBuffer) {
+        unsafe {
+            (self.draw_buffers_ptr)(n, bufs);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDrawBuffers(n: {n:?}, bufs: {bufs:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn read_buffer(&self, src: ColorBuffer) {
+        unsafe {
+            (self.read_buffer_ptr)(src);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glReadBuffer(src: {src:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Reads from the `read_buffer` of the bound `READ_FRAMEBUFFER`, rows bottom to top. With a
+    /// `PIXEL_PACK_BUFFER` bound `pixels` is an offset into it and the call doesn't wait.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::similar_names)]
+    pub unsafe fn read_pixels(
+        &self,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: TextureFormat,
+        kind: TextureDataFormat,
+        pixels: *mut std::ffi::c_void,
+    ) {
+        unsafe {
+            (self.read_pixels_ptr)(x, y, width, height, format, kind, pixels);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glReadPixels(x: {x:?}, y: {y:?}, width: {width:?}, height: {height:?}, format: {format:?}, kind: {kind:?}, pixels: {pixels:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::similar_names)]
+    pub unsafe fn blit_framebuffer(
+        &self,
+        src_x0: GLint,
+        src_y0: GLint,
+        src_x1: GLint,
+        src_y1: GLint,
+        dst_x0: GLint,
+        dst_y0: GLint,
+        dst_x1: GLint,
+        dst_y1: GLint,
+        mask: ClearMask,
+        filter: BlitFilter,
+    ) {
+        unsafe {
+            (self.blit_framebuffer_ptr)(
+                src_x0, src_y0, src_x1, src_y1, dst_x0, dst_y0, dst_x1, dst_y1, mask, filter,
+            );
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glBlitFramebuffer(src_x0: {src_x0:?}, src_y0: {src_y0:?}, src_x1: {src_x1:?}, src_y1: {src_y1:?}, dst_x0: {dst_x0:?}, dst_y0: {dst_y0:?}, dst_x1: {dst_x1:?}, dst_y1: {dst_y1:?}, mask: {mask:?}, filter: {filter:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn delete_framebuffers(&self, n: GLsizei, framebuffers: *const Framebuffer) {
+        unsafe {
+            (self.delete_framebuffers_ptr)(n, framebuffers);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDeleteFramebuffers(n: {n:?}, framebuffers: {framebuffers:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // renderbuffers
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn gen_renderbuffers(&self, n: GLsizei, renderbuffers: *mut Renderbuffer) {
+        unsafe {
+            (self.gen_renderbuffers_ptr)(n, renderbuffers);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGenRenderbuffers(n: {n:?}, renderbuffers: {renderbuffers:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn bind_renderbuffer(&self, target: RenderbufferTarget, renderbuffer: Renderbuffer) {
+        unsafe {
+            (self.bind_renderbuffer_ptr)(target, renderbuffer);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glBindRenderbuffer(target: {target:?}, renderbuffer: {renderbuffer:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn renderbuffer_storage(
+        &self,
+        target: RenderbufferTarget,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ) {
+        unsafe {
+            (self.renderbuffer_storage_ptr)(target, internalformat, width, height);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glRenderbufferStorage(target: {target:?}, internalformat: {internalformat:?}, width: {width:?}, height: {height:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn delete_renderbuffers(&self, n: GLsizei, renderbuffers: *const Renderbuffer) {
+        unsafe {
+            (self.delete_renderbuffers_ptr)(n, renderbuffers);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glDeleteRenderbuffers(n: {n:?}, renderbuffers: {renderbuffers:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+}
+
+extern "system" fn missing_dispatch_compute(_: GLuint, _: GLuint, _: GLuint) {
+    crate::missing("glDispatchCompute")
+}
+extern "system" fn missing_dispatch_compute_indirect(_: GLintptr) {
+    crate::missing("glDispatchComputeIndirect")
+}
+extern "system" fn missing_memory_barrier(_: MemoryBarrier) {
+    crate::missing("glMemoryBarrier")
+}
+extern "system" fn missing_bind_image_texture(
+    _: GLuint,
+    _: Texture,
+    _: GLint,
+    _: GLboolean,
+    _: GLint,
+    _: ImageAccess,
+    _: InternalFormat,
+) {
+    crate::missing("glBindImageTexture")
+}
+extern "system" fn missing_debug_message_callback(
+    _: Option<DebugMessageCallback>,
+    _: *const std::ffi::c_void,
+) {
+    crate::missing("glDebugMessageCallback")
+}
+extern "system" fn missing_debug_message_control(
+    _: DebugSource,
+    _: DebugType,
+    _: DebugSeverity,
+    _: GLsizei,
+    _: *const GLuint,
+    _: GLboolean,
+) {
+    crate::missing("glDebugMessageControl")
+}
+#[allow(clippy::too_many_arguments)]
+extern "system" fn missing_get_debug_message_log(
+    _: GLuint,
+    _: GLsizei,
+    _: *mut DebugSource,
+    _: *mut DebugType,
+    _: *mut GLuint,
+    _: *mut DebugSeverity,
+    _: *mut GLsizei,
+    _: *mut GLchar,
+) -> GLuint {
+    crate::missing("glGetDebugMessageLog")
+}
+extern "system" fn missing_debug_message_insert(
+    _: DebugSource,
+    _: DebugType,
+    _: GLuint,
+    _: DebugSeverity,
+    _: GLsizei,
+    _: *const GLchar,
+) {
+    crate::missing("glDebugMessageInsert")
+}
+extern "system" fn missing_push_debug_group(
+    _: DebugSource,
+    _: GLuint,
+    _: GLsizei,
+    _: *const GLchar,
+) {
+    crate::missing("glPushDebugGroup")
+}
+extern "system" fn missing_pop_debug_group() {
+    crate::missing("glPopDebugGroup")
+}
+extern "system" fn missing_object_label(
+    _: ObjectIdentifier,
+    _: GLuint,
+    _: GLsizei,
+    _: *const GLchar,
+) {
+    crate::missing("glObjectLabel")
+}
+extern "system" fn missing_vertex_attrib_format(
+    _: AttributeIndex,
+    _: AttributeComponents,
+    _: VertexAttributeKind,
+    _: GLboolean,
+    _: GLuint,
+) {
+    crate::missing("glVertexAttribFormat")
+}
+extern "system" fn missing_vertex_attrib_i_format(
+    _: AttributeIndex,
+    _: AttributeComponents,
+    _: VertexAttributeKind,
+    _: GLuint,
+) {
+    crate::missing("glVertexAttribIFormat")
+}
+extern "system" fn missing_bind_vertex_buffer(
+    _: BufferBindingIndex,
+    _: Buffer,
+    _: GLintptr,
+    _: GLsizei,
+) {
+    crate::missing("glBindVertexBuffer")
+}
+extern "system" fn missing_vertex_binding_divisor(_: BufferBindingIndex, _: GLuint) {
+    crate::missing("glVertexBindingDivisor")
+}
+extern "system" fn missing_vertex_attrib_binding(_: AttributeIndex, _: BufferBindingIndex) {
+    crate::missing("glVertexAttribBinding")
+}
+extern "system" fn missing_get_program_interface_iv(
+    _: Program,
+    _: ProgramInterface,
+    _: ProgramInterfaceParameterName,
+    _: *mut GLint,
+) {
+    crate::missing("glGetProgramInterfaceiv")
+}
+#[allow(clippy::too_many_arguments)]
+extern "system" fn missing_get_program_resource_iv(
+    _: Program,
+    _: ProgramInterface,
+    _: GLuint,
+    _: GLsizei,
+    _: *const ProgramResourceProperty,
+    _: GLsizei,
+    _: *mut GLsizei,
+    _: *mut GLint,
+) {
+    crate::missing("glGetProgramResourceiv")
+}
+extern "system" fn missing_get_program_resource_name(
+    _: Program,
+    _: ProgramInterface,
+    _: GLuint,
+    _: GLsizei,
+    _: *mut GLsizei,
+    _: *mut GLchar,
+) {
+    crate::missing("glGetProgramResourceName")
+}
+extern "system" fn missing_get_program_resource_index(
+    _: Program,
+    _: ProgramInterface,
+    _: *const GLchar,
+) -> GLuint {
+    crate::missing("glGetProgramResourceIndex")
+}
+extern "system" fn missing_shader_storage_block_binding(_: Program, _: GLuint, _: GLuint) {
+    crate::missing("glShaderStorageBlockBinding")
+}
+extern "system" fn missing_enable(_: Capability) {
+    crate::missing("glEnable")
+}
+extern "system" fn missing_disable(_: Capability) {
+    crate::missing("glDisable")
+}
+extern "system" fn missing_depth_func(_: DepthFunc) {
+    crate::missing("glDepthFunc")
+}
+extern "system" fn missing_depth_mask(_: GLboolean) {
+    crate::missing("glDepthMask")
+}
+extern "system" fn missing_color_mask(_: GLboolean, _: GLboolean, _: GLboolean, _: GLboolean) {
+    crate::missing("glColorMask")
+}
+extern "system" fn missing_cull_face(_: Face) {
+    crate::missing("glCullFace")
+}
+extern "system" fn missing_front_face(_: FrontFace) {
+    crate::missing("glFrontFace")
+}
+extern "system" fn missing_polygon_mode(_: Face, _: PolygonMode) {
+    crate::missing("glPolygonMode")
+}
+extern "system" fn missing_polygon_offset(_: GLfloat, _: GLfloat) {
+    crate::missing("glPolygonOffset")
+}
+extern "system" fn missing_scissor(_: GLint, _: GLint, _: GLsizei, _: GLsizei) {
+    crate::missing("glScissor")
+}
+extern "system" fn missing_blend_func(_: BlendFactor, _: BlendFactor) {
+    crate::missing("glBlendFunc")
+}
+extern "system" fn missing_blend_func_separate(
+    _: BlendFactor,
+    _: BlendFactor,
+    _: BlendFactor,
+    _: BlendFactor,
+) {
+    crate::missing("glBlendFuncSeparate")
+}
+extern "system" fn missing_blend_equation(_: BlendEquation) {
+    crate::missing("glBlendEquation")
+}
+extern "system" fn missing_blend_equation_separate(_: BlendEquation, _: BlendEquation) {
+    crate::missing("glBlendEquationSeparate")
+}
+extern "system" fn missing_blend_color(_: GLfloat, _: GLfloat, _: GLfloat, _: GLfloat) {
+    crate::missing("glBlendColor")
+}
+extern "system" fn missing_stencil_func(_: StencilFunc, _: GLint, _: GLuint) {
+    crate::missing("glStencilFunc")
+}
+extern "system" fn missing_stencil_op(_: StencilOp, _: StencilOp, _: StencilOp) {
+    crate::missing("glStencilOp")
+}
+extern "system" fn missing_stencil_mask(_: GLuint) {
+    crate::missing("glStencilMask")
+}
+extern "system" fn missing_clear(_: ClearMask) {
+    crate::missing("glClear")
+}
+extern "system" fn missing_clear_color(_: GLfloat, _: GLfloat, _: GLfloat, _: GLfloat) {
+    crate::missing("glClearColor")
+}
+extern "system" fn missing_viewport(_: GLint, _: GLint, _: GLsizei, _: GLsizei) {
+    crate::missing("glViewport")
+}
+extern "system" fn missing_get_integer_v(_: StateParameterName, _: *mut GLint) {
+    crate::missing("glGetIntegerv")
+}
+extern "system" fn missing_get_error() -> ErrorCode {
+    crate::missing("glGetError")
+}
+extern "system" fn missing_get_string(_: StringName) -> *const GLubyte {
+    crate::missing("glGetString")
+}
+extern "system" fn missing_get_stringi(_: StringName, _: GLuint) -> *const GLubyte {
+    crate::missing("glGetStringi")
+}
+extern "system" fn missing_draw_elements(
+    _: Primitive,
+    _: GLsizei,
+    _: ElementKind,
+    _: *const std::ffi::c_void,
+) {
+    crate::missing("glDrawElements")
+}
+extern "system" fn missing_draw_elements_base_vertex(
+    _: Primitive,
+    _: GLsizei,
+    _: ElementKind,
+    _: *const std::ffi::c_void,
+    _: GLint,
+) {
+    crate::missing("glDrawElementsBaseVertex")
+}
+extern "system" fn missing_draw_arrays(_: Primitive, _: GLint, _: GLsizei) {
+    crate::missing("glDrawArrays")
+}
+extern "system" fn missing_draw_arrays_instanced(_: Primitive, _: GLint, _: GLsizei, _: GLsizei) {
+    crate::missing("glDrawArraysInstanced")
+}
+extern "system" fn missing_draw_elements_instanced_base_vertex_base_instance(
+    _: Primitive,
+    _: GLsizei,
+    _: ElementKind,
+    _: *const std::ffi::c_void,
+    _: GLsizei,
+    _: GLint,
+    _: GLuint,
+) {
+    crate::missing("glDrawElementsInstancedBaseVertexBaseInstance")
+}
+extern "system" fn missing_multi_draw_elements_indirect(
+    _: Primitive,
+    _: ElementKind,
+    _: *const std::ffi::c_void,
+    _: GLsizei,
+    _: GLsizei,
+) {
+    crate::missing("glMultiDrawElementsIndirect")
+}
+extern "system" fn missing_patch_parameter_i(_: PatchParameterName, _: GLint) {
+    crate::missing("glPatchParameteri")
+}
+extern "system" fn missing_gen_vertex_arrays(_: GLsizei, _: *mut VertexArray) {
+    crate::missing("glGenVertexArrays")
+}
+extern "system" fn missing_bind_vertex_array(_: VertexArray) {
+    crate::missing("glBindVertexArray")
+}
+extern "system" fn missing_enable_vertex_attrib_array(_: AttributeIndex) {
+    crate::missing("glEnableVertexAttribArray")
+}
+extern "system" fn missing_delete_vertex_arrays(_: GLsizei, _: *const VertexArray) {
+    crate::missing("glDeleteVertexArrays")
+}
+extern "system" fn missing_gen_buffers(_: GLsizei, _: *mut Buffer) {
+    crate::missing("glGenBuffers")
+}
+extern "system" fn missing_bind_buffer(_: BufferTarget, _: Buffer) {
+    crate::missing("glBindBuffer")
+}
+extern "system" fn missing_buffer_data(
+    _: BufferTarget,
+    _: GLsizeiptr,
+    _: *const std::ffi::c_void,
+    _: BufferUsage,
+) {
+    crate::missing("glBufferData")
+}
+extern "system" fn missing_buffer_sub_data(
+    _: BufferTarget,
+    _: GLintptr,
+    _: GLsizeiptr,
+    _: *const std::ffi::c_void,
+) {
+    crate::missing("glBufferSubData")
+}
+extern "system" fn missing_map_buffer_range(
+    _: BufferTarget,
+    _: GLintptr,
+    _: GLsizeiptr,
+    _: MapAccess,
+) -> *mut std::ffi::c_void {
+    crate::missing("glMapBufferRange")
+}
+extern "system" fn missing_unmap_buffer(_: BufferTarget) -> GLboolean {
+    crate::missing("glUnmapBuffer")
+}
+extern "system" fn missing_flush_mapped_buffer_range(_: BufferTarget, _: GLintptr, _: GLsizeiptr) {
+    crate::missing("glFlushMappedBufferRange")
+}
+extern "system" fn missing_get_buffer_sub_data(
+    _: BufferTarget,
+    _: GLintptr,
+    _: GLsizeiptr,
+    _: *mut std::ffi::c_void,
+) {
+    crate::missing("glGetBufferSubData")
+}
+extern "system" fn missing_fence_sync(_: SyncCondition, _: GLbitField) -> GLsync {
+    crate::missing("glFenceSync")
+}
+extern "system" fn missing_client_wait_sync(
+    _: GLsync,
+    _: SyncWaitFlags,
+    _: GLuint64,
+) -> SyncStatus {
+    crate::missing("glClientWaitSync")
+}
+extern "system" fn missing_delete_sync(_: GLsync) {
+    crate::missing("glDeleteSync")
+}
+extern "system" fn missing_flush() {
+    crate::missing("glFlush")
+}
+extern "system" fn missing_finish() {
+    crate::missing("glFinish")
+}
+extern "system" fn missing_bind_buffer_base(_: BufferTarget, _: GLuint, _: Buffer) {
+    crate::missing("glBindBufferBase")
+}
+extern "system" fn missing_bind_buffer_range(
+    _: BufferTarget,
+    _: GLuint,
+    _: Buffer,
+    _: GLintptr,
+    _: GLsizeiptr,
+) {
+    crate::missing("glBindBufferRange")
+}
+extern "system" fn missing_delete_buffers(_: GLsizei, _: *const Buffer) {
+    crate::missing("glDeleteBuffers")
+}
+extern "system" fn missing_create_shader(_: ShaderKind) -> Shader {
+    crate::missing("glCreateShader")
+}
+extern "system" fn missing_shader_source(
+    _: Shader,
+    _: GLsizei,
+    _: *const *const GLchar,
+    _: *const GLint,
+) {
+    crate::missing("glShaderSource")
+}
+extern "system" fn missing_compile_shader(_: Shader) {
+    crate::missing("glCompileShader")
+}
+extern "system" fn missing_delete_shader(_: Shader) {
+    crate::missing("glDeleteShader")
+}
+extern "system" fn missing_get_shader_iv(_: Shader, _: ShaderParameterName, _: *mut GLint) {
+    crate::missing("glGetShaderiv")
+}
+extern "system" fn missing_get_shader_info_log(
+    _: Shader,
+    _: GLsizei,
+    _: *mut GLsizei,
+    _: *mut GLchar,
+) {
+    crate::missing("glGetShaderInfoLog")
+}
+extern "system" fn missing_create_program() -> Program {
+    crate::missing("glCreateProgram")
+}
+extern "system" fn missing_attach_shader(_: Program, _: Shader) {
+    crate::missing("glAttachShader")
+}
+extern "system" fn missing_link_program(_: Program) {
+    crate::missing("glLinkProgram")
+}
+extern "system" fn missing_get_program_iv(_: Program, _: ProgramParameterName, _: *mut GLint) {
+    crate::missing("glGetProgramiv")
+}
+extern "system" fn missing_get_program_info_log(
+    _: Program,
+    _: GLsizei,
+    _: *mut GLsizei,
+    _: *mut GLchar,
+) {
+    crate::missing("glGetProgramInfoLog")
+}
+extern "system" fn missing_detach_shader(_: Program, _: Shader) {
+    crate::missing("glDetachShader")
+}
+extern "system" fn missing_use_program(_: Program) {
+    crate::missing("glUseProgram")
+}
+extern "system" fn missing_delete_program(_: Program) {
+    crate::missing("glDeleteProgram")
+}
+extern "system" fn missing_get_uniform_block_index(_: Program, _: *const GLchar) -> GLuint {
+    crate::missing("glGetUniformBlockIndex")
+}
+extern "system" fn missing_uniform_block_binding(_: Program, _: GLuint, _: GLuint) {
+    crate::missing("glUniformBlockBinding")
+}
+extern "system" fn missing_uniform1_i(_: GLint, _: GLint) {
+    crate::missing("glUniform1i")
+}
+extern "system" fn missing_uniform1_f(_: GLint, _: GLfloat) {
+    crate::missing("glUniform1f")
+}
+extern "system" fn missing_uniform1_ui(_: GLint, _: GLuint) {
+    crate::missing("glUniform1ui")
+}
+extern "system" fn missing_uniform1_fv(_: GLint, _: GLsizei, _: *const GLfloat) {
+    crate::missing("glUniform1fv")
+}
+extern "system" fn missing_uniform2_fv(_: GLint, _: GLsizei, _: *const GLfloat) {
+    crate::missing("glUniform2fv")
+}
+extern "system" fn missing_uniform3_fv(_: GLint, _: GLsizei, _: *const GLfloat) {
+    crate::missing("glUniform3fv")
+}
+extern "system" fn missing_uniform4_fv(_: GLint, _: GLsizei, _: *const GLfloat) {
+    crate::missing("glUniform4fv")
+}
+extern "system" fn missing_uniform1_iv(_: GLint, _: GLsizei, _: *const GLint) {
+    crate::missing("glUniform1iv")
+}
+extern "system" fn missing_uniform2_iv(_: GLint, _: GLsizei, _: *const GLint) {
+    crate::missing("glUniform2iv")
+}
+extern "system" fn missing_uniform3_iv(_: GLint, _: GLsizei, _: *const GLint) {
+    crate::missing("glUniform3iv")
+}
+extern "system" fn missing_uniform4_iv(_: GLint, _: GLsizei, _: *const GLint) {
+    crate::missing("glUniform4iv")
+}
+extern "system" fn missing_uniform1_uiv(_: GLint, _: GLsizei, _: *const GLuint) {
+    crate::missing("glUniform1uiv")
+}
+extern "system" fn missing_uniform2_uiv(_: GLint, _: GLsizei, _: *const GLuint) {
+    crate::missing("glUniform2uiv")
+}
+extern "system" fn missing_uniform3_uiv(_: GLint, _: GLsizei, _: *const GLuint) {
+    crate::missing("glUniform3uiv")
+}
+extern "system" fn missing_uniform4_uiv(_: GLint, _: GLsizei, _: *const GLuint) {
+    crate::missing("glUniform4uiv")
+}
+extern "system" fn missing_uniform_matrix3_fv(
+    _: GLint,
+    _: GLsizei,
+    _: GLboolean,
+    _: *const GLfloat,
+) {
+    crate::missing("glUniformMatrix3fv")
+}
+extern "system" fn missing_uniform_matrix4_fv(
+    _: GLint,
+    _: GLsizei,
+    _: GLboolean,
+    _: *const GLfloat,
+) {
+    crate::missing("glUniformMatrix4fv")
+}
+extern "system" fn missing_program_uniform1_i(_: Program, _: GLint, _: GLint) {
+    crate::missing("glProgramUniform1i")
+}
+extern "system" fn missing_program_uniform1_f(_: Program, _: GLint, _: GLfloat) {
+    crate::missing("glProgramUniform1f")
+}
+extern "system" fn missing_program_uniform1_ui(_: Program, _: GLint, _: GLuint) {
+    crate::missing("glProgramUniform1ui")
+}
+extern "system" fn missing_program_uniform1_fv(
+    _: Program,
+    _: GLint,
+    _: GLsizei,
+    _: *const GLfloat,
+) {
+    crate::missing("glProgramUniform1fv")
+}
+extern "system" fn missing_program_uniform2_fv(
+    _: Program,
+    _: GLint,
+    _: GLsizei,
+    _: *const GLfloat,
+) {
+    crate::missing("glProgramUniform2fv")
+}
+extern "system" fn missing_program_uniform3_fv(
+    _: Program,
+    _: GLint,
+    _: GLsizei,
+    _: *const GLfloat,
+) {
+    crate::missing("glProgramUniform3fv")
+}
+extern "system" fn missing_program_uniform4_fv(
+    _: Program,
+    _: GLint,
+    _: GLsizei,
+    _: *const GLfloat,
+) {
+    crate::missing("glProgramUniform4fv")
+}
+extern "system" fn missing_program_uniform1_iv(_: Program, _: GLint, _: GLsizei, _: *const GLint) {
+    crate::missing("glProgramUniform1iv")
+}
+extern "system" fn missing_program_uniform2_iv(_: Program, _: GLint, _: GLsizei, _: *const GLint) {
+    crate::missing("glProgramUniform2iv")
+}
+extern "system" fn missing_program_uniform3_iv(_: Program, _: GLint, _: GLsizei, _: *const GLint) {
+    crate::missing("glProgramUniform3iv")
+}
+extern "system" fn missing_program_uniform4_iv(_: Program, _: GLint, _: GLsizei, _: *const GLint) {
+    crate::missing("glProgramUniform4iv")
+}
+extern "system" fn missing_program_uniform1_uiv(
+    _: Program,
+    _: GLint,
+    _: GLsizei,
+    _: *const GLuint,
+) {
+    crate::missing("glProgramUniform1uiv")
+}
+extern "system" fn missing_program_uniform2_uiv(
+    _: Program,
+    _: GLint,
+    _: GLsizei,
+    _: *const GLuint,
+) {
+    crate::missing("glProgramUniform2uiv")
+}
+extern "system" fn missing_program_uniform3_uiv(
+    _: Program,
+    _: GLint,
+    _: GLsizei,
+    _: *const GLuint,
+) {
+    crate::missing("glProgramUniform3uiv")
+}
+extern "system" fn missing_program_uniform4_uiv(
+    _: Program,
+    _: GLint,
+    _: GLsizei,
+    _: *const GLuint,
+) {
+    crate::missing("glProgramUniform4uiv")
+}
+extern "system" fn missing_program_uniform_matrix3_fv(
+    _: Program,
+    _: GLint,
+    _: GLsizei,
+    _: GLboolean,
+    _: *const GLfloat,
+) {
+    crate::missing("glProgramUniformMatrix3fv")
+}
+extern "system" fn missing_program_uniform_matrix4_fv(
+    _: Program,
+    _: GLint,
+    _: GLsizei,
+    _: GLboolean,
+    _: *const GLfloat,
+) {
+    crate::missing("glProgramUniformMatrix4fv")
+}
+extern "system" fn missing_gen_textures(_: GLsizei, _: *mut Texture) {
+    crate::missing("glGenTextures")
+}
+extern "system" fn missing_active_texture(_: TextureUnit) {
+    crate::missing("glActiveTexture")
+}
+extern "system" fn missing_bind_texture(_: TextureTarget, _: Texture) {
+    crate::missing("glBindTexture")
+}
+#[allow(clippy::too_many_arguments)]
+extern "system" fn missing_tex_image_2d(
+    _: TextureTarget,
+    _: GLint,
+    _: InternalFormat,
+    _: GLsizei,
+    _: GLsizei,
+    _: Border,
+    _: TextureFormat,
+    _: TextureDataFormat,
+    _: *const std::ffi::c_void,
+) {
+    crate::missing("glTexImage2D")
+}
+#[allow(clippy::too_many_arguments)]
+extern "system" fn missing_tex_image_3d(
+    _: TextureTarget,
+    _: GLint,
+    _: InternalFormat,
+    _: GLsizei,
+    _: GLsizei,
+    _: GLsizei,
+    _: Border,
+    _: TextureFormat,
+    _: TextureDataFormat,
+    _: *const std::ffi::c_void,
+) {
+    crate::missing("glTexImage3D")
+}
+#[allow(clippy::too_many_arguments)]
+extern "system" fn missing_compressed_tex_image_2d(
+    _: TextureTarget,
+    _: GLint,
+    _: InternalFormat,
+    _: GLsizei,
+    _: GLsizei,
+    _: Border,
+    _: GLsizei,
+    _: *const std::ffi::c_void,
+) {
+    crate::missing("glCompressedTexImage2D")
+}
+extern "system" fn missing_tex_storage_2d(
+    _: TextureTarget,
+    _: GLsizei,
+    _: InternalFormat,
+    _: GLsizei,
+    _: GLsizei,
+) {
+    crate::missing("glTexStorage2D")
+}
+extern "system" fn missing_tex_storage_3d(
+    _: TextureTarget,
+    _: GLsizei,
+    _: InternalFormat,
+    _: GLsizei,
+    _: GLsizei,
+    _: GLsizei,
+) {
+    crate::missing("glTexStorage3D")
+}
+#[allow(clippy::too_many_arguments)]
+extern "system" fn missing_tex_sub_image_2d(
+    _: TextureTarget,
+    _: GLint,
+    _: GLint,
+    _: GLint,
+    _: GLsizei,
+    _: GLsizei,
+    _: TextureFormat,
+    _: TextureDataFormat,
+    _: *const std::ffi::c_void,
+) {
+    crate::missing("glTexSubImage2D")
+}
+#[allow(clippy::too_many_arguments)]
+extern "system" fn missing_tex_sub_image_3d(
+    _: TextureTarget,
+    _: GLint,
+    _: GLint,
+    _: GLint,
+    _: GLint,
+    _: GLsizei,
+    _: GLsizei,
+    _: GLsizei,
+    _: TextureFormat,
+    _: TextureDataFormat,
+    _: *const std::ffi::c_void,
+) {
+    crate::missing("glTexSubImage3D")
+}
+extern "system" fn missing_generate_mipmap(_: TextureTarget) {
+    crate::missing("glGenerateMipmap")
+}
+extern "system" fn missing_pixel_store_i(_: PixelStoreParameter, _: GLint) {
+    crate::missing("glPixelStorei")
+}
+extern "system" fn missing_delete_textures(_: GLsizei, _: *const Texture) {
+    crate::missing("glDeleteTextures")
+}
+extern "system" fn missing_gen_queries(_: GLsizei, _: *mut Query) {
+    crate::missing("glGenQueries")
+}
+extern "system" fn missing_begin_query(_: QueryTarget, _: Query) {
+    crate::missing("glBeginQuery")
+}
+extern "system" fn missing_end_query(_: QueryTarget) {
+    crate::missing("glEndQuery")
+}
+extern "system" fn missing_query_counter(_: Query, _: QueryCounterTarget) {
+    crate::missing("glQueryCounter")
+}
+extern "system" fn missing_get_query_object_iv(_: Query, _: QueryParameter, _: *mut GLint) {
+    crate::missing("glGetQueryObjectiv")
+}
+extern "system" fn missing_get_query_object_uiv(_: Query, _: QueryParameter, _: *mut GLuint) {
+    crate::missing("glGetQueryObjectuiv")
+}
+extern "system" fn missing_get_query_object_i64v(_: Query, _: QueryParameter, _: *mut GLint64) {
+    crate::missing("glGetQueryObjecti64v")
+}
+extern "system" fn missing_get_query_object_ui64v(_: Query, _: QueryParameter, _: *mut GLuint64) {
+    crate::missing("glGetQueryObjectui64v")
+}
+extern "system" fn missing_delete_queries(_: GLsizei, _: *const Query) {
+    crate::missing("glDeleteQueries")
+}
+extern "system" fn missing_gen_samplers(_: GLsizei, _: *mut Sampler) {
+    crate::missing("glGenSamplers")
+}
+extern "system" fn missing_bind_sampler(_: GLuint, _: Sampler) {
+    crate::missing("glBindSampler")
+}
+extern "system" fn missing_sampler_parameter_i(
+    _: Sampler,
+    _: SamplerParameterName,
+    _: SamplerParameterValue,
+) {
+    crate::missing("glSamplerParameteri")
+}
+extern "system" fn missing_sampler_parameter_f(_: Sampler, _: SamplerParameterName, _: GLfloat) {
+    crate::missing("glSamplerParameterf")
+}
+extern "system" fn missing_sampler_parameter_fv(
+    _: Sampler,
+    _: SamplerParameterName,
+    _: *const GLfloat,
+) {
+    crate::missing("glSamplerParameterfv")
+}
+extern "system" fn missing_delete_samplers(_: GLsizei, _: *const Sampler) {
+    crate::missing("glDeleteSamplers")
+}
+extern "system" fn missing_gen_framebuffers(_: GLsizei, _: *mut Framebuffer) {
+    crate::missing("glGenFramebuffers")
+}
+extern "system" fn missing_bind_framebuffer(_: FramebufferTarget, _: Framebuffer) {
+    crate::missing("glBindFramebuffer")
+}
+extern "system" fn missing_framebuffer_texture_2d(
+    _: FramebufferTarget,
+    _: FramebufferAttachment,
+    _: TextureTarget,
+    _: Texture,
+    _: GLint,
+) {
+    crate::missing("glFramebufferTexture2D")
+}
+extern "system" fn missing_framebuffer_renderbuffer(
+    _: FramebufferTarget,
+    _: FramebufferAttachment,
+    _: RenderbufferTarget,
+    _: Renderbuffer,
+) {
+    crate::missing("glFramebufferRenderbuffer")
+}
+extern "system" fn missing_check_framebuffer_status(_: FramebufferTarget) -> FramebufferStatus {
+    crate::missing("glCheckFramebufferStatus")
+}
+extern "system" fn missing_draw_buffers(_: GLsizei, _: *const ColorBuffer) {
+    crate::missing("glDrawBuffers")
+}
+extern "system" fn missing_read_buffer(_: ColorBuffer) {
+    crate::missing("glReadBuffer")
+}
+extern "system" fn missing_read_pixels(
+    _: GLint,
+    _: GLint,
+    _: GLsizei,
+    _: GLsizei,
+    _: TextureFormat,
+    _: TextureDataFormat,
+    _: *mut std::ffi::c_void,
+) {
+    crate::missing("glReadPixels")
+}
+#[allow(clippy::too_many_arguments)]
+extern "system" fn missing_blit_framebuffer(
+    _: GLint,
+    _: GLint,
+    _: GLint,
+    _: GLint,
+    _: GLint,
+    _: GLint,
+    _: GLint,
+    _: GLint,
+    _: ClearMask,
+    _: BlitFilter,
+) {
+    crate::missing("glBlitFramebuffer")
+}
+extern "system" fn missing_delete_framebuffers(_: GLsizei, _: *const Framebuffer) {
+    crate::missing("glDeleteFramebuffers")
+}
+extern "system" fn missing_gen_renderbuffers(_: GLsizei, _: *mut Renderbuffer) {
+    crate::missing("glGenRenderbuffers")
+}
+extern "system" fn missing_bind_renderbuffer(_: RenderbufferTarget, _: Renderbuffer) {
+    crate::missing("glBindRenderbuffer")
+}
+extern "system" fn missing_renderbuffer_storage(
+    _: RenderbufferTarget,
+    _: InternalFormat,
+    _: GLsizei,
+    _: GLsizei,
+) {
+    crate::missing("glRenderbufferStorage")
+}
+extern "system" fn missing_delete_renderbuffers(_: GLsizei, _: *const Renderbuffer) {
+    crate::missing("glDeleteRenderbuffers")
+}
@@ -0,0 +1,86 @@
+use std::ptr::{addr_of, addr_of_mut};
+
+use super::{
+    pod::byte_size, Api, Buffer, BufferTarget, BufferUsage, Framebuffer, GLchar, GLint, GLintptr,
+    Pod, Query, Renderbuffer, Sampler, Shader, Texture, VertexArray,
+};
+
+/// Generates a function that creates a single object with one of the `glGen*` functions.
+macro_rules! gen_single {
+    ($($(#[$meta:meta])* $name:ident => $gen:ident -> $kind:ident;)*) => {
+        $(
+            $(#[$meta])*
+            ///
+            /// # Safety
+            /// The caller has to make sure that the OpenGL context that loaded the functions
+            /// is made current for the calling thread.
+            #[must_use]
+            pub unsafe fn $name(&self) -> $kind {
+                let mut object = $kind(0);
+                unsafe { self.$gen(1, addr_of_mut!(object)) };
+                object
+            }
+        )*
+    };
+}
+
+impl Api {
+    gen_single! {
+        /// Generates one buffer name, see `OwnedBuffer` for one that is deleted when dropped.
+        gen_buffer => gen_buffers -> Buffer;
+        /// Generates one texture name.
+        gen_texture => gen_textures -> Texture;
+        /// Generates one sampler name.
+        gen_sampler => gen_samplers -> Sampler;
+        /// Generates one vertex array name.
+        gen_vertex_array => gen_vertex_arrays -> VertexArray;
+        /// Generates one framebuffer name.
+        gen_framebuffer => gen_framebuffers -> Framebuffer;
+        /// Generates one renderbuffer name.
+        gen_renderbuffer => gen_renderbuffers -> Renderbuffer;
+        /// Generates one query name.
+        gen_query => gen_queries -> Query;
+    }
+
+    /// Allocates the storage of the buffer bound to `target` and fills it with `data`.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the calling thread.
+    pub unsafe fn buffer_data_slice<T: Pod>(
+        &self,
+        target: BufferTarget,
+        data: &[T],
+        usage: BufferUsage,
+    ) {
+        unsafe { self.buffer_data(target, byte_size(data), data.as_ptr().cast(), usage) };
+    }
+
+    /// Overwrites the buffer bound to `target` with `data`, starting `offset` bytes in.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the calling thread.
+    pub unsafe fn buffer_sub_data_slice<T: Pod>(
+        &self,
+        target: BufferTarget,
+        offset: GLintptr,
+        data: &[T],
+    ) {
+        unsafe { self.buffer_sub_data(target, offset, byte_size(data), data.as_ptr().cast()) };
+    }
+
+    /// Replaces the source code of `shader` with `source`, which doesn't need a nul terminator.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the calling thread.
+    ///
+    /// # Panics
+    /// If `source` is longer than `GLint::MAX` bytes.
+    pub unsafe fn shader_source_str(&self, shader: Shader, source: &str) {
+        let length = GLint::try_from(source.len()).expect("shader source should fit a GLint");
+        let string = source.as_ptr().cast::<GLchar>();
+        unsafe { self.shader_source(shader, 1, addr_of!(string), addr_of!(length)) };
+    }
+}
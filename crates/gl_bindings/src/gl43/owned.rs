@@ -0,0 +1,133 @@
+use std::{ptr::addr_of, rc::Rc};
+
+use super::{
+    Api, Buffer, Framebuffer, Program, Query, Renderbuffer, Sampler, Texture, VertexArray,
+};
+
+/// Generates an owning wrapper around a raw object handle.
+/// The wrapper keeps the `Api` alive through an `Rc` and deletes the object when dropped.
+macro_rules! owned_object {
+    (
+        $(#[$meta:meta])*
+        $owned:ident($raw:ident),
+        create: |$create_gl:ident| $create:expr,
+        delete: |$delete_gl:ident, $handle:ident| $delete:expr $(,)?
+    ) => {
+        $(#[$meta])*
+        pub struct $owned {
+            gl: Rc<Api>,
+            handle: $raw,
+        }
+
+        impl $owned {
+            /// Creates a new object.
+            ///
+            /// # Safety
+            /// The caller has to make sure that the OpenGL context that loaded the functions
+            /// is made current for the thread creating and dropping the object.
+            #[must_use]
+            pub unsafe fn new(gl: &Rc<Api>) -> Self {
+                let $create_gl = gl.as_ref();
+                let handle = unsafe { $create };
+
+                Self {
+                    gl: Rc::clone(gl),
+                    handle,
+                }
+            }
+
+            /// Takes ownership of an existing object, deleting it once dropped.
+            ///
+            /// # Safety
+            /// The handle has to be a valid object of the context that loaded `gl`
+            /// and must not be deleted by anyone else.
+            #[must_use]
+            pub const unsafe fn from_raw(gl: Rc<Api>, handle: $raw) -> Self {
+                Self { gl, handle }
+            }
+
+            /// The raw handle, for use with the `Api` functions.
+            /// It is only valid as long as `self` is alive.
+            #[must_use]
+            pub const fn handle(&self) -> $raw {
+                self.handle
+            }
+
+            /// The `Api` this object was created with.
+            #[must_use]
+            pub const fn api(&self) -> &Rc<Api> {
+                &self.gl
+            }
+        }
+
+        impl Drop for $owned {
+            fn drop(&mut self) {
+                let $delete_gl = self.gl.as_ref();
+                let $handle = self.handle;
+                unsafe { $delete };
+            }
+        }
+
+        impl std::fmt::Debug for $owned {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_tuple(stringify!($owned)).field(&self.handle).finish()
+            }
+        }
+    };
+}
+
+owned_object!(
+    /// A buffer object that is deleted when dropped.
+    OwnedBuffer(Buffer),
+    create: |gl| gl.gen_buffer(),
+    delete: |gl, buffer| gl.delete_buffers(1, addr_of!(buffer)),
+);
+
+owned_object!(
+    /// A texture object that is deleted when dropped.
+    OwnedTexture(Texture),
+    create: |gl| gl.gen_texture(),
+    delete: |gl, texture| gl.delete_textures(1, addr_of!(texture)),
+);
+
+owned_object!(
+    /// A sampler object that is deleted when dropped.
+    OwnedSampler(Sampler),
+    create: |gl| gl.gen_sampler(),
+    delete: |gl, sampler| gl.delete_samplers(1, addr_of!(sampler)),
+);
+
+owned_object!(
+    /// A vertex array object that is deleted when dropped.
+    OwnedVertexArray(VertexArray),
+    create: |gl| gl.gen_vertex_array(),
+    delete: |gl, vao| gl.delete_vertex_arrays(1, addr_of!(vao)),
+);
+
+owned_object!(
+    /// A program object that is deleted when dropped.
+    OwnedProgram(Program),
+    create: |gl| gl.create_program(),
+    delete: |gl, program| gl.delete_program(program),
+);
+
+owned_object!(
+    /// A framebuffer object that is deleted when dropped.
+    OwnedFramebuffer(Framebuffer),
+    create: |gl| gl.gen_framebuffer(),
+    delete: |gl, framebuffer| gl.delete_framebuffers(1, addr_of!(framebuffer)),
+);
+
+owned_object!(
+    /// A renderbuffer object that is deleted when dropped.
+    OwnedRenderbuffer(Renderbuffer),
+    create: |gl| gl.gen_renderbuffer(),
+    delete: |gl, renderbuffer| gl.delete_renderbuffers(1, addr_of!(renderbuffer)),
+);
+
+owned_object!(
+    /// A query object that is deleted when dropped.
+    OwnedQuery(Query),
+    create: |gl| gl.gen_query(),
+    delete: |gl, query| gl.delete_queries(1, addr_of!(query)),
+);
@@ -0,0 +1,34 @@
+use super::{DrawElementsIndirectCommand, GLsizeiptr};
+
+/// Plain old data that can be handed to OpenGL as raw bytes.
+///
+/// # Safety
+/// Every byte of the type has to be initialized, so it must not contain any padding, and any
+/// bit pattern OpenGL writes back has to be a valid value. `#[repr(C)]` structs of `Pod`
+/// fields without padding qualify.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! pod {
+    ($($kind:ty),* $(,)?) => {
+        $(unsafe impl Pod for $kind {})*
+    };
+}
+
+pod!(u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+
+unsafe impl Pod for DrawElementsIndirectCommand {}
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+/// The bytes of `data`.
+#[must_use]
+pub const fn as_bytes<T: Pod>(data: &[T]) -> &[u8] {
+    // Safety: `Pod` guarantees that every byte of `T` is initialized
+    unsafe { std::slice::from_raw_parts(data.as_ptr().cast(), std::mem::size_of_val(data)) }
+}
+
+/// The size of `data` in bytes, as taken by the buffer functions.
+pub(super) const fn byte_size<T: Pod>(data: &[T]) -> GLsizeiptr {
+    // slices never exceed `isize::MAX` bytes
+    std::mem::size_of_val(data).cast_signed()
+}
@@ -0,0 +1,166 @@
+use std::rc::Rc;
+
+use super::{
+    Api, Buffer, BufferTarget, BufferUsage, GLint, GLsizei, GLsync, OwnedBuffer, SyncCondition,
+    SyncStatus, SyncWaitFlags, TextureDataFormat, TextureFormat,
+};
+
+/// Bytes of `width` x `height` RGBA8 pixels, rows stay 4 byte aligned for `PACK_ALIGNMENT`.
+fn rgba8_size(width: GLsizei, height: GLsizei) -> usize {
+    let width = usize::try_from(width).expect("width should not be negative");
+    let height = usize::try_from(height).expect("height should not be negative");
+    width * height * 4
+}
+
+impl Api {
+    /// Reads a rectangle of the read framebuffer as RGBA8, rows bottom to top. Waits for all
+    /// rendering to the framebuffer to finish, see `PixelReadback` for a version that doesn't.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the calling thread and that no `PIXEL_PACK_BUFFER` is bound.
+    ///
+    /// # Panics
+    /// If `width` or `height` is negative.
+    #[must_use]
+    pub unsafe fn read_pixels_rgba8(
+        &self,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> Vec<u8> {
+        let mut pixels = vec![0; rgba8_size(width, height)];
+        unsafe {
+            self.read_pixels(
+                x,
+                y,
+                width,
+                height,
+                TextureFormat::RGBA,
+                TextureDataFormat::U8,
+                pixels.as_mut_ptr().cast(),
+            );
+        }
+        pixels
+    }
+}
+
+/// Reads a rectangle of the read framebuffer as RGBA8 into a pixel pack buffer without waiting
+/// for the GPU, the pixels can be fetched once `is_ready` says so a frame or two later.
+pub struct PixelReadback {
+    buffer: OwnedBuffer,
+    fence: GLsync,
+    size: usize,
+    width: GLsizei,
+    height: GLsizei,
+}
+
+impl PixelReadback {
+    /// Starts reading the rectangle, rows bottom to top.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread using and dropping the readback.
+    ///
+    /// # Panics
+    /// If `width` or `height` is negative.
+    #[must_use]
+    pub unsafe fn start(gl: &Rc<Api>, x: GLint, y: GLint, width: GLsizei, height: GLsizei) -> Self {
+        let size = rgba8_size(width, height);
+
+        unsafe {
+            let buffer = OwnedBuffer::new(gl);
+            gl.bind_buffer(BufferTarget::PIXEL_PACK_BUFFER, buffer.handle());
+            gl.buffer_data(
+                BufferTarget::PIXEL_PACK_BUFFER,
+                size.try_into().expect("readback size should fit"),
+                std::ptr::null(),
+                BufferUsage::STREAM_READ,
+            );
+            gl.read_pixels(
+                x,
+                y,
+                width,
+                height,
+                TextureFormat::RGBA,
+                TextureDataFormat::U8,
+                std::ptr::null_mut(),
+            );
+            // a bound pack buffer would turn every other `read_pixels` into a buffer write
+            gl.bind_buffer(BufferTarget::PIXEL_PACK_BUFFER, Buffer(0));
+
+            Self {
+                buffer,
+                fence: gl.fence_sync(SyncCondition::GPU_COMMANDS_COMPLETE, 0),
+                size,
+                width,
+                height,
+            }
+        }
+    }
+
+    /// The `(width, height)` of the rectangle.
+    #[must_use]
+    pub const fn dimensions(&self) -> (GLsizei, GLsizei) {
+        (self.width, self.height)
+    }
+
+    /// Whether the GPU copied the pixels, `finish` doesn't block afterwards.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that started the readback
+    /// is made current for the calling thread.
+    #[must_use]
+    pub unsafe fn is_ready(&self) -> bool {
+        let status = unsafe {
+            self.buffer
+                .api()
+                .client_wait_sync(self.fence, SyncWaitFlags::FLUSH_COMMANDS, 0)
+        };
+        matches!(
+            status,
+            SyncStatus::ALREADY_SIGNALED | SyncStatus::CONDITION_SATISFIED
+        )
+    }
+
+    /// Copies the pixels out of the buffer, waiting for the GPU if they aren't ready yet.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that started the readback
+    /// is made current for the calling thread.
+    #[must_use]
+    pub unsafe fn finish(self) -> Vec<u8> {
+        let gl = self.buffer.api();
+        let mut pixels = vec![0; self.size];
+
+        unsafe {
+            gl.bind_buffer(BufferTarget::PIXEL_PACK_BUFFER, self.buffer.handle());
+            gl.get_buffer_sub_data(
+                BufferTarget::PIXEL_PACK_BUFFER,
+                0,
+                // `start` made sure it fits
+                pixels.len().cast_signed(),
+                pixels.as_mut_ptr().cast(),
+            );
+            gl.bind_buffer(BufferTarget::PIXEL_PACK_BUFFER, Buffer(0));
+        }
+        pixels
+    }
+}
+
+impl Drop for PixelReadback {
+    fn drop(&mut self) {
+        unsafe { self.buffer.api().delete_sync(self.fence) };
+    }
+}
+
+impl std::fmt::Debug for PixelReadback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PixelReadback")
+            .field("buffer", &self.buffer)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,233 @@
+use std::ptr::addr_of_mut;
+
+use super::{
+    Api, GLint, GLsizei, GLuint, Program, ProgramInterface, ProgramInterfaceParameterName,
+    ProgramResourceProperty, ShaderDataKind,
+};
+
+/// An active uniform or vertex input of a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Variable {
+    /// The name as reported by the driver, arrays end with `[0]`.
+    pub name: String,
+    pub kind: ShaderDataKind,
+    /// Number of elements, 1 for variables that aren't arrays.
+    pub array_size: GLint,
+    /// `None` for uniforms inside a block and for built-in inputs like `gl_VertexID`.
+    pub location: Option<GLint>,
+}
+
+impl Variable {
+    /// Whether this is the variable `name`, the `[0]` of arrays may be left out.
+    #[must_use]
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name == name || self.name.strip_suffix("[0]") == Some(name)
+    }
+}
+
+/// An active uniform or shader storage block of a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InterfaceBlock {
+    /// The block name, not the instance name.
+    pub name: String,
+    /// The binding point the block reads its buffer from.
+    pub binding: GLuint,
+    /// Minimum size in bytes of the buffer bound to the block.
+    pub data_size: GLint,
+}
+
+/// The active resources of a linked program, queried once with the program interface queries.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProgramReflection {
+    pub uniforms: Vec<Variable>,
+    pub inputs: Vec<Variable>,
+    pub uniform_blocks: Vec<InterfaceBlock>,
+    pub storage_blocks: Vec<InterfaceBlock>,
+}
+
+impl ProgramReflection {
+    /// Queries the resources of `program`, which has to be linked successfully.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the calling thread and that `program` belongs to it.
+    #[must_use]
+    pub unsafe fn new(gl: &Api, program: Program) -> Self {
+        unsafe {
+            Self {
+                uniforms: variables(gl, program, ProgramInterface::UNIFORM),
+                inputs: variables(gl, program, ProgramInterface::PROGRAM_INPUT),
+                uniform_blocks: blocks(gl, program, ProgramInterface::UNIFORM_BLOCK),
+                storage_blocks: blocks(gl, program, ProgramInterface::SHADER_STORAGE_BLOCK),
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn uniform(&self, name: &str) -> Option<&Variable> {
+        self.uniforms.iter().find(|uniform| uniform.is_named(name))
+    }
+
+    /// The location of the uniform `name`, `None` if it's inside a block or not active.
+    #[must_use]
+    pub fn uniform_location(&self, name: &str) -> Option<GLint> {
+        self.uniform(name).and_then(|uniform| uniform.location)
+    }
+
+    #[must_use]
+    pub fn input(&self, name: &str) -> Option<&Variable> {
+        self.inputs.iter().find(|input| input.is_named(name))
+    }
+
+    #[must_use]
+    pub fn uniform_block(&self, name: &str) -> Option<&InterfaceBlock> {
+        self.uniform_blocks.iter().find(|block| block.name == name)
+    }
+
+    #[must_use]
+    pub fn storage_block(&self, name: &str) -> Option<&InterfaceBlock> {
+        self.storage_blocks.iter().find(|block| block.name == name)
+    }
+}
+
+unsafe fn variables(gl: &Api, program: Program, interface: ProgramInterface) -> Vec<Variable> {
+    (0..unsafe { resource_count(gl, program, interface) })
+        .map(|index| {
+            let [name_length, kind, array_size, location] = unsafe {
+                properties(
+                    gl,
+                    program,
+                    interface,
+                    index,
+                    [
+                        ProgramResourceProperty::NAME_LENGTH,
+                        ProgramResourceProperty::KIND,
+                        ProgramResourceProperty::ARRAY_SIZE,
+                        ProgramResourceProperty::LOCATION,
+                    ],
+                )
+            };
+
+            Variable {
+                name: unsafe { resource_name(gl, program, interface, index, name_length) },
+                kind: ShaderDataKind(kind.try_into().unwrap_or_default()),
+                array_size,
+                location: (location >= 0).then_some(location),
+            }
+        })
+        .collect()
+}
+
+unsafe fn blocks(gl: &Api, program: Program, interface: ProgramInterface) -> Vec<InterfaceBlock> {
+    (0..unsafe { resource_count(gl, program, interface) })
+        .map(|index| {
+            let [name_length, binding, data_size] = unsafe {
+                properties(
+                    gl,
+                    program,
+                    interface,
+                    index,
+                    [
+                        ProgramResourceProperty::NAME_LENGTH,
+                        ProgramResourceProperty::BUFFER_BINDING,
+                        ProgramResourceProperty::BUFFER_DATA_SIZE,
+                    ],
+                )
+            };
+
+            InterfaceBlock {
+                name: unsafe { resource_name(gl, program, interface, index, name_length) },
+                binding: binding.try_into().unwrap_or_default(),
+                data_size,
+            }
+        })
+        .collect()
+}
+
+unsafe fn resource_count(gl: &Api, program: Program, interface: ProgramInterface) -> GLuint {
+    let mut count = 0;
+    unsafe {
+        gl.get_program_interface_iv(
+            program,
+            interface,
+            ProgramInterfaceParameterName::ACTIVE_RESOURCES,
+            addr_of_mut!(count),
+        );
+    }
+    count.try_into().unwrap_or_default()
+}
+
+unsafe fn properties<const N: usize>(
+    gl: &Api,
+    program: Program,
+    interface: ProgramInterface,
+    index: GLuint,
+    props: [ProgramResourceProperty; N],
+) -> [GLint; N] {
+    let count = GLsizei::try_from(N).expect("property count should fit");
+    let mut values = [0; N];
+    unsafe {
+        gl.get_program_resource_iv(
+            program,
+            interface,
+            index,
+            count,
+            props.as_ptr(),
+            count,
+            std::ptr::null_mut(),
+            values.as_mut_ptr(),
+        );
+    }
+    values
+}
+
+/// `name_length` is the `NAME_LENGTH` of the resource, including the null terminator.
+unsafe fn resource_name(
+    gl: &Api,
+    program: Program,
+    interface: ProgramInterface,
+    index: GLuint,
+    name_length: GLint,
+) -> String {
+    let mut name = vec![0u8; usize::try_from(name_length).unwrap_or_default()];
+    let mut length = 0;
+    unsafe {
+        gl.get_program_resource_name(
+            program,
+            interface,
+            index,
+            name_length,
+            addr_of_mut!(length),
+            name.as_mut_ptr().cast(),
+        );
+    }
+    name.truncate(usize::try_from(length).unwrap_or_default());
+    String::from_utf8_lossy(&name).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ShaderDataKind, Variable};
+
+    fn variable(name: &str) -> Variable {
+        Variable {
+            name: name.to_owned(),
+            kind: ShaderDataKind::FLOAT,
+            array_size: 1,
+            location: Some(0),
+        }
+    }
+
+    #[test]
+    fn arrays_are_named_with_and_without_their_first_index() {
+        let array = variable("weights[0]");
+        assert!(array.is_named("weights[0]"));
+        assert!(array.is_named("weights"));
+        assert!(!array.is_named("weights[1]"));
+        assert!(!array.is_named("weight"));
+
+        let plain = variable("weights");
+        assert!(plain.is_named("weights"));
+        assert!(!plain.is_named("weights[0]"));
+    }
+}
@@ -0,0 +1,84 @@
+use super::{
+    Api, CompareFunc, GLfloat, MagFilter, MinFilter, Sampler, SamplerParameterName,
+    SamplerParameterValue, WrapMode,
+};
+
+/// A sampler parameter together with a value of the type it expects.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SamplerParameter {
+    MinFilter(MinFilter),
+    MagFilter(MagFilter),
+    WrapS(WrapMode),
+    WrapT(WrapMode),
+    WrapR(WrapMode),
+    MinLod(GLfloat),
+    MaxLod(GLfloat),
+    LodBias(GLfloat),
+    /// The color outside of the texture for `WrapMode::CLAMP_TO_BORDER`.
+    BorderColor([GLfloat; 4]),
+    /// Compares depth textures against the reference value of the lookup, like shadow
+    /// samplers do, `None` returns the depth itself.
+    Compare(Option<CompareFunc>),
+    /// Needs OpenGL 4.6 or one of the texture filter anisotropic extensions.
+    MaxAnisotropy(GLfloat),
+}
+
+impl Api {
+    /// Sets one parameter of `sampler`.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the calling thread and that `sampler` belongs to it.
+    pub unsafe fn sampler_parameter(&self, sampler: Sampler, parameter: SamplerParameter) {
+        use SamplerParameter as P;
+        use SamplerParameterName as N;
+
+        unsafe {
+            match parameter {
+                P::MinFilter(filter) => self.sampler_parameter_i(
+                    sampler,
+                    N::TEXTURE_MIN_FILTER,
+                    SamplerParameterValue(filter.0),
+                ),
+                P::MagFilter(filter) => self.sampler_parameter_i(
+                    sampler,
+                    N::TEXTURE_MAG_FILTER,
+                    SamplerParameterValue(filter.0),
+                ),
+                P::WrapS(wrap) => self.wrap(sampler, N::TEXTURE_WRAP_S, wrap),
+                P::WrapT(wrap) => self.wrap(sampler, N::TEXTURE_WRAP_T, wrap),
+                P::WrapR(wrap) => self.wrap(sampler, N::TEXTURE_WRAP_R, wrap),
+                P::MinLod(lod) => self.sampler_parameter_f(sampler, N::TEXTURE_MIN_LOD, lod),
+                P::MaxLod(lod) => self.sampler_parameter_f(sampler, N::TEXTURE_MAX_LOD, lod),
+                P::LodBias(bias) => self.sampler_parameter_f(sampler, N::TEXTURE_LOD_BIAS, bias),
+                P::BorderColor(color) => {
+                    self.sampler_parameter_fv(sampler, N::TEXTURE_BORDER_COLOR, color.as_ptr());
+                }
+                P::Compare(None) => self.sampler_parameter_i(
+                    sampler,
+                    N::TEXTURE_COMPARE_MODE,
+                    SamplerParameterValue::NONE,
+                ),
+                P::Compare(Some(func)) => {
+                    self.sampler_parameter_i(
+                        sampler,
+                        N::TEXTURE_COMPARE_MODE,
+                        SamplerParameterValue::COMPARE_REF_TO_TEXTURE,
+                    );
+                    self.sampler_parameter_i(
+                        sampler,
+                        N::TEXTURE_COMPARE_FUNC,
+                        SamplerParameterValue(func.0),
+                    );
+                }
+                P::MaxAnisotropy(anisotropy) => {
+                    self.sampler_parameter_f(sampler, N::TEXTURE_MAX_ANISOTROPY, anisotropy);
+                }
+            }
+        }
+    }
+
+    unsafe fn wrap(&self, sampler: Sampler, pname: SamplerParameterName, wrap: WrapMode) {
+        unsafe { self.sampler_parameter_i(sampler, pname, SamplerParameterValue(wrap.0)) };
+    }
+}
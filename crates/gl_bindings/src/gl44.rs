@@ -0,0 +1,48 @@
+//! Buffer storage of OpenGL 4.4.
+//!
+//! `buffer_storage` allocates immutable storage, which persistent mappings need. It's kept out
+//! of `gl43::Api` so that plain 4.3 contexts load every function there, load this `Api` next to
+//! it with `load_if_supported` and fall back to `buffer_data` without it.
+//!
+//! The newer tokens `WrapMode::MIRROR_CLAMP_TO_EDGE` (4.4) and
+//! `SamplerParameterName::TEXTURE_MAX_ANISOTROPY` (4.6) stay in `gl43`, a token can't go missing
+//! at load time. Passing them to an older context raises `INVALID_ENUM`, so check the version or
+//! extension first.
+use std::ptr::addr_of_mut;
+
+use crate::{
+    gl43::{self, BufferStorageFlags, BufferTarget, ErrorCode, StateParameterName},
+    types::GLsizeiptr,
+    Loader,
+};
+
+include!("gl44/generated.rs");
+
+unsafe impl Send for Api {}
+unsafe impl Sync for Api {}
+
+impl Api {
+    /// Loads the functions if the current context reports at least version 4.4 and provides
+    /// all of them, returns `None` otherwise so the caller can fall back to the 4.3 functions.
+    ///
+    /// # Safety
+    /// The context `gl` was loaded from has to be current, see `with_loader`.
+    pub unsafe fn load_if_supported(
+        gl: &gl43::Api,
+        loader: &impl Fn(&str) -> *const std::ffi::c_void,
+    ) -> Option<Self> {
+        let (mut major, mut minor) = (0, 0);
+
+        unsafe {
+            gl.get_integer_v(StateParameterName::MAJOR_VERSION, addr_of_mut!(major));
+            gl.get_integer_v(StateParameterName::MINOR_VERSION, addr_of_mut!(minor));
+        }
+
+        if (major, minor) < (4, 4) {
+            return None;
+        }
+
+        let api = unsafe { Self::with_loader(loader) };
+        api.missing_functions().is_empty().then_some(api)
+    }
+}
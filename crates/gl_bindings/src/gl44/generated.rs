@@ -0,0 +1,127 @@
+// Generated by `cargo run -p gl_generator` from `registry/gl.xml` and `registry/gl44.txt`.
+// Do not edit by hand, change the allow-list and regenerate instead.
+
+/// Buffer storage functions of OpenGL 4.4, loaded next to `gl43::Api`
+#[allow(clippy::struct_field_names)]
+pub struct Api {
+    // buffers
+    buffer_storage_ptr: unsafe extern "system" fn(
+        target: BufferTarget,
+        size: GLsizeiptr,
+        data: *const std::ffi::c_void,
+        flags: BufferStorageFlags,
+    ),
+    // errors, for the `checked` feature
+    get_error_ptr: unsafe extern "system" fn() -> ErrorCode,
+    missing: Vec<&'static str>,
+}
+
+impl Api {
+    /// Loads all function pointers using a context function loader,
+    /// also called `ProcAddress`. Works with GLFW, SDL2 any pretty much any OpenGL context.
+    ///
+    /// Functions the loader returns a null pointer for are listed by `missing_functions` and
+    /// replaced with stand-ins. Calling one panics inside an `extern "system"` function, which
+    /// aborts the whole process, see `try_with_loader` to fail at load time instead.
+    ///
+    /// # Safety
+    /// Unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers.
+    ///
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader(loader: &impl Fn(&str) -> *const std::ffi::c_void) -> Self {
+        let mut missing = Vec::new();
+        Self {
+            // buffers
+            buffer_storage_ptr: loader.load_or(
+                "glBufferStorage",
+                &mut missing,
+                missing_buffer_storage,
+            ),
+            // errors, for the `checked` feature
+            get_error_ptr: loader.load_or("glGetError", &mut missing, missing_get_error),
+            missing,
+        }
+    }
+
+    /// Loads all function pointers like `with_loader`, but fails with the functions the loader
+    /// returned a null pointer for instead of putting in stand-ins that abort when called.
+    ///
+    /// # Safety
+    /// See `with_loader`.
+    pub unsafe fn try_with_loader(
+        loader: &impl Fn(&str) -> *const std::ffi::c_void,
+    ) -> Result<Self, crate::Error> {
+        let api = unsafe { Self::with_loader(loader) };
+        if api.missing.is_empty() {
+            Ok(api)
+        } else {
+            Err(crate::Error::FailedToLoad(api.missing))
+        }
+    }
+
+    /// The functions the context doesn't provide, calling them aborts the process.
+    #[must_use]
+    pub fn missing_functions(&self) -> &[&'static str] {
+        &self.missing
+    }
+
+    // buffers
+
+    /// Allocates immutable storage, needed for persistent mappings.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn buffer_storage(
+        &self,
+        target: BufferTarget,
+        size: GLsizeiptr,
+        data: *const std::ffi::c_void,
+        flags: BufferStorageFlags,
+    ) {
+        unsafe {
+            (self.buffer_storage_ptr)(target, size, data, flags);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glBufferStorage(target: {target:?}, size: {size:?}, data: {data:?}, flags: {flags:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // errors, for the `checked` feature
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[must_use]
+    pub unsafe fn get_error(&self) -> ErrorCode {
+        unsafe { (self.get_error_ptr)() }
+    }
+}
+
+extern "system" fn missing_buffer_storage(
+    _: BufferTarget,
+    _: GLsizeiptr,
+    _: *const std::ffi::c_void,
+    _: BufferStorageFlags,
+) {
+    crate::missing("glBufferStorage")
+}
+extern "system" fn missing_get_error() -> ErrorCode {
+    crate::missing("glGetError")
+}
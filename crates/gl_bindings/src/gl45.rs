@@ -0,0 +1,94 @@
+//! Direct state access (DSA) functions of OpenGL 4.5.
+//!
+//! They edit objects by name instead of through the current binding, so they don't disturb
+//! whatever else is bound. The handles and enums are the ones of `gl43`, this `Api` only holds
+//! the additional functions and is meant to be loaded next to a `gl43::Api`.
+use std::ptr::addr_of_mut;
+
+use crate::{
+    gl43::{
+        self, AttributeComponents, AttributeIndex, Buffer, BufferBindingIndex, BufferStorageFlags,
+        BufferUsage, ErrorCode, InternalFormat, Pod, Sampler, StateParameterName, Texture,
+        TextureDataFormat, TextureFormat, TextureTarget, VertexArray, VertexAttributeKind,
+    },
+    types::{GLboolean, GLint, GLintptr, GLsizei, GLsizeiptr, GLuint},
+    Loader,
+};
+
+include!("gl45/generated.rs");
+
+unsafe impl Send for Api {}
+unsafe impl Sync for Api {}
+
+impl Api {
+    /// Loads the functions if the current context reports at least version 4.5 and provides
+    /// all of them, returns `None` otherwise so the caller can fall back to the 4.3 functions.
+    ///
+    /// # Safety
+    /// The context `gl` was loaded from has to be current, see `with_loader`.
+    pub unsafe fn load_if_supported(
+        gl: &gl43::Api,
+        loader: &impl Fn(&str) -> *const std::ffi::c_void,
+    ) -> Option<Self> {
+        let (mut major, mut minor) = (0, 0);
+
+        unsafe {
+            gl.get_integer_v(StateParameterName::MAJOR_VERSION, addr_of_mut!(major));
+            gl.get_integer_v(StateParameterName::MINOR_VERSION, addr_of_mut!(minor));
+        }
+
+        if (major, minor) < (4, 5) {
+            return None;
+        }
+
+        let api = unsafe { Self::with_loader(loader) };
+        api.missing_functions().is_empty().then_some(api)
+    }
+}
+
+/// Creates a single object with one of the `glCreate*` functions.
+macro_rules! create_single {
+    ($($(#[$meta:meta])* $name:ident => $create:ident($($arg:ident: $arg_kind:ty),*) -> $kind:ident;)*) => {
+        $(
+            $(#[$meta])*
+            ///
+            /// # Safety
+            /// The caller has to make sure that the OpenGL context that loaded the functions
+            /// is made current for the calling thread.
+            #[must_use]
+            pub unsafe fn $name(&self, $($arg: $arg_kind),*) -> $kind {
+                let mut object = $kind::from_raw(0);
+                unsafe { self.$create($($arg,)* 1, addr_of_mut!(object)) };
+                object
+            }
+        )*
+    };
+}
+
+impl Api {
+    create_single! {
+        /// Creates one buffer object, see `gl43::OwnedBuffer::from_raw` to have it deleted.
+        create_buffer => create_buffers() -> Buffer;
+        /// Creates one texture object of `target`.
+        create_texture => create_textures(target: TextureTarget) -> Texture;
+        /// Creates one sampler object.
+        create_sampler => create_samplers() -> Sampler;
+        /// Creates one vertex array object.
+        create_vertex_array => create_vertex_arrays() -> VertexArray;
+    }
+
+    /// Allocates immutable storage for `buffer`, filled with `data`.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the calling thread.
+    pub unsafe fn named_buffer_storage_slice<T: Pod>(
+        &self,
+        buffer: Buffer,
+        data: &[T],
+        flags: BufferStorageFlags,
+    ) {
+        let size = std::mem::size_of_val(data).cast_signed();
+        unsafe { self.named_buffer_storage(buffer, size, data.as_ptr().cast(), flags) };
+    }
+}
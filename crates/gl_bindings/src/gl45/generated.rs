@@ -0,0 +1,927 @@
+// Generated by `cargo run -p gl_generator` from `registry/gl.xml` and `registry/gl45.txt`.
+// Do not edit by hand, change the allow-list and regenerate instead.
+
+/// Direct state access functions of OpenGL 4.5, loaded next to `gl43::Api`
+#[allow(clippy::struct_field_names)]
+pub struct Api {
+    // buffers
+    create_buffers_ptr: unsafe extern "system" fn(n: GLsizei, buffers: *mut Buffer),
+    named_buffer_storage_ptr: unsafe extern "system" fn(
+        buffer: Buffer,
+        size: GLsizeiptr,
+        data: *const std::ffi::c_void,
+        flags: BufferStorageFlags,
+    ),
+    named_buffer_data_ptr: unsafe extern "system" fn(
+        buffer: Buffer,
+        size: GLsizeiptr,
+        data: *const std::ffi::c_void,
+        usage: BufferUsage,
+    ),
+    named_buffer_sub_data_ptr: unsafe extern "system" fn(
+        buffer: Buffer,
+        offset: GLintptr,
+        size: GLsizeiptr,
+        data: *const std::ffi::c_void,
+    ),
+    // textures
+    create_textures_ptr:
+        unsafe extern "system" fn(target: TextureTarget, n: GLsizei, textures: *mut Texture),
+    texture_storage_2d_ptr: unsafe extern "system" fn(
+        texture: Texture,
+        levels: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ),
+    texture_storage_3d_ptr: unsafe extern "system" fn(
+        texture: Texture,
+        levels: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+    ),
+    texture_sub_image_2d_ptr: unsafe extern "system" fn(
+        texture: Texture,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: TextureFormat,
+        kind: TextureDataFormat,
+        pixels: *const std::ffi::c_void,
+    ),
+    texture_sub_image_3d_ptr: unsafe extern "system" fn(
+        texture: Texture,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        format: TextureFormat,
+        kind: TextureDataFormat,
+        pixels: *const std::ffi::c_void,
+    ),
+    generate_texture_mipmap_ptr: unsafe extern "system" fn(texture: Texture),
+    bind_texture_unit_ptr: unsafe extern "system" fn(unit: GLuint, texture: Texture),
+    // samplers
+    create_samplers_ptr: unsafe extern "system" fn(n: GLsizei, samplers: *mut Sampler),
+    // vertex arrays
+    create_vertex_arrays_ptr: unsafe extern "system" fn(n: GLsizei, arrays: *mut VertexArray),
+    enable_vertex_array_attrib_ptr:
+        unsafe extern "system" fn(vaobj: VertexArray, index: AttributeIndex),
+    vertex_array_attrib_format_ptr: unsafe extern "system" fn(
+        vaobj: VertexArray,
+        attribindex: AttributeIndex,
+        size: AttributeComponents,
+        kind: VertexAttributeKind,
+        normalized: GLboolean,
+        relativeoffset: GLuint,
+    ),
+    vertex_array_attrib_i_format_ptr: unsafe extern "system" fn(
+        vaobj: VertexArray,
+        attribindex: AttributeIndex,
+        size: AttributeComponents,
+        kind: VertexAttributeKind,
+        relativeoffset: GLuint,
+    ),
+    vertex_array_attrib_binding_ptr: unsafe extern "system" fn(
+        vaobj: VertexArray,
+        attribindex: AttributeIndex,
+        bindingindex: BufferBindingIndex,
+    ),
+    vertex_array_vertex_buffer_ptr: unsafe extern "system" fn(
+        vaobj: VertexArray,
+        bindingindex: BufferBindingIndex,
+        buffer: Buffer,
+        offset: GLintptr,
+        stride: GLsizei,
+    ),
+    vertex_array_element_buffer_ptr: unsafe extern "system" fn(vaobj: VertexArray, buffer: Buffer),
+    // errors, for the `checked` feature
+    get_error_ptr: unsafe extern "system" fn() -> ErrorCode,
+    missing: Vec<&'static str>,
+}
+
+impl Api {
+    /// Loads all function pointers using a context function loader,
+    /// also called `ProcAddress`. Works with GLFW, SDL2 any pretty much any OpenGL context.
+    ///
+    /// Functions the loader returns a null pointer for are listed by `missing_functions` and
+    /// replaced with stand-ins. Calling one panics inside an `extern "system"` function, which
+    /// aborts the whole process, see `try_with_loader` to fail at load time instead.
+    ///
+    /// # Safety
+    /// Unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers.
+    ///
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader(loader: &impl Fn(&str) -> *const std::ffi::c_void) -> Self {
+        let mut missing = Vec::new();
+        Self {
+            // buffers
+            create_buffers_ptr: loader.load_or(
+                "glCreateBuffers",
+                &mut missing,
+                missing_create_buffers,
+            ),
+            named_buffer_storage_ptr: loader.load_or(
+                "glNamedBufferStorage",
+                &mut missing,
+                missing_named_buffer_storage,
+            ),
+            named_buffer_data_ptr: loader.load_or(
+                "glNamedBufferData",
+                &mut missing,
+                missing_named_buffer_data,
+            ),
+            named_buffer_sub_data_ptr: loader.load_or(
+                "glNamedBufferSubData",
+                &mut missing,
+                missing_named_buffer_sub_data,
+            ),
+            // textures
+            create_textures_ptr: loader.load_or(
+                "glCreateTextures",
+                &mut missing,
+                missing_create_textures,
+            ),
+            texture_storage_2d_ptr: loader.load_or(
+                "glTextureStorage2D",
+                &mut missing,
+                missing_texture_storage_2d,
+            ),
+            texture_storage_3d_ptr: loader.load_or(
+                "glTextureStorage3D",
+                &mut missing,
+                missing_texture_storage_3d,
+            ),
+            texture_sub_image_2d_ptr: loader.load_or(
+                "glTextureSubImage2D",
+                &mut missing,
+                missing_texture_sub_image_2d,
+            ),
+            texture_sub_image_3d_ptr: loader.load_or(
+                "glTextureSubImage3D",
+                &mut missing,
+                missing_texture_sub_image_3d,
+            ),
+            generate_texture_mipmap_ptr: loader.load_or(
+                "glGenerateTextureMipmap",
+                &mut missing,
+                missing_generate_texture_mipmap,
+            ),
+            bind_texture_unit_ptr: loader.load_or(
+                "glBindTextureUnit",
+                &mut missing,
+                missing_bind_texture_unit,
+            ),
+            // samplers
+            create_samplers_ptr: loader.load_or(
+                "glCreateSamplers",
+                &mut missing,
+                missing_create_samplers,
+            ),
+            // vertex arrays
+            create_vertex_arrays_ptr: loader.load_or(
+                "glCreateVertexArrays",
+                &mut missing,
+                missing_create_vertex_arrays,
+            ),
+            enable_vertex_array_attrib_ptr: loader.load_or(
+                "glEnableVertexArrayAttrib",
+                &mut missing,
+                missing_enable_vertex_array_attrib,
+            ),
+            vertex_array_attrib_format_ptr: loader.load_or(
+                "glVertexArrayAttribFormat",
+                &mut missing,
+                missing_vertex_array_attrib_format,
+            ),
+            vertex_array_attrib_i_format_ptr: loader.load_or(
+                "glVertexArrayAttribIFormat",
+                &mut missing,
+                missing_vertex_array_attrib_i_format,
+            ),
+            vertex_array_attrib_binding_ptr: loader.load_or(
+                "glVertexArrayAttribBinding",
+                &mut missing,
+                missing_vertex_array_attrib_binding,
+            ),
+            vertex_array_vertex_buffer_ptr: loader.load_or(
+                "glVertexArrayVertexBuffer",
+                &mut missing,
+                missing_vertex_array_vertex_buffer,
+            ),
+            vertex_array_element_buffer_ptr: loader.load_or(
+                "glVertexArrayElementBuffer",
+                &mut missing,
+                missing_vertex_array_element_buffer,
+            ),
+            // errors, for the `checked` feature
+            get_error_ptr: loader.load_or("glGetError", &mut missing, missing_get_error),
+            missing,
+        }
+    }
+
+    /// Loads all function pointers like `with_loader`, but fails with the functions the loader
+    /// returned a null pointer for instead of putting in stand-ins that abort when called.
+    ///
+    /// # Safety
+    /// See `with_loader`.
+    pub unsafe fn try_with_loader(
+        loader: &impl Fn(&str) -> *const std::ffi::c_void,
+    ) -> Result<Self, crate::Error> {
+        let api = unsafe { Self::with_loader(loader) };
+        if api.missing.is_empty() {
+            Ok(api)
+        } else {
+            Err(crate::Error::FailedToLoad(api.missing))
+        }
+    }
+
+    /// The functions the context doesn't provide, calling them aborts the process.
+    #[must_use]
+    pub fn missing_functions(&self) -> &[&'static str] {
+        &self.missing
+    }
+
+    // buffers
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn create_buffers(&self, n: GLsizei, buffers: *mut Buffer) {
+        unsafe {
+            (self.create_buffers_ptr)(n, buffers);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glCreateBuffers(n: {n:?}, buffers: {buffers:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Allocates immutable storage for the buffer, optionally filled with `data`.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn named_buffer_storage(
+        &self,
+        buffer: Buffer,
+        size: GLsizeiptr,
+        data: *const std::ffi::c_void,
+        flags: BufferStorageFlags,
+    ) {
+        unsafe {
+            (self.named_buffer_storage_ptr)(buffer, size, data, flags);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glNamedBufferStorage(buffer: {buffer:?}, size: {size:?}, data: {data:?}, flags: {flags:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn named_buffer_data(
+        &self,
+        buffer: Buffer,
+        size: GLsizeiptr,
+        data: *const std::ffi::c_void,
+        usage: BufferUsage,
+    ) {
+        unsafe {
+            (self.named_buffer_data_ptr)(buffer, size, data, usage);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glNamedBufferData(buffer: {buffer:?}, size: {size:?}, data: {data:?}, usage: {usage:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn named_buffer_sub_data(
+        &self,
+        buffer: Buffer,
+        offset: GLintptr,
+        size: GLsizeiptr,
+        data: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            (self.named_buffer_sub_data_ptr)(buffer, offset, size, data);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glNamedBufferSubData(buffer: {buffer:?}, offset: {offset:?}, size: {size:?}, data: {data:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // textures
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn create_textures(
+        &self,
+        target: TextureTarget,
+        n: GLsizei,
+        textures: *mut Texture,
+    ) {
+        unsafe {
+            (self.create_textures_ptr)(target, n, textures);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glCreateTextures(target: {target:?}, n: {n:?}, textures: {textures:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Allocates immutable storage for all `levels` of the texture.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn texture_storage_2d(
+        &self,
+        texture: Texture,
+        levels: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ) {
+        unsafe {
+            (self.texture_storage_2d_ptr)(texture, levels, internalformat, width, height);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glTextureStorage2D(texture: {texture:?}, levels: {levels:?}, internalformat: {internalformat:?}, width: {width:?}, height: {height:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Allocates immutable storage for all `levels` of the texture, `depth` is the number of
+    /// layers for array textures.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn texture_storage_3d(
+        &self,
+        texture: Texture,
+        levels: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+    ) {
+        unsafe {
+            (self.texture_storage_3d_ptr)(texture, levels, internalformat, width, height, depth);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glTextureStorage3D(texture: {texture:?}, levels: {levels:?}, internalformat: {internalformat:?}, width: {width:?}, height: {height:?}, depth: {depth:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::similar_names)]
+    pub unsafe fn texture_sub_image_2d(
+        &self,
+        texture: Texture,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: TextureFormat,
+        kind: TextureDataFormat,
+        pixels: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            (self.texture_sub_image_2d_ptr)(
+                texture, level, xoffset, yoffset, width, height, format, kind, pixels,
+            );
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glTextureSubImage2D(texture: {texture:?}, level: {level:?}, xoffset: {xoffset:?}, yoffset: {yoffset:?}, width: {width:?}, height: {height:?}, format: {format:?}, kind: {kind:?}, pixels: {pixels:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::similar_names)]
+    pub unsafe fn texture_sub_image_3d(
+        &self,
+        texture: Texture,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        format: TextureFormat,
+        kind: TextureDataFormat,
+        pixels: *const std::ffi::c_void,
+    ) {
+        unsafe {
+            (self.texture_sub_image_3d_ptr)(
+                texture, level, xoffset, yoffset, zoffset, width, height, depth, format, kind,
+                pixels,
+            );
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glTextureSubImage3D(texture: {texture:?}, level: {level:?}, xoffset: {xoffset:?}, yoffset: {yoffset:?}, zoffset: {zoffset:?}, width: {width:?}, height: {height:?}, depth: {depth:?}, format: {format:?}, kind: {kind:?}, pixels: {pixels:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn generate_texture_mipmap(&self, texture: Texture) {
+        unsafe {
+            (self.generate_texture_mipmap_ptr)(texture);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glGenerateTextureMipmap(texture: {texture:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// Binds the texture to texture unit `unit`, unlike `active_texture` counting from zero.
+    ///
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn bind_texture_unit(&self, unit: GLuint, texture: Texture) {
+        unsafe {
+            (self.bind_texture_unit_ptr)(unit, texture);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glBindTextureUnit(unit: {unit:?}, texture: {texture:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // samplers
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn create_samplers(&self, n: GLsizei, samplers: *mut Sampler) {
+        unsafe {
+            (self.create_samplers_ptr)(n, samplers);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glCreateSamplers(n: {n:?}, samplers: {samplers:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // vertex arrays
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn create_vertex_arrays(&self, n: GLsizei, arrays: *mut VertexArray) {
+        unsafe {
+            (self.create_vertex_arrays_ptr)(n, arrays);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glCreateVertexArrays(n: {n:?}, arrays: {arrays:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn enable_vertex_array_attrib(&self, vaobj: VertexArray, index: AttributeIndex) {
+        unsafe {
+            (self.enable_vertex_array_attrib_ptr)(vaobj, index);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glEnableVertexArrayAttrib(vaobj: {vaobj:?}, index: {index:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn vertex_array_attrib_format(
+        &self,
+        vaobj: VertexArray,
+        attribindex: AttributeIndex,
+        size: AttributeComponents,
+        kind: VertexAttributeKind,
+        normalized: GLboolean,
+        relativeoffset: GLuint,
+    ) {
+        unsafe {
+            (self.vertex_array_attrib_format_ptr)(
+                vaobj,
+                attribindex,
+                size,
+                kind,
+                normalized,
+                relativeoffset,
+            );
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glVertexArrayAttribFormat(vaobj: {vaobj:?}, attribindex: {attribindex:?}, size: {size:?}, kind: {kind:?}, normalized: {normalized:?}, relativeoffset: {relativeoffset:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn vertex_array_attrib_i_format(
+        &self,
+        vaobj: VertexArray,
+        attribindex: AttributeIndex,
+        size: AttributeComponents,
+        kind: VertexAttributeKind,
+        relativeoffset: GLuint,
+    ) {
+        unsafe {
+            (self.vertex_array_attrib_i_format_ptr)(vaobj, attribindex, size, kind, relativeoffset);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glVertexArrayAttribIFormat(vaobj: {vaobj:?}, attribindex: {attribindex:?}, size: {size:?}, kind: {kind:?}, relativeoffset: {relativeoffset:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn vertex_array_attrib_binding(
+        &self,
+        vaobj: VertexArray,
+        attribindex: AttributeIndex,
+        bindingindex: BufferBindingIndex,
+    ) {
+        unsafe {
+            (self.vertex_array_attrib_binding_ptr)(vaobj, attribindex, bindingindex);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glVertexArrayAttribBinding(vaobj: {vaobj:?}, attribindex: {attribindex:?}, bindingindex: {bindingindex:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn vertex_array_vertex_buffer(
+        &self,
+        vaobj: VertexArray,
+        bindingindex: BufferBindingIndex,
+        buffer: Buffer,
+        offset: GLintptr,
+        stride: GLsizei,
+    ) {
+        unsafe {
+            (self.vertex_array_vertex_buffer_ptr)(vaobj, bindingindex, buffer, offset, stride);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || {
+                format!("glVertexArrayVertexBuffer(vaobj: {vaobj:?}, bindingindex: {bindingindex:?}, buffer: {buffer:?}, offset: {offset:?}, stride: {stride:?})")
+            },
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub unsafe fn vertex_array_element_buffer(&self, vaobj: VertexArray, buffer: Buffer) {
+        unsafe {
+            (self.vertex_array_element_buffer_ptr)(vaobj, buffer);
+        }
+        #[cfg(feature = "checked")]
+        crate::check_error(
+            || format!("glVertexArrayElementBuffer(vaobj: {vaobj:?}, buffer: {buffer:?})"),
+            || unsafe { (self.get_error_ptr)() },
+        );
+    }
+
+    // errors, for the `checked` feature
+
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.
+    #[inline]
+    #[must_use]
+    pub unsafe fn get_error(&self) -> ErrorCode {
+        unsafe { (self.get_error_ptr)() }
+    }
+}
+
+extern "system" fn missing_create_buffers(_: GLsizei, _: *mut Buffer) {
+    crate::missing("glCreateBuffers")
+}
+extern "system" fn missing_named_buffer_storage(
+    _: Buffer,
+    _: GLsizeiptr,
+    _: *const std::ffi::c_void,
+    _: BufferStorageFlags,
+) {
+    crate::missing("glNamedBufferStorage")
+}
+extern "system" fn missing_named_buffer_data(
+    _: Buffer,
+    _: GLsizeiptr,
+    _: *const std::ffi::c_void,
+    _: BufferUsage,
+) {
+    crate::missing("glNamedBufferData")
+}
+extern "system" fn missing_named_buffer_sub_data(
+    _: Buffer,
+    _: GLintptr,
+    _: GLsizeiptr,
+    _: *const std::ffi::c_void,
+) {
+    crate::missing("glNamedBufferSubData")
+}
+extern "system" fn missing_create_textures(_: TextureTarget, _: GLsizei, _: *mut Texture) {
+    crate::missing("glCreateTextures")
+}
+extern "system" fn missing_texture_storage_2d(
+    _: Texture,
+    _: GLsizei,
+    _: InternalFormat,
+    _: GLsizei,
+    _: GLsizei,
+) {
+    crate::missing("glTextureStorage2D")
+}
+extern "system" fn missing_texture_storage_3d(
+    _: Texture,
+    _: GLsizei,
+    _: InternalFormat,
+    _: GLsizei,
+    _: GLsizei,
+    _: GLsizei,
+) {
+    crate::missing("glTextureStorage3D")
+}
+#[allow(clippy::too_many_arguments)]
+extern "system" fn missing_texture_sub_image_2d(
+    _: Texture,
+    _: GLint,
+    _: GLint,
+    _: GLint,
+    _: GLsizei,
+    _: GLsizei,
+    _: TextureFormat,
+    _: TextureDataFormat,
+    _: *const std::ffi::c_void,
+) {
+    crate::missing("glTextureSubImage2D")
+}
+#[allow(clippy::too_many_arguments)]
+extern "system" fn missing_texture_sub_image_3d(
+    _: Texture,
+    _: GLint,
+    _: GLint,
+    _: GLint,
+    _: GLint,
+    _: GLsizei,
+    _: GLsizei,
+    _: GLsizei,
+    _: TextureFormat,
+    _: TextureDataFormat,
+    _: *const std::ffi::c_void,
+) {
+    crate::missing("glTextureSubImage3D")
+}
+extern "system" fn missing_generate_texture_mipmap(_: Texture) {
+    crate::missing("glGenerateTextureMipmap")
+}
+extern "system" fn missing_bind_texture_unit(_: GLuint, _: Texture) {
+    crate::missing("glBindTextureUnit")
+}
+extern "system" fn missing_create_samplers(_: GLsizei, _: *mut Sampler) {
+    crate::missing("glCreateSamplers")
+}
+extern "system" fn missing_create_vertex_arrays(_: GLsizei, _: *mut VertexArray) {
+    crate::missing("glCreateVertexArrays")
+}
+extern "system" fn missing_enable_vertex_array_attrib(_: VertexArray, _: AttributeIndex) {
+    crate::missing("glEnableVertexArrayAttrib")
+}
+extern "system" fn missing_vertex_array_attrib_format(
+    _: VertexArray,
+    _: AttributeIndex,
+    _: AttributeComponents,
+    _: VertexAttributeKind,
+    _: GLboolean,
+    _: GLuint,
+) {
+    crate::missing("glVertexArrayAttribFormat")
+}
+extern "system" fn missing_vertex_array_attrib_i_format(
+    _: VertexArray,
+    _: AttributeIndex,
+    _: AttributeComponents,
+    _: VertexAttributeKind,
+    _: GLuint,
+) {
+    crate::missing("glVertexArrayAttribIFormat")
+}
+extern "system" fn missing_vertex_array_attrib_binding(
+    _: VertexArray,
+    _: AttributeIndex,
+    _: BufferBindingIndex,
+) {
+    crate::missing("glVertexArrayAttribBinding")
+}
+extern "system" fn missing_vertex_array_vertex_buffer(
+    _: VertexArray,
+    _: BufferBindingIndex,
+    _: Buffer,
+    _: GLintptr,
+    _: GLsizei,
+) {
+    crate::missing("glVertexArrayVertexBuffer")
+}
+extern "system" fn missing_vertex_array_element_buffer(_: VertexArray, _: Buffer) {
+    crate::missing("glVertexArrayElementBuffer")
+}
+extern "system" fn missing_get_error() -> ErrorCode {
+    crate::missing("glGetError")
+}
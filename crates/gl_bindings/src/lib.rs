@@ -1,5 +1,3 @@
-use std::fmt::Display;
-
 //shared/common GL types
 pub mod types {
     pub type GLbyte = std::ffi::c_char;
@@ -10,10 +8,15 @@ pub mod types {
     pub type GLfloat = std::ffi::c_float;
     pub type GLhalf = std::ffi::c_ushort;
     pub type GLint = std::ffi::c_int;
+    pub type GLint64 = i64;
     pub type GLintptr = isize;
     pub type GLsizei = std::ffi::c_int;
     pub type GLsizeiptr = isize;
     pub type GLuint = std::ffi::c_uint;
+    pub type GLubyte = std::ffi::c_uchar;
+    pub type GLuint64 = u64;
+    /// Opaque handle of a fence created with `fence_sync`.
+    pub type GLsync = *const std::ffi::c_void;
 
     #[repr(transparent)]
     #[derive(Copy, Clone, Debug)]
@@ -33,40 +36,86 @@ pub mod types {
             }
         }
     }
+
+    impl From<GLboolean> for bool {
+        fn from(value: GLboolean) -> Self {
+            value.0 != 0
+        }
+    }
 }
 
+#[cfg(feature = "egl")]
+pub mod egl;
 pub mod gl43;
+pub mod gl44;
+pub mod gl45;
+#[cfg(feature = "mock")]
+pub mod mock;
 
-#[derive(Debug)]
+/// Why `try_with_loader` of one of the APIs failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Error {
-    FailedToLoad(String),
+    /// The loader returned a null pointer for these functions.
+    FailedToLoad(Vec<&'static str>),
+}
+
+impl std::error::Error for Error {}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FailedToLoad(symbols) => {
+                write!(f, "function pointers for {} not found", symbols.join(", "))
+            }
+        }
+    }
 }
 
 trait Loader {
-    fn load<T>(&self, symbol: &str) -> Result<T, Error>;
+    /// Loads `symbol`, or records it in `missing` and returns `fallback` if it's null.
+    fn load_or<T>(&self, symbol: &'static str, missing: &mut Vec<&'static str>, fallback: T) -> T;
 }
 
 impl<F> Loader for F
 where
     F: Fn(&str) -> *const std::ffi::c_void,
 {
-    fn load<T>(&self, symbol: &str) -> Result<T, Error> {
+    fn load_or<T>(&self, symbol: &'static str, missing: &mut Vec<&'static str>, fallback: T) -> T {
         let ptr = (self)(symbol);
 
         if ptr.is_null() {
-            Err(Error::FailedToLoad(symbol.to_string()))
+            missing.push(symbol);
+            fallback
         } else {
-            Ok(unsafe { std::mem::transmute_copy(&ptr) })
+            unsafe { std::mem::transmute_copy(&ptr) }
         }
     }
 }
 
-impl std::error::Error for Error {}
+/// Panics with the call described by `call` if `get_error` reports any errors,
+/// every wrapper runs this after its function with the `checked` feature.
+#[cfg(feature = "checked")]
+#[track_caller]
+fn check_error(call: impl FnOnce() -> String, get_error: impl Fn() -> gl43::ErrorCode) {
+    // there can be several error flags, each call returns and clears one of them
+    let errors: Vec<_> = std::iter::from_fn(|| {
+        let error = get_error();
+        (error != gl43::ErrorCode::NO_ERROR).then(|| error.to_string())
+    })
+    .take(8)
+    .collect();
 
-impl Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::FailedToLoad(s) => write!(f, "function pointer for {s} not found"),
-        }
-    }
+    assert!(
+        errors.is_empty(),
+        "{} failed with {}",
+        call(),
+        errors.join(", ")
+    );
+}
+
+/// Body of the stand-ins the loaders put in place of functions the context doesn't provide.
+/// They are `extern "system"`, so the panic aborts the process after printing the message.
+#[cold]
+fn missing(symbol: &str) -> ! {
+    panic!("{symbol} is not supported by the current OpenGL context")
 }
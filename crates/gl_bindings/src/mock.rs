@@ -0,0 +1,835 @@
+//! A fake OpenGL implementation for tests on machines without a GPU.
+//!
+//! [`loader`] can be passed to `with_loader` of any of the generated APIs. Every call is
+//! recorded with its arguments in a per-thread log, `glGen*` and `glCreate*` hand out fresh
+//! object names and integer queries (`glGet*iv`, `glGet*64v`) answer with values set by
+//! [`set_integer`].
+//! Debug groups are tracked by name, see [`debug_groups`]. `glMapBufferRange` hands out zeroed
+//! memory of the requested length that stays valid until [`reset`]. `glGetError` reports the
+//! errors queued with [`push_error`], one per call. `glDebugMessageInsert` hands the message to
+//! the callback set with `glDebugMessageCallback` or, without one, adds it to the message log
+//! that `glGetDebugMessageLog` reads. Nothing is ever drawn.
+//!
+//! ```
+//! # use gl_bindings::{gl43, mock};
+//! let gl = unsafe { gl43::Api::with_loader(&mock::loader) };
+//! unsafe { gl.enable(gl43::Capability::DEPTH) };
+//!
+//! assert_eq!(mock::calls()[0], mock::Call::new("glEnable", [mock::Arg::Int(0x0B71)]));
+//! ```
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    ffi::c_void,
+};
+
+use crate::types::{
+    GLboolean, GLchar, GLenum, GLint, GLint64, GLintptr, GLsizei, GLuint, GLuint64,
+};
+
+mod gl43;
+mod gl44;
+mod gl45;
+
+/// One recorded call.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Call {
+    pub name: &'static str,
+    pub args: Vec<Arg>,
+}
+
+impl Call {
+    pub fn new(name: &'static str, args: impl Into<Vec<Arg>>) -> Self {
+        Self {
+            name,
+            args: args.into(),
+        }
+    }
+}
+
+/// An argument of a recorded call. Enums, handles and booleans are recorded as integers.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Arg {
+    Int(i64),
+    Float(f64),
+    Pointer(*const c_void),
+}
+
+impl From<GLint> for Arg {
+    fn from(value: GLint) -> Self {
+        Self::Int(value.into())
+    }
+}
+
+impl From<GLuint> for Arg {
+    fn from(value: GLuint) -> Self {
+        Self::Int(value.into())
+    }
+}
+
+impl From<GLuint64> for Arg {
+    fn from(value: GLuint64) -> Self {
+        // `TIMEOUT_IGNORED` wraps to -1
+        Self::Int(value.cast_signed())
+    }
+}
+
+impl From<GLintptr> for Arg {
+    fn from(value: GLintptr) -> Self {
+        Self::Int(value as i64)
+    }
+}
+
+impl From<GLboolean> for Arg {
+    fn from(value: GLboolean) -> Self {
+        Self::Int(bool::from(value).into())
+    }
+}
+
+impl From<f32> for Arg {
+    fn from(value: f32) -> Self {
+        Self::Float(value.into())
+    }
+}
+
+impl<T> From<*const T> for Arg {
+    fn from(value: *const T) -> Self {
+        Self::Pointer(value.cast())
+    }
+}
+
+impl<T> From<*mut T> for Arg {
+    fn from(value: *mut T) -> Self {
+        Self::Pointer(value.cast_const().cast())
+    }
+}
+
+/// A debug group opened with `glPushDebugGroup`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DebugGroup {
+    pub name: String,
+    /// Number of groups that were open around this one.
+    pub depth: usize,
+}
+
+/// Turns the object name a call produced into its return type. Everything that isn't a
+/// `glCreate*` call returns zero, `GL_FALSE` or null, except for `glCheckFramebufferStatus`
+/// which always reports complete framebuffers.
+trait Return {
+    fn from_name(name: GLuint) -> Self;
+}
+
+impl Return for GLuint {
+    fn from_name(name: GLuint) -> Self {
+        name
+    }
+}
+
+impl Return for GLint {
+    fn from_name(name: GLuint) -> Self {
+        name.try_into().unwrap_or_default()
+    }
+}
+
+impl Return for GLboolean {
+    fn from_name(name: GLuint) -> Self {
+        (name != 0).into()
+    }
+}
+
+impl<T> Return for *const T {
+    fn from_name(_: GLuint) -> Self {
+        std::ptr::null()
+    }
+}
+
+impl<T> Return for *mut T {
+    /// The memory of the last `glMapBufferRange`, pointers don't fit into an object name.
+    fn from_name(_: GLuint) -> Self {
+        STATE
+            .with_borrow_mut(|state| std::mem::replace(&mut state.mapped, std::ptr::null_mut()))
+            .cast()
+    }
+}
+
+const MAJOR_VERSION: GLenum = 0x821B;
+const MINOR_VERSION: GLenum = 0x821C;
+const COMPILE_STATUS: GLenum = 0x8B81;
+const LINK_STATUS: GLenum = 0x8B82;
+const FRAMEBUFFER_COMPLETE: GLenum = 0x8CD5;
+const MAX_DEBUG_MESSAGE_LENGTH: GLenum = 0x9143;
+const DEBUG_LOGGED_MESSAGES: GLenum = 0x9145;
+
+type DebugCallback = extern "system" fn(
+    source: GLenum,
+    kind: GLenum,
+    id: GLuint,
+    severity: GLenum,
+    length: GLsizei,
+    message: *const GLchar,
+    user_param: *mut c_void,
+);
+
+/// A message waiting in the debug message log.
+struct LoggedMessage {
+    source: GLenum,
+    kind: GLenum,
+    id: GLuint,
+    severity: GLenum,
+    text: String,
+}
+
+struct State {
+    calls: Vec<Call>,
+    last_name: GLuint,
+    integers: HashMap<GLenum, GLint>,
+    /// The errors `glGetError` reports next.
+    errors: VecDeque<GLenum>,
+    debug_groups: Vec<DebugGroup>,
+    open_debug_groups: usize,
+    /// The callback and user parameter set with `glDebugMessageCallback`.
+    debug_callback: Option<(DebugCallback, *mut c_void)>,
+    debug_log: VecDeque<LoggedMessage>,
+    mappings: Vec<Box<[u8]>>,
+    /// The mapping `glMapBufferRange` returns.
+    mapped: *mut u8,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            calls: Vec::new(),
+            last_name: 0,
+            integers: HashMap::new(),
+            errors: VecDeque::new(),
+            debug_groups: Vec::new(),
+            open_debug_groups: 0,
+            debug_callback: None,
+            debug_log: VecDeque::new(),
+            mappings: Vec::new(),
+            mapped: std::ptr::null_mut(),
+        }
+    }
+}
+
+impl State {
+    const fn next_name(&mut self) -> GLuint {
+        self.last_name += 1;
+        self.last_name
+    }
+
+    fn integer(&self, pname: GLenum) -> GLint {
+        self.integers
+            .get(&pname)
+            .copied()
+            .unwrap_or_else(|| match pname {
+                // a 4.3 context where shaders compile and programs link unless a test says otherwise
+                MAJOR_VERSION => 4,
+                MINOR_VERSION => 3,
+                COMPILE_STATUS | LINK_STATUS => 1,
+                MAX_DEBUG_MESSAGE_LENGTH => 1024,
+                DEBUG_LOGGED_MESSAGES => self.debug_log.len().try_into().unwrap_or(GLint::MAX),
+                _ => 0,
+            })
+    }
+
+    /// Moves the oldest logged messages that fit into `capacity` bytes of text into the output
+    /// arrays of `glGetDebugMessageLog`, returns how many it moved.
+    ///
+    /// # Safety
+    /// The arrays have to be valid for `count` writes and the text for `capacity`.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn take_debug_log(
+        &mut self,
+        count: usize,
+        capacity: usize,
+        sources: *mut GLenum,
+        kinds: *mut GLenum,
+        ids: *mut GLuint,
+        severities: *mut GLenum,
+        lengths: *mut GLsizei,
+        text: *mut u8,
+    ) -> GLuint {
+        let mut offset = 0;
+        let mut fetched = 0;
+        while fetched < count {
+            // the lengths include the nul terminator
+            let Some(length) = self.debug_log.front().map(|message| message.text.len() + 1) else {
+                break;
+            };
+            if offset + length > capacity {
+                break;
+            }
+            let Some(message) = self.debug_log.pop_front() else {
+                break;
+            };
+
+            unsafe {
+                sources.add(fetched).write(message.source);
+                kinds.add(fetched).write(message.kind);
+                ids.add(fetched).write(message.id);
+                severities.add(fetched).write(message.severity);
+                lengths
+                    .add(fetched)
+                    .write(length.try_into().unwrap_or(GLsizei::MAX));
+                let out = text.add(offset);
+                out.copy_from_nonoverlapping(message.text.as_ptr(), length - 1);
+                out.add(length - 1).write(0);
+            }
+            offset += length;
+            fetched += 1;
+        }
+        fetched.try_into().unwrap_or(GLuint::MAX)
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<State> = RefCell::default();
+}
+
+/// Function loader handing out the fake functions, returns null for unknown symbols.
+///
+/// The `gl44` and `gl45` functions are available as well, but their `load_if_supported` only
+/// loads them after the version was raised with [`set_integer`].
+#[must_use]
+pub fn loader(symbol: &str) -> *const c_void {
+    [gl43::lookup, gl44::lookup, gl45::lookup]
+        .into_iter()
+        .map(|lookup| lookup(symbol))
+        .find(|function| !function.is_null())
+        .unwrap_or(std::ptr::null())
+}
+
+/// All calls recorded on this thread so far.
+#[must_use]
+pub fn calls() -> Vec<Call> {
+    STATE.with_borrow(|state| state.calls.clone())
+}
+
+/// Returns the calls recorded on this thread and clears the log.
+#[must_use]
+pub fn take_calls() -> Vec<Call> {
+    STATE.with_borrow_mut(|state| std::mem::take(&mut state.calls))
+}
+
+/// Sets the value integer queries like `glGetProgramiv` or `glGetQueryObjectui64v` answer for
+/// `pname`.
+pub fn set_integer(pname: GLenum, value: GLint) {
+    STATE.with_borrow_mut(|state| {
+        state.integers.insert(pname, value);
+    });
+}
+
+/// Queues an error code like `GL_INVALID_ENUM` (`0x0500`) for `glGetError` to report, after the
+/// errors queued before it.
+pub fn push_error(code: GLenum) {
+    STATE.with_borrow_mut(|state| state.errors.push_back(code));
+}
+
+/// All debug groups opened on this thread so far, in the order they were pushed.
+#[must_use]
+pub fn debug_groups() -> Vec<DebugGroup> {
+    STATE.with_borrow(|state| state.debug_groups.clone())
+}
+
+/// Clears the log, the debug groups, the debug callback and message log, the object names, the
+/// buffer mappings, the queued errors and all values set with [`set_integer`].
+pub fn reset() {
+    STATE.with_borrow_mut(|state| *state = State::default());
+}
+
+/// Records a call and fakes its side effects.
+///
+/// # Safety
+/// Output pointers of `glGen*`, `glGet*iv`, `glGet*64v` and `glGetDebugMessageLog` have to be
+/// valid for writes and the messages of `glPushDebugGroup` and `glDebugMessageInsert` valid for
+/// reads, just as with a real driver.
+unsafe fn call(name: &'static str, args: &[Arg]) -> GLuint {
+    let result = STATE.with_borrow_mut(|state| {
+        state.calls.push(Call::new(name, args));
+
+        match args {
+            // glCreateTextures takes the target first
+            [.., Arg::Int(n), Arg::Pointer(names)]
+                if name.starts_with("glGen") || name.starts_with("glCreate") =>
+            {
+                let names = names.cast_mut().cast::<GLuint>();
+                for i in 0..usize::try_from(*n).unwrap_or_default() {
+                    unsafe { names.add(i).write(state.next_name()) };
+                }
+                0
+            }
+            _ if name.starts_with("glCreate") => state.next_name(),
+            _ if name == "glCheckFramebufferStatus" => FRAMEBUFFER_COMPLETE,
+            _ if name == "glGetError" => state.errors.pop_front().unwrap_or_default(),
+            [.., Arg::Int(length), Arg::Pointer(message)] if name == "glPushDebugGroup" => {
+                let group = DebugGroup {
+                    name: unsafe { read_string(message.cast(), *length) },
+                    depth: state.open_debug_groups,
+                };
+                state.debug_groups.push(group);
+                state.open_debug_groups += 1;
+                0
+            }
+            [_, _, Arg::Int(length), _] if name == "glMapBufferRange" => {
+                let length = usize::try_from(*length).unwrap_or_default();
+                let mut mapping = vec![0; length].into_boxed_slice();
+                state.mapped = mapping.as_mut_ptr();
+                state.mappings.push(mapping);
+                0
+            }
+            _ if name == "glPopDebugGroup" => {
+                state.open_debug_groups = state.open_debug_groups.saturating_sub(1);
+                0
+            }
+            [Arg::Pointer(callback), Arg::Pointer(user_param)]
+                if name == "glDebugMessageCallback" =>
+            {
+                state.debug_callback = (!callback.is_null()).then(|| {
+                    // Safety: non-null callbacks come from an `Option<DebugMessageCallback>`
+                    let callback = unsafe { std::mem::transmute::<*const c_void, DebugCallback>(*callback) };
+                    (callback, user_param.cast_mut())
+                });
+                0
+            }
+            [Arg::Int(count), Arg::Int(capacity), Arg::Pointer(sources), Arg::Pointer(kinds), Arg::Pointer(ids), Arg::Pointer(severities), Arg::Pointer(lengths), Arg::Pointer(text)]
+                if name == "glGetDebugMessageLog" =>
+            unsafe {
+                state.take_debug_log(
+                    usize::try_from(*count).unwrap_or_default(),
+                    usize::try_from(*capacity).unwrap_or_default(),
+                    sources.cast_mut().cast(),
+                    kinds.cast_mut().cast(),
+                    ids.cast_mut().cast(),
+                    severities.cast_mut().cast(),
+                    lengths.cast_mut().cast(),
+                    text.cast_mut().cast(),
+                )
+            },
+            [.., Arg::Int(pname), Arg::Pointer(params)]
+                if name.starts_with("glGet")
+                    && (name.ends_with("iv") || name.ends_with("Integerv")) =>
+            {
+                let value = state.integer(GLenum::try_from(*pname).unwrap_or_default());
+                unsafe { params.cast_mut().cast::<GLint>().write(value) };
+                0
+            }
+            // the unsigned variants get the same bits
+            [.., Arg::Int(pname), Arg::Pointer(params)]
+                if name.starts_with("glGet") && name.ends_with("64v") =>
+            {
+                let value = state.integer(GLenum::try_from(*pname).unwrap_or_default());
+                unsafe { params.cast_mut().cast::<GLint64>().write(value.into()) };
+                0
+            }
+            _ => 0,
+        }
+    });
+
+    if name == "glDebugMessageInsert" {
+        unsafe { insert_debug_message(args) };
+    }
+    result
+}
+
+/// Hands an inserted message to the debug callback or logs it. The callback runs without the
+/// state borrowed, it may well make calls of its own.
+unsafe fn insert_debug_message(args: &[Arg]) {
+    let &[Arg::Int(source), Arg::Int(kind), Arg::Int(id), Arg::Int(severity), Arg::Int(length), Arg::Pointer(message)] =
+        args
+    else {
+        return;
+    };
+    let (source, kind, id, severity) = (
+        GLenum::try_from(source).unwrap_or_default(),
+        GLenum::try_from(kind).unwrap_or_default(),
+        GLuint::try_from(id).unwrap_or_default(),
+        GLenum::try_from(severity).unwrap_or_default(),
+    );
+
+    if let Some((callback, user_param)) = STATE.with_borrow(|state| state.debug_callback) {
+        callback(
+            source,
+            kind,
+            id,
+            severity,
+            length.try_into().unwrap_or(-1),
+            message.cast(),
+            user_param,
+        );
+    } else {
+        let text = unsafe { read_string(message.cast(), length) };
+        STATE.with_borrow_mut(|state| {
+            state.debug_log.push_back(LoggedMessage {
+                source,
+                kind,
+                id,
+                severity,
+                text,
+            });
+        });
+    }
+}
+
+/// Copies a string passed with its length, negative lengths mean it's null terminated.
+unsafe fn read_string(text: *const std::ffi::c_char, length: i64) -> String {
+    usize::try_from(length).map_or_else(
+        |_| {
+            unsafe { std::ffi::CStr::from_ptr(text) }
+                .to_string_lossy()
+                .into_owned()
+        },
+        |length| {
+            let bytes = unsafe { std::slice::from_raw_parts(text.cast::<u8>(), length) };
+            String::from_utf8_lossy(bytes).into_owned()
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ptr::addr_of_mut, rc::Rc};
+
+    use super::{
+        calls, debug_groups, loader, push_error, reset, set_integer, take_calls, Arg, Call,
+        DebugGroup, MAJOR_VERSION, MINOR_VERSION,
+    };
+    use crate::{gl43, gl45};
+
+    fn api() -> gl43::Api {
+        reset();
+        unsafe { gl43::Api::with_loader(&loader) }
+    }
+
+    /// The calls without the `glGetError`s the `checked` feature adds.
+    fn recorded(calls: Vec<Call>) -> Vec<Call> {
+        calls
+            .into_iter()
+            .filter(|call| call.name != "glGetError")
+            .collect()
+    }
+
+    #[test]
+    fn records_wrapper_calls() {
+        let gl = api();
+        unsafe {
+            gl.enable(gl43::Capability::DEPTH);
+            gl.viewport(0, 0, 640, 480);
+        }
+
+        let expected = [
+            Call::new("glEnable", [Arg::Int(0x0B71)]),
+            Call::new(
+                "glViewport",
+                [Arg::Int(0), Arg::Int(0), Arg::Int(640), Arg::Int(480)],
+            ),
+        ];
+        assert_eq!(recorded(calls()), expected);
+        assert_eq!(recorded(take_calls()), expected);
+        assert!(calls().is_empty());
+    }
+
+    #[test]
+    fn try_with_loader_reports_missing_functions() {
+        reset();
+        assert!(unsafe { gl43::Api::try_with_loader(&loader) }.is_ok());
+
+        let only_enable = |symbol: &str| {
+            if symbol == "glEnable" {
+                loader(symbol)
+            } else {
+                std::ptr::null()
+            }
+        };
+        let Err(crate::Error::FailedToLoad(missing)) =
+            (unsafe { gl43::Api::try_with_loader(&only_enable) })
+        else {
+            panic!("loading without functions should fail");
+        };
+        assert!(missing.contains(&"glDisable"));
+        assert!(!missing.contains(&"glEnable"));
+    }
+
+    #[test]
+    fn hands_out_fresh_names() {
+        let gl = api();
+        set_integer(MAJOR_VERSION, 4);
+        set_integer(MINOR_VERSION, 5);
+        let dsa = unsafe { gl45::Api::load_if_supported(&gl, &loader) }
+            .expect("the mock should provide 4.5 once the version says so");
+
+        let mut names = [gl43::Buffer::from_raw(0); 5];
+        unsafe {
+            names[0] = gl.gen_buffer();
+            gl.gen_buffers(2, names[1..3].as_mut_ptr());
+            names[3] = dsa.create_buffer();
+            names[4] = dsa.create_buffer();
+        }
+
+        for (i, name) in names.iter().enumerate() {
+            assert_ne!(*name, gl43::Buffer::from_raw(0));
+            assert!(
+                !names[i + 1..].contains(name),
+                "{name:?} was handed out twice"
+            );
+        }
+    }
+
+    #[test]
+    fn integer_queries_answer_set_values() {
+        let gl = api();
+        let query = |pname| {
+            let mut value = 0;
+            unsafe { gl.get_integer_v(pname, addr_of_mut!(value)) };
+            value
+        };
+
+        assert_eq!(query(gl43::StateParameterName::MAJOR_VERSION), 4);
+        assert_eq!(
+            query(gl43::StateParameterName::UNIFORM_BUFFER_OFFSET_ALIGNMENT),
+            0
+        );
+
+        set_integer(0x8A34, 256);
+        assert_eq!(
+            query(gl43::StateParameterName::UNIFORM_BUFFER_OFFSET_ALIGNMENT),
+            256
+        );
+    }
+
+    #[test]
+    fn debug_groups_nest() {
+        let gl = api();
+        {
+            let _frame = unsafe { gl43::DebugScope::new(&gl, "frame") };
+            drop(unsafe { gl43::DebugScope::new(&gl, "meshes") });
+            let _text = unsafe { gl43::DebugScope::new(&gl, "text") };
+        }
+        drop(unsafe { gl43::DebugScope::new(&gl, "present") });
+
+        let group = |name: &str, depth| DebugGroup {
+            name: name.to_owned(),
+            depth,
+        };
+        assert_eq!(
+            debug_groups(),
+            [
+                group("frame", 0),
+                group("meshes", 1),
+                group("text", 1),
+                group("present", 0)
+            ]
+        );
+    }
+
+    fn message(kind: gl43::DebugType, id: u32, text: &str) -> gl43::DebugMessage {
+        gl43::DebugMessage {
+            source: gl43::DebugSource::APPLICATION,
+            kind,
+            id,
+            severity: gl43::DebugSeverity::NOTIFICATION,
+            text: text.to_owned(),
+        }
+    }
+
+    #[test]
+    fn the_message_log_is_split_into_messages() {
+        let gl = api();
+        unsafe {
+            gl.debug_marker("first");
+            gl.debug_marker("");
+            gl.debug_marker("the third one");
+        }
+
+        let marker = |text| message(gl43::DebugType::MARKER, 0, text);
+        assert_eq!(
+            unsafe { gl.debug_message_log() },
+            [marker("first"), marker(""), marker("the third one")]
+        );
+        assert!(unsafe { gl.debug_message_log() }.is_empty());
+    }
+
+    #[test]
+    fn the_collector_takes_messages_with_and_without_a_length() {
+        let gl = Rc::new(api());
+        let collector = unsafe { gl43::DebugCollector::install(&gl) };
+        unsafe {
+            gl.debug_message_insert(
+                gl43::DebugSource::APPLICATION,
+                gl43::DebugType::OTHER,
+                1,
+                gl43::DebugSeverity::NOTIFICATION,
+                -1,
+                c"nul terminated".as_ptr(),
+            );
+            gl.debug_message_insert(
+                gl43::DebugSource::APPLICATION,
+                gl43::DebugType::OTHER,
+                2,
+                gl43::DebugSeverity::NOTIFICATION,
+                5,
+                c"counted, not this".as_ptr(),
+            );
+        }
+
+        assert_eq!(
+            collector.take(),
+            [
+                message(gl43::DebugType::OTHER, 1, "nul terminated"),
+                message(gl43::DebugType::OTHER, 2, "count"),
+            ]
+        );
+        assert!(collector.take().is_empty());
+        assert!(unsafe { gl.debug_message_log() }.is_empty());
+    }
+
+    #[test]
+    fn muting_disables_the_ids_at_any_severity() {
+        let gl = api();
+        let ids = [131_185, 131_218];
+        unsafe { gl.mute_debug_messages(gl43::DebugSource::API, gl43::DebugType::OTHER, &ids) };
+
+        assert_eq!(
+            recorded(calls()),
+            [Call::new(
+                "glDebugMessageControl",
+                [
+                    Arg::Int(0x8246),
+                    Arg::Int(0x8251),
+                    // DONT_CARE
+                    Arg::Int(0x1100),
+                    Arg::Int(2),
+                    Arg::Pointer(ids.as_ptr().cast()),
+                    // FALSE
+                    Arg::Int(0),
+                ]
+            )]
+        );
+    }
+
+    #[test]
+    fn readbacks_read_into_a_pack_buffer_and_fence_it() {
+        let gl = Rc::new(api());
+        let readback = unsafe { gl43::PixelReadback::start(&gl, 8, 4, 16, 2) };
+        assert_eq!(readback.dimensions(), (16, 2));
+
+        let calls: Vec<_> = recorded(calls())
+            .into_iter()
+            .skip_while(|call| call.name != "glBindBuffer")
+            .collect();
+        // PIXEL_PACK_BUFFER, the first name the mock hands out
+        let bind = |buffer| Call::new("glBindBuffer", [Arg::Int(0x88EB), Arg::Int(buffer)]);
+        assert_eq!(
+            calls,
+            [
+                bind(1),
+                Call::new(
+                    "glBufferData",
+                    [
+                        Arg::Int(0x88EB),
+                        Arg::Int(16 * 2 * 4),
+                        Arg::Pointer(std::ptr::null()),
+                        // STREAM_READ
+                        Arg::Int(0x88E1),
+                    ]
+                ),
+                // RGBA, UNSIGNED_BYTE, at offset zero of the pack buffer
+                Call::new(
+                    "glReadPixels",
+                    [
+                        Arg::Int(8),
+                        Arg::Int(4),
+                        Arg::Int(16),
+                        Arg::Int(2),
+                        Arg::Int(0x1908),
+                        Arg::Int(0x1401),
+                        Arg::Pointer(std::ptr::null()),
+                    ]
+                ),
+                bind(0),
+                // SYNC_GPU_COMMANDS_COMPLETE
+                Call::new("glFenceSync", [Arg::Int(0x9117), Arg::Int(0)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn get_error_reports_the_queued_errors_in_order() {
+        let gl = api();
+        push_error(0x0500);
+        push_error(0x0502);
+
+        let errors: Vec<_> = (0..3).map(|_| unsafe { gl.get_error() }).collect();
+        assert_eq!(
+            errors,
+            [
+                gl43::ErrorCode::INVALID_ENUM,
+                gl43::ErrorCode::INVALID_OPERATION,
+                gl43::ErrorCode::NO_ERROR
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "checked")]
+    #[should_panic(
+        expected = "glEnable(cap: Capability(2929)) failed with INVALID_ENUM, INVALID_VALUE"
+    )]
+    fn checked_calls_panic_with_the_call_and_all_errors() {
+        let gl = api();
+        push_error(0x0500);
+        push_error(0x0501);
+
+        unsafe { gl.enable(gl43::Capability::DEPTH) };
+    }
+
+    #[test]
+    #[cfg(feature = "checked")]
+    fn checked_calls_pass_without_errors() {
+        let gl = api();
+        unsafe { gl.enable(gl43::Capability::DEPTH) };
+
+        let names: Vec<_> = calls().iter().map(|call| call.name).collect();
+        assert_eq!(names, ["glEnable", "glGetError"]);
+    }
+
+    #[test]
+    fn program_reflection_queries_every_interface() {
+        let gl = api();
+        // ACTIVE_RESOURCES
+        set_integer(0x92F5, 1);
+
+        let reflection = unsafe {
+            let program = gl.create_program();
+            gl43::ProgramReflection::new(&gl, program)
+        };
+        assert_eq!(reflection.uniforms.len(), 1);
+        assert_eq!(reflection.inputs.len(), 1);
+        assert_eq!(reflection.uniform_blocks.len(), 1);
+        assert_eq!(reflection.storage_blocks.len(), 1);
+
+        let interfaces: Vec<_> = calls()
+            .into_iter()
+            .filter(|call| call.name == "glGetProgramInterfaceiv")
+            .map(|call| call.args[1])
+            .collect();
+        // UNIFORM, PROGRAM_INPUT, UNIFORM_BLOCK, SHADER_STORAGE_BLOCK
+        assert_eq!(
+            interfaces,
+            [
+                Arg::Int(0x92E1),
+                Arg::Int(0x92E3),
+                Arg::Int(0x92E2),
+                Arg::Int(0x92E6)
+            ]
+        );
+        // one property query and one name query per resource
+        assert_eq!(
+            calls()
+                .iter()
+                .filter(|call| call.name == "glGetProgramResourceName")
+                .count(),
+            4
+        );
+    }
+}
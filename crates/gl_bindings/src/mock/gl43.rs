@@ -0,0 +1,2242 @@
+// Generated by `cargo run -p gl_generator` from `registry/gl.xml` and `registry/gl43.txt`.
+// Do not edit by hand, change the allow-list and regenerate instead.
+
+use super::{call, Arg, Return};
+use crate::types::{
+    GLbitField, GLboolean, GLchar, GLenum, GLfloat, GLint, GLint64, GLintptr, GLsizei, GLsizeiptr,
+    GLsync, GLubyte, GLuint, GLuint64,
+};
+
+#[allow(clippy::similar_names)]
+unsafe extern "system" fn dispatch_compute(
+    num_groups_x: GLuint,
+    num_groups_y: GLuint,
+    num_groups_z: GLuint,
+) {
+    unsafe {
+        call(
+            "glDispatchCompute",
+            &[
+                Arg::from(num_groups_x),
+                Arg::from(num_groups_y),
+                Arg::from(num_groups_z),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn dispatch_compute_indirect(indirect: GLintptr) {
+    unsafe {
+        call("glDispatchComputeIndirect", &[Arg::from(indirect)]);
+    }
+}
+
+unsafe extern "system" fn memory_barrier(barriers: GLbitField) {
+    unsafe {
+        call("glMemoryBarrier", &[Arg::from(barriers)]);
+    }
+}
+
+unsafe extern "system" fn bind_image_texture(
+    unit: GLuint,
+    texture: GLuint,
+    level: GLint,
+    layered: GLboolean,
+    layer: GLint,
+    access: GLenum,
+    format: GLenum,
+) {
+    unsafe {
+        call(
+            "glBindImageTexture",
+            &[
+                Arg::from(unit),
+                Arg::from(texture),
+                Arg::from(level),
+                Arg::from(layered),
+                Arg::from(layer),
+                Arg::from(access),
+                Arg::from(format),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn debug_message_callback(
+    callback: *const std::ffi::c_void,
+    user_param: *const std::ffi::c_void,
+) {
+    unsafe {
+        call(
+            "glDebugMessageCallback",
+            &[Arg::from(callback), Arg::from(user_param)],
+        );
+    }
+}
+
+unsafe extern "system" fn debug_message_control(
+    source: GLenum,
+    kind: GLenum,
+    severity: GLenum,
+    count: GLsizei,
+    ids: *const GLuint,
+    enabled: GLboolean,
+) {
+    unsafe {
+        call(
+            "glDebugMessageControl",
+            &[
+                Arg::from(source),
+                Arg::from(kind),
+                Arg::from(severity),
+                Arg::from(count),
+                Arg::from(ids),
+                Arg::from(enabled),
+            ],
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "system" fn get_debug_message_log(
+    count: GLuint,
+    buf_size: GLsizei,
+    sources: *mut GLenum,
+    types: *mut GLenum,
+    ids: *mut GLuint,
+    severities: *mut GLenum,
+    lengths: *mut GLsizei,
+    message_log: *mut GLchar,
+) -> GLuint {
+    unsafe {
+        Return::from_name(call(
+            "glGetDebugMessageLog",
+            &[
+                Arg::from(count),
+                Arg::from(buf_size),
+                Arg::from(sources),
+                Arg::from(types),
+                Arg::from(ids),
+                Arg::from(severities),
+                Arg::from(lengths),
+                Arg::from(message_log),
+            ],
+        ))
+    }
+}
+
+unsafe extern "system" fn debug_message_insert(
+    source: GLenum,
+    kind: GLenum,
+    id: GLuint,
+    severity: GLenum,
+    length: GLsizei,
+    buf: *const GLchar,
+) {
+    unsafe {
+        call(
+            "glDebugMessageInsert",
+            &[
+                Arg::from(source),
+                Arg::from(kind),
+                Arg::from(id),
+                Arg::from(severity),
+                Arg::from(length),
+                Arg::from(buf),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn push_debug_group(
+    source: GLenum,
+    id: GLuint,
+    length: GLsizei,
+    message: *const GLchar,
+) {
+    unsafe {
+        call(
+            "glPushDebugGroup",
+            &[
+                Arg::from(source),
+                Arg::from(id),
+                Arg::from(length),
+                Arg::from(message),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn pop_debug_group() {
+    unsafe {
+        call("glPopDebugGroup", &[]);
+    }
+}
+
+unsafe extern "system" fn object_label(
+    identifier: GLenum,
+    name: GLuint,
+    length: GLsizei,
+    label: *const GLchar,
+) {
+    unsafe {
+        call(
+            "glObjectLabel",
+            &[
+                Arg::from(identifier),
+                Arg::from(name),
+                Arg::from(length),
+                Arg::from(label),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn vertex_attrib_format(
+    attribindex: GLuint,
+    size: GLint,
+    kind: GLenum,
+    normalized: GLboolean,
+    relativeoffset: GLuint,
+) {
+    unsafe {
+        call(
+            "glVertexAttribFormat",
+            &[
+                Arg::from(attribindex),
+                Arg::from(size),
+                Arg::from(kind),
+                Arg::from(normalized),
+                Arg::from(relativeoffset),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn vertex_attrib_i_format(
+    attribindex: GLuint,
+    size: GLint,
+    kind: GLenum,
+    relativeoffset: GLuint,
+) {
+    unsafe {
+        call(
+            "glVertexAttribIFormat",
+            &[
+                Arg::from(attribindex),
+                Arg::from(size),
+                Arg::from(kind),
+                Arg::from(relativeoffset),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn bind_vertex_buffer(
+    bindingindex: GLuint,
+    buffer: GLuint,
+    offset: GLintptr,
+    stride: GLsizei,
+) {
+    unsafe {
+        call(
+            "glBindVertexBuffer",
+            &[
+                Arg::from(bindingindex),
+                Arg::from(buffer),
+                Arg::from(offset),
+                Arg::from(stride),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn vertex_binding_divisor(bindingindex: GLuint, divisor: GLuint) {
+    unsafe {
+        call(
+            "glVertexBindingDivisor",
+            &[Arg::from(bindingindex), Arg::from(divisor)],
+        );
+    }
+}
+
+unsafe extern "system" fn vertex_attrib_binding(attribindex: GLuint, bindingindex: GLuint) {
+    unsafe {
+        call(
+            "glVertexAttribBinding",
+            &[Arg::from(attribindex), Arg::from(bindingindex)],
+        );
+    }
+}
+
+unsafe extern "system" fn get_program_interface_iv(
+    program: GLuint,
+    program_interface: GLenum,
+    pname: GLenum,
+    params: *mut GLint,
+) {
+    unsafe {
+        call(
+            "glGetProgramInterfaceiv",
+            &[
+                Arg::from(program),
+                Arg::from(program_interface),
+                Arg::from(pname),
+                Arg::from(params),
+            ],
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "system" fn get_program_resource_iv(
+    program: GLuint,
+    program_interface: GLenum,
+    index: GLuint,
+    prop_count: GLsizei,
+    props: *const GLenum,
+    buf_size: GLsizei,
+    length: *mut GLsizei,
+    params: *mut GLint,
+) {
+    unsafe {
+        call(
+            "glGetProgramResourceiv",
+            &[
+                Arg::from(program),
+                Arg::from(program_interface),
+                Arg::from(index),
+                Arg::from(prop_count),
+                Arg::from(props),
+                Arg::from(buf_size),
+                Arg::from(length),
+                Arg::from(params),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn get_program_resource_name(
+    program: GLuint,
+    program_interface: GLenum,
+    index: GLuint,
+    buf_size: GLsizei,
+    length: *mut GLsizei,
+    name: *mut GLchar,
+) {
+    unsafe {
+        call(
+            "glGetProgramResourceName",
+            &[
+                Arg::from(program),
+                Arg::from(program_interface),
+                Arg::from(index),
+                Arg::from(buf_size),
+                Arg::from(length),
+                Arg::from(name),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn get_program_resource_index(
+    program: GLuint,
+    program_interface: GLenum,
+    name: *const GLchar,
+) -> GLuint {
+    unsafe {
+        Return::from_name(call(
+            "glGetProgramResourceIndex",
+            &[
+                Arg::from(program),
+                Arg::from(program_interface),
+                Arg::from(name),
+            ],
+        ))
+    }
+}
+
+unsafe extern "system" fn shader_storage_block_binding(
+    program: GLuint,
+    storage_block_index: GLuint,
+    storage_block_binding: GLuint,
+) {
+    unsafe {
+        call(
+            "glShaderStorageBlockBinding",
+            &[
+                Arg::from(program),
+                Arg::from(storage_block_index),
+                Arg::from(storage_block_binding),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn enable(cap: GLenum) {
+    unsafe {
+        call("glEnable", &[Arg::from(cap)]);
+    }
+}
+
+unsafe extern "system" fn disable(cap: GLenum) {
+    unsafe {
+        call("glDisable", &[Arg::from(cap)]);
+    }
+}
+
+unsafe extern "system" fn depth_func(func: GLenum) {
+    unsafe {
+        call("glDepthFunc", &[Arg::from(func)]);
+    }
+}
+
+unsafe extern "system" fn depth_mask(flag: GLboolean) {
+    unsafe {
+        call("glDepthMask", &[Arg::from(flag)]);
+    }
+}
+
+unsafe extern "system" fn color_mask(
+    red: GLboolean,
+    green: GLboolean,
+    blue: GLboolean,
+    alpha: GLboolean,
+) {
+    unsafe {
+        call(
+            "glColorMask",
+            &[
+                Arg::from(red),
+                Arg::from(green),
+                Arg::from(blue),
+                Arg::from(alpha),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn cull_face(mode: GLenum) {
+    unsafe {
+        call("glCullFace", &[Arg::from(mode)]);
+    }
+}
+
+unsafe extern "system" fn front_face(mode: GLenum) {
+    unsafe {
+        call("glFrontFace", &[Arg::from(mode)]);
+    }
+}
+
+unsafe extern "system" fn polygon_mode(face: GLenum, mode: GLenum) {
+    unsafe {
+        call("glPolygonMode", &[Arg::from(face), Arg::from(mode)]);
+    }
+}
+
+unsafe extern "system" fn polygon_offset(factor: GLfloat, units: GLfloat) {
+    unsafe {
+        call("glPolygonOffset", &[Arg::from(factor), Arg::from(units)]);
+    }
+}
+
+#[allow(clippy::similar_names)]
+unsafe extern "system" fn scissor(x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+    unsafe {
+        call(
+            "glScissor",
+            &[
+                Arg::from(x),
+                Arg::from(y),
+                Arg::from(width),
+                Arg::from(height),
+            ],
+        );
+    }
+}
+
+#[allow(clippy::similar_names)]
+unsafe extern "system" fn blend_func(sfactor: GLenum, dfactor: GLenum) {
+    unsafe {
+        call("glBlendFunc", &[Arg::from(sfactor), Arg::from(dfactor)]);
+    }
+}
+
+#[allow(clippy::similar_names)]
+unsafe extern "system" fn blend_func_separate(
+    sfactor_rgb: GLenum,
+    dfactor_rgb: GLenum,
+    sfactor_alpha: GLenum,
+    dfactor_alpha: GLenum,
+) {
+    unsafe {
+        call(
+            "glBlendFuncSeparate",
+            &[
+                Arg::from(sfactor_rgb),
+                Arg::from(dfactor_rgb),
+                Arg::from(sfactor_alpha),
+                Arg::from(dfactor_alpha),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn blend_equation(mode: GLenum) {
+    unsafe {
+        call("glBlendEquation", &[Arg::from(mode)]);
+    }
+}
+
+unsafe extern "system" fn blend_equation_separate(mode_rgb: GLenum, mode_alpha: GLenum) {
+    unsafe {
+        call(
+            "glBlendEquationSeparate",
+            &[Arg::from(mode_rgb), Arg::from(mode_alpha)],
+        );
+    }
+}
+
+unsafe extern "system" fn blend_color(red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+    unsafe {
+        call(
+            "glBlendColor",
+            &[
+                Arg::from(red),
+                Arg::from(green),
+                Arg::from(blue),
+                Arg::from(alpha),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn stencil_func(func: GLenum, reference: GLint, mask: GLuint) {
+    unsafe {
+        call(
+            "glStencilFunc",
+            &[Arg::from(func), Arg::from(reference), Arg::from(mask)],
+        );
+    }
+}
+
+unsafe extern "system" fn stencil_op(fail: GLenum, zfail: GLenum, zpass: GLenum) {
+    unsafe {
+        call(
+            "glStencilOp",
+            &[Arg::from(fail), Arg::from(zfail), Arg::from(zpass)],
+        );
+    }
+}
+
+unsafe extern "system" fn stencil_mask(mask: GLuint) {
+    unsafe {
+        call("glStencilMask", &[Arg::from(mask)]);
+    }
+}
+
+unsafe extern "system" fn clear(mask: GLbitField) {
+    unsafe {
+        call("glClear", &[Arg::from(mask)]);
+    }
+}
+
+unsafe extern "system" fn clear_color(red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+    unsafe {
+        call(
+            "glClearColor",
+            &[
+                Arg::from(red),
+                Arg::from(green),
+                Arg::from(blue),
+                Arg::from(alpha),
+            ],
+        );
+    }
+}
+
+#[allow(clippy::similar_names)]
+unsafe extern "system" fn viewport(x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+    unsafe {
+        call(
+            "glViewport",
+            &[
+                Arg::from(x),
+                Arg::from(y),
+                Arg::from(width),
+                Arg::from(height),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn get_integer_v(pname: GLenum, data: *mut GLint) {
+    unsafe {
+        call("glGetIntegerv", &[Arg::from(pname), Arg::from(data)]);
+    }
+}
+
+unsafe extern "system" fn get_error() -> GLenum {
+    unsafe { Return::from_name(call("glGetError", &[])) }
+}
+
+unsafe extern "system" fn get_string(name: GLenum) -> *const GLubyte {
+    unsafe { Return::from_name(call("glGetString", &[Arg::from(name)])) }
+}
+
+unsafe extern "system" fn get_stringi(name: GLenum, index: GLuint) -> *const GLubyte {
+    unsafe { Return::from_name(call("glGetStringi", &[Arg::from(name), Arg::from(index)])) }
+}
+
+unsafe extern "system" fn draw_elements(
+    mode: GLenum,
+    count: GLsizei,
+    kind: GLenum,
+    indices: *const std::ffi::c_void,
+) {
+    unsafe {
+        call(
+            "glDrawElements",
+            &[
+                Arg::from(mode),
+                Arg::from(count),
+                Arg::from(kind),
+                Arg::from(indices),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn draw_elements_base_vertex(
+    mode: GLenum,
+    count: GLsizei,
+    kind: GLenum,
+    indices: *const std::ffi::c_void,
+    basevertex: GLint,
+) {
+    unsafe {
+        call(
+            "glDrawElementsBaseVertex",
+            &[
+                Arg::from(mode),
+                Arg::from(count),
+                Arg::from(kind),
+                Arg::from(indices),
+                Arg::from(basevertex),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn draw_arrays(mode: GLenum, first: GLint, count: GLsizei) {
+    unsafe {
+        call(
+            "glDrawArrays",
+            &[Arg::from(mode), Arg::from(first), Arg::from(count)],
+        );
+    }
+}
+
+unsafe extern "system" fn draw_arrays_instanced(
+    mode: GLenum,
+    first: GLint,
+    count: GLsizei,
+    instancecount: GLsizei,
+) {
+    unsafe {
+        call(
+            "glDrawArraysInstanced",
+            &[
+                Arg::from(mode),
+                Arg::from(first),
+                Arg::from(count),
+                Arg::from(instancecount),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn draw_elements_instanced_base_vertex_base_instance(
+    mode: GLenum,
+    count: GLsizei,
+    kind: GLenum,
+    indices: *const std::ffi::c_void,
+    instancecount: GLsizei,
+    basevertex: GLint,
+    baseinstance: GLuint,
+) {
+    unsafe {
+        call(
+            "glDrawElementsInstancedBaseVertexBaseInstance",
+            &[
+                Arg::from(mode),
+                Arg::from(count),
+                Arg::from(kind),
+                Arg::from(indices),
+                Arg::from(instancecount),
+                Arg::from(basevertex),
+                Arg::from(baseinstance),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn multi_draw_elements_indirect(
+    mode: GLenum,
+    kind: GLenum,
+    indirect: *const std::ffi::c_void,
+    drawcount: GLsizei,
+    stride: GLsizei,
+) {
+    unsafe {
+        call(
+            "glMultiDrawElementsIndirect",
+            &[
+                Arg::from(mode),
+                Arg::from(kind),
+                Arg::from(indirect),
+                Arg::from(drawcount),
+                Arg::from(stride),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn patch_parameter_i(pname: GLenum, value: GLint) {
+    unsafe {
+        call("glPatchParameteri", &[Arg::from(pname), Arg::from(value)]);
+    }
+}
+
+unsafe extern "system" fn gen_vertex_arrays(n: GLsizei, arrays: *mut GLuint) {
+    unsafe {
+        call("glGenVertexArrays", &[Arg::from(n), Arg::from(arrays)]);
+    }
+}
+
+unsafe extern "system" fn bind_vertex_array(array: GLuint) {
+    unsafe {
+        call("glBindVertexArray", &[Arg::from(array)]);
+    }
+}
+
+unsafe extern "system" fn enable_vertex_attrib_array(index: GLuint) {
+    unsafe {
+        call("glEnableVertexAttribArray", &[Arg::from(index)]);
+    }
+}
+
+unsafe extern "system" fn delete_vertex_arrays(n: GLsizei, arrays: *const GLuint) {
+    unsafe {
+        call("glDeleteVertexArrays", &[Arg::from(n), Arg::from(arrays)]);
+    }
+}
+
+unsafe extern "system" fn gen_buffers(n: GLsizei, buffers: *mut GLuint) {
+    unsafe {
+        call("glGenBuffers", &[Arg::from(n), Arg::from(buffers)]);
+    }
+}
+
+unsafe extern "system" fn bind_buffer(target: GLenum, buffer: GLuint) {
+    unsafe {
+        call("glBindBuffer", &[Arg::from(target), Arg::from(buffer)]);
+    }
+}
+
+unsafe extern "system" fn buffer_data(
+    target: GLenum,
+    size: GLsizeiptr,
+    data: *const std::ffi::c_void,
+    usage: GLenum,
+) {
+    unsafe {
+        call(
+            "glBufferData",
+            &[
+                Arg::from(target),
+                Arg::from(size),
+                Arg::from(data),
+                Arg::from(usage),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn buffer_sub_data(
+    target: GLenum,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    data: *const std::ffi::c_void,
+) {
+    unsafe {
+        call(
+            "glBufferSubData",
+            &[
+                Arg::from(target),
+                Arg::from(offset),
+                Arg::from(size),
+                Arg::from(data),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn map_buffer_range(
+    target: GLenum,
+    offset: GLintptr,
+    length: GLsizeiptr,
+    access: GLbitField,
+) -> *mut std::ffi::c_void {
+    unsafe {
+        Return::from_name(call(
+            "glMapBufferRange",
+            &[
+                Arg::from(target),
+                Arg::from(offset),
+                Arg::from(length),
+                Arg::from(access),
+            ],
+        ))
+    }
+}
+
+unsafe extern "system" fn unmap_buffer(target: GLenum) -> GLboolean {
+    unsafe { Return::from_name(call("glUnmapBuffer", &[Arg::from(target)])) }
+}
+
+unsafe extern "system" fn flush_mapped_buffer_range(
+    target: GLenum,
+    offset: GLintptr,
+    length: GLsizeiptr,
+) {
+    unsafe {
+        call(
+            "glFlushMappedBufferRange",
+            &[Arg::from(target), Arg::from(offset), Arg::from(length)],
+        );
+    }
+}
+
+unsafe extern "system" fn get_buffer_sub_data(
+    target: GLenum,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    data: *mut std::ffi::c_void,
+) {
+    unsafe {
+        call(
+            "glGetBufferSubData",
+            &[
+                Arg::from(target),
+                Arg::from(offset),
+                Arg::from(size),
+                Arg::from(data),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn fence_sync(condition: GLenum, flags: GLbitField) -> GLsync {
+    unsafe {
+        Return::from_name(call(
+            "glFenceSync",
+            &[Arg::from(condition), Arg::from(flags)],
+        ))
+    }
+}
+
+unsafe extern "system" fn client_wait_sync(
+    sync: GLsync,
+    flags: GLbitField,
+    timeout: GLuint64,
+) -> GLenum {
+    unsafe {
+        Return::from_name(call(
+            "glClientWaitSync",
+            &[Arg::from(sync), Arg::from(flags), Arg::from(timeout)],
+        ))
+    }
+}
+
+unsafe extern "system" fn delete_sync(sync: GLsync) {
+    unsafe {
+        call("glDeleteSync", &[Arg::from(sync)]);
+    }
+}
+
+unsafe extern "system" fn flush() {
+    unsafe {
+        call("glFlush", &[]);
+    }
+}
+
+unsafe extern "system" fn finish() {
+    unsafe {
+        call("glFinish", &[]);
+    }
+}
+
+unsafe extern "system" fn bind_buffer_base(target: GLenum, index: GLuint, buffer: GLuint) {
+    unsafe {
+        call(
+            "glBindBufferBase",
+            &[Arg::from(target), Arg::from(index), Arg::from(buffer)],
+        );
+    }
+}
+
+unsafe extern "system" fn bind_buffer_range(
+    target: GLenum,
+    index: GLuint,
+    buffer: GLuint,
+    offset: GLintptr,
+    size: GLsizeiptr,
+) {
+    unsafe {
+        call(
+            "glBindBufferRange",
+            &[
+                Arg::from(target),
+                Arg::from(index),
+                Arg::from(buffer),
+                Arg::from(offset),
+                Arg::from(size),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn delete_buffers(n: GLsizei, buffers: *const GLuint) {
+    unsafe {
+        call("glDeleteBuffers", &[Arg::from(n), Arg::from(buffers)]);
+    }
+}
+
+unsafe extern "system" fn create_shader(kind: GLenum) -> GLuint {
+    unsafe { Return::from_name(call("glCreateShader", &[Arg::from(kind)])) }
+}
+
+unsafe extern "system" fn shader_source(
+    shader: GLuint,
+    count: GLsizei,
+    string: *const *const GLchar,
+    length: *const GLint,
+) {
+    unsafe {
+        call(
+            "glShaderSource",
+            &[
+                Arg::from(shader),
+                Arg::from(count),
+                Arg::from(string),
+                Arg::from(length),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn compile_shader(shader: GLuint) {
+    unsafe {
+        call("glCompileShader", &[Arg::from(shader)]);
+    }
+}
+
+unsafe extern "system" fn delete_shader(shader: GLuint) {
+    unsafe {
+        call("glDeleteShader", &[Arg::from(shader)]);
+    }
+}
+
+unsafe extern "system" fn get_shader_iv(shader: GLuint, pname: GLenum, params: *mut GLint) {
+    unsafe {
+        call(
+            "glGetShaderiv",
+            &[Arg::from(shader), Arg::from(pname), Arg::from(params)],
+        );
+    }
+}
+
+unsafe extern "system" fn get_shader_info_log(
+    shader: GLuint,
+    buf_size: GLsizei,
+    length: *mut GLsizei,
+    info_log: *mut GLchar,
+) {
+    unsafe {
+        call(
+            "glGetShaderInfoLog",
+            &[
+                Arg::from(shader),
+                Arg::from(buf_size),
+                Arg::from(length),
+                Arg::from(info_log),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn create_program() -> GLuint {
+    unsafe { Return::from_name(call("glCreateProgram", &[])) }
+}
+
+unsafe extern "system" fn attach_shader(program: GLuint, shader: GLuint) {
+    unsafe {
+        call("glAttachShader", &[Arg::from(program), Arg::from(shader)]);
+    }
+}
+
+unsafe extern "system" fn link_program(program: GLuint) {
+    unsafe {
+        call("glLinkProgram", &[Arg::from(program)]);
+    }
+}
+
+unsafe extern "system" fn get_program_iv(program: GLuint, pname: GLenum, params: *mut GLint) {
+    unsafe {
+        call(
+            "glGetProgramiv",
+            &[Arg::from(program), Arg::from(pname), Arg::from(params)],
+        );
+    }
+}
+
+unsafe extern "system" fn get_program_info_log(
+    program: GLuint,
+    buf_size: GLsizei,
+    length: *mut GLsizei,
+    info_log: *mut GLchar,
+) {
+    unsafe {
+        call(
+            "glGetProgramInfoLog",
+            &[
+                Arg::from(program),
+                Arg::from(buf_size),
+                Arg::from(length),
+                Arg::from(info_log),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn detach_shader(program: GLuint, shader: GLuint) {
+    unsafe {
+        call("glDetachShader", &[Arg::from(program), Arg::from(shader)]);
+    }
+}
+
+unsafe extern "system" fn use_program(program: GLuint) {
+    unsafe {
+        call("glUseProgram", &[Arg::from(program)]);
+    }
+}
+
+unsafe extern "system" fn delete_program(program: GLuint) {
+    unsafe {
+        call("glDeleteProgram", &[Arg::from(program)]);
+    }
+}
+
+unsafe extern "system" fn get_uniform_block_index(
+    program: GLuint,
+    uniform_block_name: *const GLchar,
+) -> GLuint {
+    unsafe {
+        Return::from_name(call(
+            "glGetUniformBlockIndex",
+            &[Arg::from(program), Arg::from(uniform_block_name)],
+        ))
+    }
+}
+
+unsafe extern "system" fn uniform_block_binding(
+    program: GLuint,
+    uniform_block_index: GLuint,
+    uniform_block_binding: GLuint,
+) {
+    unsafe {
+        call(
+            "glUniformBlockBinding",
+            &[
+                Arg::from(program),
+                Arg::from(uniform_block_index),
+                Arg::from(uniform_block_binding),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn uniform1_i(location: GLint, v0: GLint) {
+    unsafe {
+        call("glUniform1i", &[Arg::from(location), Arg::from(v0)]);
+    }
+}
+
+unsafe extern "system" fn uniform1_f(location: GLint, v0: GLfloat) {
+    unsafe {
+        call("glUniform1f", &[Arg::from(location), Arg::from(v0)]);
+    }
+}
+
+unsafe extern "system" fn uniform1_ui(location: GLint, v0: GLuint) {
+    unsafe {
+        call("glUniform1ui", &[Arg::from(location), Arg::from(v0)]);
+    }
+}
+
+unsafe extern "system" fn uniform1_fv(location: GLint, count: GLsizei, value: *const GLfloat) {
+    unsafe {
+        call(
+            "glUniform1fv",
+            &[Arg::from(location), Arg::from(count), Arg::from(value)],
+        );
+    }
+}
+
+unsafe extern "system" fn uniform2_fv(location: GLint, count: GLsizei, value: *const GLfloat) {
+    unsafe {
+        call(
+            "glUniform2fv",
+            &[Arg::from(location), Arg::from(count), Arg::from(value)],
+        );
+    }
+}
+
+unsafe extern "system" fn uniform3_fv(location: GLint, count: GLsizei, value: *const GLfloat) {
+    unsafe {
+        call(
+            "glUniform3fv",
+            &[Arg::from(location), Arg::from(count), Arg::from(value)],
+        );
+    }
+}
+
+unsafe extern "system" fn uniform4_fv(location: GLint, count: GLsizei, value: *const GLfloat) {
+    unsafe {
+        call(
+            "glUniform4fv",
+            &[Arg::from(location), Arg::from(count), Arg::from(value)],
+        );
+    }
+}
+
+unsafe extern "system" fn uniform1_iv(location: GLint, count: GLsizei, value: *const GLint) {
+    unsafe {
+        call(
+            "glUniform1iv",
+            &[Arg::from(location), Arg::from(count), Arg::from(value)],
+        );
+    }
+}
+
+unsafe extern "system" fn uniform2_iv(location: GLint, count: GLsizei, value: *const GLint) {
+    unsafe {
+        call(
+            "glUniform2iv",
+            &[Arg::from(location), Arg::from(count), Arg::from(value)],
+        );
+    }
+}
+
+unsafe extern "system" fn uniform3_iv(location: GLint, count: GLsizei, value: *const GLint) {
+    unsafe {
+        call(
+            "glUniform3iv",
+            &[Arg::from(location), Arg::from(count), Arg::from(value)],
+        );
+    }
+}
+
+unsafe extern "system" fn uniform4_iv(location: GLint, count: GLsizei, value: *const GLint) {
+    unsafe {
+        call(
+            "glUniform4iv",
+            &[Arg::from(location), Arg::from(count), Arg::from(value)],
+        );
+    }
+}
+
+unsafe extern "system" fn uniform1_uiv(location: GLint, count: GLsizei, value: *const GLuint) {
+    unsafe {
+        call(
+            "glUniform1uiv",
+            &[Arg::from(location), Arg::from(count), Arg::from(value)],
+        );
+    }
+}
+
+unsafe extern "system" fn uniform2_uiv(location: GLint, count: GLsizei, value: *const GLuint) {
+    unsafe {
+        call(
+            "glUniform2uiv",
+            &[Arg::from(location), Arg::from(count), Arg::from(value)],
+        );
+    }
+}
+
+unsafe extern "system" fn uniform3_uiv(location: GLint, count: GLsizei, value: *const GLuint) {
+    unsafe {
+        call(
+            "glUniform3uiv",
+            &[Arg::from(location), Arg::from(count), Arg::from(value)],
+        );
+    }
+}
+
+unsafe extern "system" fn uniform4_uiv(location: GLint, count: GLsizei, value: *const GLuint) {
+    unsafe {
+        call(
+            "glUniform4uiv",
+            &[Arg::from(location), Arg::from(count), Arg::from(value)],
+        );
+    }
+}
+
+unsafe extern "system" fn uniform_matrix3_fv(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+) {
+    unsafe {
+        call(
+            "glUniformMatrix3fv",
+            &[
+                Arg::from(location),
+                Arg::from(count),
+                Arg::from(transpose),
+                Arg::from(value),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn uniform_matrix4_fv(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+) {
+    unsafe {
+        call(
+            "glUniformMatrix4fv",
+            &[
+                Arg::from(location),
+                Arg::from(count),
+                Arg::from(transpose),
+                Arg::from(value),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform1_i(program: GLuint, location: GLint, v0: GLint) {
+    unsafe {
+        call(
+            "glProgramUniform1i",
+            &[Arg::from(program), Arg::from(location), Arg::from(v0)],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform1_f(program: GLuint, location: GLint, v0: GLfloat) {
+    unsafe {
+        call(
+            "glProgramUniform1f",
+            &[Arg::from(program), Arg::from(location), Arg::from(v0)],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform1_ui(program: GLuint, location: GLint, v0: GLuint) {
+    unsafe {
+        call(
+            "glProgramUniform1ui",
+            &[Arg::from(program), Arg::from(location), Arg::from(v0)],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform1_fv(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLfloat,
+) {
+    unsafe {
+        call(
+            "glProgramUniform1fv",
+            &[
+                Arg::from(program),
+                Arg::from(location),
+                Arg::from(count),
+                Arg::from(value),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform2_fv(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLfloat,
+) {
+    unsafe {
+        call(
+            "glProgramUniform2fv",
+            &[
+                Arg::from(program),
+                Arg::from(location),
+                Arg::from(count),
+                Arg::from(value),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform3_fv(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLfloat,
+) {
+    unsafe {
+        call(
+            "glProgramUniform3fv",
+            &[
+                Arg::from(program),
+                Arg::from(location),
+                Arg::from(count),
+                Arg::from(value),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform4_fv(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLfloat,
+) {
+    unsafe {
+        call(
+            "glProgramUniform4fv",
+            &[
+                Arg::from(program),
+                Arg::from(location),
+                Arg::from(count),
+                Arg::from(value),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform1_iv(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLint,
+) {
+    unsafe {
+        call(
+            "glProgramUniform1iv",
+            &[
+                Arg::from(program),
+                Arg::from(location),
+                Arg::from(count),
+                Arg::from(value),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform2_iv(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLint,
+) {
+    unsafe {
+        call(
+            "glProgramUniform2iv",
+            &[
+                Arg::from(program),
+                Arg::from(location),
+                Arg::from(count),
+                Arg::from(value),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform3_iv(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLint,
+) {
+    unsafe {
+        call(
+            "glProgramUniform3iv",
+            &[
+                Arg::from(program),
+                Arg::from(location),
+                Arg::from(count),
+                Arg::from(value),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform4_iv(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLint,
+) {
+    unsafe {
+        call(
+            "glProgramUniform4iv",
+            &[
+                Arg::from(program),
+                Arg::from(location),
+                Arg::from(count),
+                Arg::from(value),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform1_uiv(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLuint,
+) {
+    unsafe {
+        call(
+            "glProgramUniform1uiv",
+            &[
+                Arg::from(program),
+                Arg::from(location),
+                Arg::from(count),
+                Arg::from(value),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform2_uiv(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLuint,
+) {
+    unsafe {
+        call(
+            "glProgramUniform2uiv",
+            &[
+                Arg::from(program),
+                Arg::from(location),
+                Arg::from(count),
+                Arg::from(value),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform3_uiv(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLuint,
+) {
+    unsafe {
+        call(
+            "glProgramUniform3uiv",
+            &[
+                Arg::from(program),
+                Arg::from(location),
+                Arg::from(count),
+                Arg::from(value),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform4_uiv(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLuint,
+) {
+    unsafe {
+        call(
+            "glProgramUniform4uiv",
+            &[
+                Arg::from(program),
+                Arg::from(location),
+                Arg::from(count),
+                Arg::from(value),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform_matrix3_fv(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+) {
+    unsafe {
+        call(
+            "glProgramUniformMatrix3fv",
+            &[
+                Arg::from(program),
+                Arg::from(location),
+                Arg::from(count),
+                Arg::from(transpose),
+                Arg::from(value),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn program_uniform_matrix4_fv(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+) {
+    unsafe {
+        call(
+            "glProgramUniformMatrix4fv",
+            &[
+                Arg::from(program),
+                Arg::from(location),
+                Arg::from(count),
+                Arg::from(transpose),
+                Arg::from(value),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn gen_textures(n: GLsizei, textures: *mut GLuint) {
+    unsafe {
+        call("glGenTextures", &[Arg::from(n), Arg::from(textures)]);
+    }
+}
+
+unsafe extern "system" fn active_texture(texture: GLenum) {
+    unsafe {
+        call("glActiveTexture", &[Arg::from(texture)]);
+    }
+}
+
+unsafe extern "system" fn bind_texture(target: GLenum, texture: GLuint) {
+    unsafe {
+        call("glBindTexture", &[Arg::from(target), Arg::from(texture)]);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "system" fn tex_image_2d(
+    target: GLenum,
+    level: GLint,
+    internalformat: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    border: GLint,
+    format: GLenum,
+    kind: GLenum,
+    pixels: *const std::ffi::c_void,
+) {
+    unsafe {
+        call(
+            "glTexImage2D",
+            &[
+                Arg::from(target),
+                Arg::from(level),
+                Arg::from(internalformat),
+                Arg::from(width),
+                Arg::from(height),
+                Arg::from(border),
+                Arg::from(format),
+                Arg::from(kind),
+                Arg::from(pixels),
+            ],
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "system" fn tex_image_3d(
+    target: GLenum,
+    level: GLint,
+    internalformat: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    border: GLint,
+    format: GLenum,
+    kind: GLenum,
+    pixels: *const std::ffi::c_void,
+) {
+    unsafe {
+        call(
+            "glTexImage3D",
+            &[
+                Arg::from(target),
+                Arg::from(level),
+                Arg::from(internalformat),
+                Arg::from(width),
+                Arg::from(height),
+                Arg::from(depth),
+                Arg::from(border),
+                Arg::from(format),
+                Arg::from(kind),
+                Arg::from(pixels),
+            ],
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "system" fn compressed_tex_image_2d(
+    target: GLenum,
+    level: GLint,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    border: GLint,
+    image_size: GLsizei,
+    data: *const std::ffi::c_void,
+) {
+    unsafe {
+        call(
+            "glCompressedTexImage2D",
+            &[
+                Arg::from(target),
+                Arg::from(level),
+                Arg::from(internalformat),
+                Arg::from(width),
+                Arg::from(height),
+                Arg::from(border),
+                Arg::from(image_size),
+                Arg::from(data),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn tex_storage_2d(
+    target: GLenum,
+    levels: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+) {
+    unsafe {
+        call(
+            "glTexStorage2D",
+            &[
+                Arg::from(target),
+                Arg::from(levels),
+                Arg::from(internalformat),
+                Arg::from(width),
+                Arg::from(height),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn tex_storage_3d(
+    target: GLenum,
+    levels: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+) {
+    unsafe {
+        call(
+            "glTexStorage3D",
+            &[
+                Arg::from(target),
+                Arg::from(levels),
+                Arg::from(internalformat),
+                Arg::from(width),
+                Arg::from(height),
+                Arg::from(depth),
+            ],
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::similar_names)]
+unsafe extern "system" fn tex_sub_image_2d(
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: GLenum,
+    kind: GLenum,
+    pixels: *const std::ffi::c_void,
+) {
+    unsafe {
+        call(
+            "glTexSubImage2D",
+            &[
+                Arg::from(target),
+                Arg::from(level),
+                Arg::from(xoffset),
+                Arg::from(yoffset),
+                Arg::from(width),
+                Arg::from(height),
+                Arg::from(format),
+                Arg::from(kind),
+                Arg::from(pixels),
+            ],
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::similar_names)]
+unsafe extern "system" fn tex_sub_image_3d(
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    zoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    format: GLenum,
+    kind: GLenum,
+    pixels: *const std::ffi::c_void,
+) {
+    unsafe {
+        call(
+            "glTexSubImage3D",
+            &[
+                Arg::from(target),
+                Arg::from(level),
+                Arg::from(xoffset),
+                Arg::from(yoffset),
+                Arg::from(zoffset),
+                Arg::from(width),
+                Arg::from(height),
+                Arg::from(depth),
+                Arg::from(format),
+                Arg::from(kind),
+                Arg::from(pixels),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn generate_mipmap(target: GLenum) {
+    unsafe {
+        call("glGenerateMipmap", &[Arg::from(target)]);
+    }
+}
+
+unsafe extern "system" fn pixel_store_i(pname: GLenum, param: GLint) {
+    unsafe {
+        call("glPixelStorei", &[Arg::from(pname), Arg::from(param)]);
+    }
+}
+
+unsafe extern "system" fn delete_textures(n: GLsizei, textures: *const GLuint) {
+    unsafe {
+        call("glDeleteTextures", &[Arg::from(n), Arg::from(textures)]);
+    }
+}
+
+unsafe extern "system" fn gen_queries(n: GLsizei, ids: *mut GLuint) {
+    unsafe {
+        call("glGenQueries", &[Arg::from(n), Arg::from(ids)]);
+    }
+}
+
+unsafe extern "system" fn begin_query(target: GLenum, id: GLuint) {
+    unsafe {
+        call("glBeginQuery", &[Arg::from(target), Arg::from(id)]);
+    }
+}
+
+unsafe extern "system" fn end_query(target: GLenum) {
+    unsafe {
+        call("glEndQuery", &[Arg::from(target)]);
+    }
+}
+
+unsafe extern "system" fn query_counter(id: GLuint, target: GLenum) {
+    unsafe {
+        call("glQueryCounter", &[Arg::from(id), Arg::from(target)]);
+    }
+}
+
+unsafe extern "system" fn get_query_object_iv(id: GLuint, pname: GLenum, params: *mut GLint) {
+    unsafe {
+        call(
+            "glGetQueryObjectiv",
+            &[Arg::from(id), Arg::from(pname), Arg::from(params)],
+        );
+    }
+}
+
+unsafe extern "system" fn get_query_object_uiv(id: GLuint, pname: GLenum, params: *mut GLuint) {
+    unsafe {
+        call(
+            "glGetQueryObjectuiv",
+            &[Arg::from(id), Arg::from(pname), Arg::from(params)],
+        );
+    }
+}
+
+unsafe extern "system" fn get_query_object_i64v(id: GLuint, pname: GLenum, params: *mut GLint64) {
+    unsafe {
+        call(
+            "glGetQueryObjecti64v",
+            &[Arg::from(id), Arg::from(pname), Arg::from(params)],
+        );
+    }
+}
+
+unsafe extern "system" fn get_query_object_ui64v(id: GLuint, pname: GLenum, params: *mut GLuint64) {
+    unsafe {
+        call(
+            "glGetQueryObjectui64v",
+            &[Arg::from(id), Arg::from(pname), Arg::from(params)],
+        );
+    }
+}
+
+unsafe extern "system" fn delete_queries(n: GLsizei, ids: *const GLuint) {
+    unsafe {
+        call("glDeleteQueries", &[Arg::from(n), Arg::from(ids)]);
+    }
+}
+
+unsafe extern "system" fn gen_samplers(count: GLsizei, samplers: *mut GLuint) {
+    unsafe {
+        call("glGenSamplers", &[Arg::from(count), Arg::from(samplers)]);
+    }
+}
+
+unsafe extern "system" fn bind_sampler(unit: GLuint, sampler: GLuint) {
+    unsafe {
+        call("glBindSampler", &[Arg::from(unit), Arg::from(sampler)]);
+    }
+}
+
+unsafe extern "system" fn sampler_parameter_i(sampler: GLuint, pname: GLenum, param: GLint) {
+    unsafe {
+        call(
+            "glSamplerParameteri",
+            &[Arg::from(sampler), Arg::from(pname), Arg::from(param)],
+        );
+    }
+}
+
+unsafe extern "system" fn sampler_parameter_f(sampler: GLuint, pname: GLenum, param: GLfloat) {
+    unsafe {
+        call(
+            "glSamplerParameterf",
+            &[Arg::from(sampler), Arg::from(pname), Arg::from(param)],
+        );
+    }
+}
+
+unsafe extern "system" fn sampler_parameter_fv(
+    sampler: GLuint,
+    pname: GLenum,
+    param: *const GLfloat,
+) {
+    unsafe {
+        call(
+            "glSamplerParameterfv",
+            &[Arg::from(sampler), Arg::from(pname), Arg::from(param)],
+        );
+    }
+}
+
+unsafe extern "system" fn delete_samplers(count: GLsizei, samplers: *const GLuint) {
+    unsafe {
+        call("glDeleteSamplers", &[Arg::from(count), Arg::from(samplers)]);
+    }
+}
+
+unsafe extern "system" fn gen_framebuffers(n: GLsizei, framebuffers: *mut GLuint) {
+    unsafe {
+        call(
+            "glGenFramebuffers",
+            &[Arg::from(n), Arg::from(framebuffers)],
+        );
+    }
+}
+
+unsafe extern "system" fn bind_framebuffer(target: GLenum, framebuffer: GLuint) {
+    unsafe {
+        call(
+            "glBindFramebuffer",
+            &[Arg::from(target), Arg::from(framebuffer)],
+        );
+    }
+}
+
+unsafe extern "system" fn framebuffer_texture_2d(
+    target: GLenum,
+    attachment: GLenum,
+    textarget: GLenum,
+    texture: GLuint,
+    level: GLint,
+) {
+    unsafe {
+        call(
+            "glFramebufferTexture2D",
+            &[
+                Arg::from(target),
+                Arg::from(attachment),
+                Arg::from(textarget),
+                Arg::from(texture),
+                Arg::from(level),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn framebuffer_renderbuffer(
+    target: GLenum,
+    attachment: GLenum,
+    renderbuffertarget: GLenum,
+    renderbuffer: GLuint,
+) {
+    unsafe {
+        call(
+            "glFramebufferRenderbuffer",
+            &[
+                Arg::from(target),
+                Arg::from(attachment),
+                Arg::from(renderbuffertarget),
+                Arg::from(renderbuffer),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn check_framebuffer_status(target: GLenum) -> GLenum {
+    unsafe { Return::from_name(call("glCheckFramebufferStatus", &[Arg::from(target)])) }
+}
+
+unsafe extern "system" fn draw_buffers(n: GLsizei, bufs: *const GLenum) {
+    unsafe {
+        call("glDrawBuffers", &[Arg::from(n), Arg::from(bufs)]);
+    }
+}
+
+unsafe extern "system" fn read_buffer(src: GLenum) {
+    unsafe {
+        call("glReadBuffer", &[Arg::from(src)]);
+    }
+}
+
+#[allow(clippy::similar_names)]
+unsafe extern "system" fn read_pixels(
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: GLenum,
+    kind: GLenum,
+    pixels: *mut std::ffi::c_void,
+) {
+    unsafe {
+        call(
+            "glReadPixels",
+            &[
+                Arg::from(x),
+                Arg::from(y),
+                Arg::from(width),
+                Arg::from(height),
+                Arg::from(format),
+                Arg::from(kind),
+                Arg::from(pixels),
+            ],
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::similar_names)]
+unsafe extern "system" fn blit_framebuffer(
+    src_x0: GLint,
+    src_y0: GLint,
+    src_x1: GLint,
+    src_y1: GLint,
+    dst_x0: GLint,
+    dst_y0: GLint,
+    dst_x1: GLint,
+    dst_y1: GLint,
+    mask: GLbitField,
+    filter: GLenum,
+) {
+    unsafe {
+        call(
+            "glBlitFramebuffer",
+            &[
+                Arg::from(src_x0),
+                Arg::from(src_y0),
+                Arg::from(src_x1),
+                Arg::from(src_y1),
+                Arg::from(dst_x0),
+                Arg::from(dst_y0),
+                Arg::from(dst_x1),
+                Arg::from(dst_y1),
+                Arg::from(mask),
+                Arg::from(filter),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn delete_framebuffers(n: GLsizei, framebuffers: *const GLuint) {
+    unsafe {
+        call(
+            "glDeleteFramebuffers",
+            &[Arg::from(n), Arg::from(framebuffers)],
+        );
+    }
+}
+
+unsafe extern "system" fn gen_renderbuffers(n: GLsizei, renderbuffers: *mut GLuint) {
+    unsafe {
+        call(
+            "glGenRenderbuffers",
+            &[Arg::from(n), Arg::from(renderbuffers)],
+        );
+    }
+}
+
+unsafe extern "system" fn bind_renderbuffer(target: GLenum, renderbuffer: GLuint) {
+    unsafe {
+        call(
+            "glBindRenderbuffer",
+            &[Arg::from(target), Arg::from(renderbuffer)],
+        );
+    }
+}
+
+unsafe extern "system" fn renderbuffer_storage(
+    target: GLenum,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+) {
+    unsafe {
+        call(
+            "glRenderbufferStorage",
+            &[
+                Arg::from(target),
+                Arg::from(internalformat),
+                Arg::from(width),
+                Arg::from(height),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn delete_renderbuffers(n: GLsizei, renderbuffers: *const GLuint) {
+    unsafe {
+        call(
+            "glDeleteRenderbuffers",
+            &[Arg::from(n), Arg::from(renderbuffers)],
+        );
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+pub(super) fn lookup(symbol: &str) -> *const std::ffi::c_void {
+    match symbol {
+        "glDispatchCompute" => dispatch_compute as *const std::ffi::c_void,
+        "glDispatchComputeIndirect" => dispatch_compute_indirect as *const std::ffi::c_void,
+        "glMemoryBarrier" => memory_barrier as *const std::ffi::c_void,
+        "glBindImageTexture" => bind_image_texture as *const std::ffi::c_void,
+        "glDebugMessageCallback" => debug_message_callback as *const std::ffi::c_void,
+        "glDebugMessageControl" => debug_message_control as *const std::ffi::c_void,
+        "glGetDebugMessageLog" => get_debug_message_log as *const std::ffi::c_void,
+        "glDebugMessageInsert" => debug_message_insert as *const std::ffi::c_void,
+        "glPushDebugGroup" => push_debug_group as *const std::ffi::c_void,
+        "glPopDebugGroup" => pop_debug_group as *const std::ffi::c_void,
+        "glObjectLabel" => object_label as *const std::ffi::c_void,
+        "glVertexAttribFormat" => vertex_attrib_format as *const std::ffi::c_void,
+        "glVertexAttribIFormat" => vertex_attrib_i_format as *const std::ffi::c_void,
+        "glBindVertexBuffer" => bind_vertex_buffer as *const std::ffi::c_void,
+        "glVertexBindingDivisor" => vertex_binding_divisor as *const std::ffi::c_void,
+        "glVertexAttribBinding" => vertex_attrib_binding as *const std::ffi::c_void,
+        "glGetProgramInterfaceiv" => get_program_interface_iv as *const std::ffi::c_void,
+        "glGetProgramResourceiv" => get_program_resource_iv as *const std::ffi::c_void,
+        "glGetProgramResourceName" => get_program_resource_name as *const std::ffi::c_void,
+        "glGetProgramResourceIndex" => get_program_resource_index as *const std::ffi::c_void,
+        "glShaderStorageBlockBinding" => shader_storage_block_binding as *const std::ffi::c_void,
+        "glEnable" => enable as *const std::ffi::c_void,
+        "glDisable" => disable as *const std::ffi::c_void,
+        "glDepthFunc" => depth_func as *const std::ffi::c_void,
+        "glDepthMask" => depth_mask as *const std::ffi::c_void,
+        "glColorMask" => color_mask as *const std::ffi::c_void,
+        "glCullFace" => cull_face as *const std::ffi::c_void,
+        "glFrontFace" => front_face as *const std::ffi::c_void,
+        "glPolygonMode" => polygon_mode as *const std::ffi::c_void,
+        "glPolygonOffset" => polygon_offset as *const std::ffi::c_void,
+        "glScissor" => scissor as *const std::ffi::c_void,
+        "glBlendFunc" => blend_func as *const std::ffi::c_void,
+        "glBlendFuncSeparate" => blend_func_separate as *const std::ffi::c_void,
+        "glBlendEquation" => blend_equation as *const std::ffi::c_void,
+        "glBlendEquationSeparate" => blend_equation_separate as *const std::ffi::c_void,
+        "glBlendColor" => blend_color as *const std::ffi::c_void,
+        "glStencilFunc" => stencil_func as *const std::ffi::c_void,
+        "glStencilOp" => stencil_op as *const std::ffi::c_void,
+        "glStencilMask" => stencil_mask as *const std::ffi::c_void,
+        "glClear" => clear as *const std::ffi::c_void,
+        "glClearColor" => clear_color as *const std::ffi::c_void,
+        "glViewport" => viewport as *const std::ffi::c_void,
+        "glGetIntegerv" => get_integer_v as *const std::ffi::c_void,
+        "glGetError" => get_error as *const std::ffi::c_void,
+        "glGetString" => get_string as *const std::ffi::c_void,
+        "glGetStringi" => get_stringi as *const std::ffi::c_void,
+        "glDrawElements" => draw_elements as *const std::ffi::c_void,
+        "glDrawElementsBaseVertex" => draw_elements_base_vertex as *const std::ffi::c_void,
+        "glDrawArrays" => draw_arrays as *const std::ffi::c_void,
+        "glDrawArraysInstanced" => draw_arrays_instanced as *const std::ffi::c_void,
+        "glDrawElementsInstancedBaseVertexBaseInstance" => {
+            draw_elements_instanced_base_vertex_base_instance as *const std::ffi::c_void
+        }
+        "glMultiDrawElementsIndirect" => multi_draw_elements_indirect as *const std::ffi::c_void,
+        "glPatchParameteri" => patch_parameter_i as *const std::ffi::c_void,
+        "glGenVertexArrays" => gen_vertex_arrays as *const std::ffi::c_void,
+        "glBindVertexArray" => bind_vertex_array as *const std::ffi::c_void,
+        "glEnableVertexAttribArray" => enable_vertex_attrib_array as *const std::ffi::c_void,
+        "glDeleteVertexArrays" => delete_vertex_arrays as *const std::ffi::c_void,
+        "glGenBuffers" => gen_buffers as *const std::ffi::c_void,
+        "glBindBuffer" => bind_buffer as *const std::ffi::c_void,
+        "glBufferData" => buffer_data as *const std::ffi::c_void,
+        "glBufferSubData" => buffer_sub_data as *const std::ffi::c_void,
+        "glMapBufferRange" => map_buffer_range as *const std::ffi::c_void,
+        "glUnmapBuffer" => unmap_buffer as *const std::ffi::c_void,
+        "glFlushMappedBufferRange" => flush_mapped_buffer_range as *const std::ffi::c_void,
+        "glGetBufferSubData" => get_buffer_sub_data as *const std::ffi::c_void,
+        "glFenceSync" => fence_sync as *const std::ffi::c_void,
+        "glClientWaitSync" => client_wait_sync as *const std::ffi::c_void,
+        "glDeleteSync" => delete_sync as *const std::ffi::c_void,
+        "glFlush" => flush as *const std::ffi::c_void,
+        "glFinish" => finish as *const std::ffi::c_void,
+        "glBindBufferBase" => bind_buffer_base as *const std::ffi::c_void,
+        "glBindBufferRange" => bind_buffer_range as *const std::ffi::c_void,
+        "glDeleteBuffers" => delete_buffers as *const std::ffi::c_void,
+        "glCreateShader" => create_shader as *const std::ffi::c_void,
+        "glShaderSource" => shader_source as *const std::ffi::c_void,
+        "glCompileShader" => compile_shader as *const std::ffi::c_void,
+        "glDeleteShader" => delete_shader as *const std::ffi::c_void,
+        "glGetShaderiv" => get_shader_iv as *const std::ffi::c_void,
+        "glGetShaderInfoLog" => get_shader_info_log as *const std::ffi::c_void,
+        "glCreateProgram" => create_program as *const std::ffi::c_void,
+        "glAttachShader" => attach_shader as *const std::ffi::c_void,
+        "glLinkProgram" => link_program as *const std::ffi::c_void,
+        "glGetProgramiv" => get_program_iv as *const std::ffi::c_void,
+        "glGetProgramInfoLog" => get_program_info_log as *const std::ffi::c_void,
+        "glDetachShader" => detach_shader as *const std::ffi::c_void,
+        "glUseProgram" => use_program as *const std::ffi::c_void,
+        "glDeleteProgram" => delete_program as *const std::ffi::c_void,
+        "glGetUniformBlockIndex" => get_uniform_block_index as *const std::ffi::c_void,
+        "glUniformBlockBinding" => uniform_block_binding as *const std::ffi::c_void,
+        "glUniform1i" => uniform1_i as *const std::ffi::c_void,
+        "glUniform1f" => uniform1_f as *const std::ffi::c_void,
+        "glUniform1ui" => uniform1_ui as *const std::ffi::c_void,
+        "glUniform1fv" => uniform1_fv as *const std::ffi::c_void,
+        "glUniform2fv" => uniform2_fv as *const std::ffi::c_void,
+        "glUniform3fv" => uniform3_fv as *const std::ffi::c_void,
+        "glUniform4fv" => uniform4_fv as *const std::ffi::c_void,
+        "glUniform1iv" => uniform1_iv as *const std::ffi::c_void,
+        "glUniform2iv" => uniform2_iv as *const std::ffi::c_void,
+        "glUniform3iv" => uniform3_iv as *const std::ffi::c_void,
+        "glUniform4iv" => uniform4_iv as *const std::ffi::c_void,
+        "glUniform1uiv" => uniform1_uiv as *const std::ffi::c_void,
+        "glUniform2uiv" => uniform2_uiv as *const std::ffi::c_void,
+        "glUniform3uiv" => uniform3_uiv as *const std::ffi::c_void,
+        "glUniform4uiv" => uniform4_uiv as *const std::ffi::c_void,
+        "glUniformMatrix3fv" => uniform_matrix3_fv as *const std::ffi::c_void,
+        "glUniformMatrix4fv" => uniform_matrix4_fv as *const std::ffi::c_void,
+        "glProgramUniform1i" => program_uniform1_i as *const std::ffi::c_void,
+        "glProgramUniform1f" => program_uniform1_f as *const std::ffi::c_void,
+        "glProgramUniform1ui" => program_uniform1_ui as *const std::ffi::c_void,
+        "glProgramUniform1fv" => program_uniform1_fv as *const std::ffi::c_void,
+        "glProgramUniform2fv" => program_uniform2_fv as *const std::ffi::c_void,
+        "glProgramUniform3fv" => program_uniform3_fv as *const std::ffi::c_void,
+        "glProgramUniform4fv" => program_uniform4_fv as *const std::ffi::c_void,
+        "glProgramUniform1iv" => program_uniform1_iv as *const std::ffi::c_void,
+        "glProgramUniform2iv" => program_uniform2_iv as *const std::ffi::c_void,
+        "glProgramUniform3iv" => program_uniform3_iv as *const std::ffi::c_void,
+        "glProgramUniform4iv" => program_uniform4_iv as *const std::ffi::c_void,
+        "glProgramUniform1uiv" => program_uniform1_uiv as *const std::ffi::c_void,
+        "glProgramUniform2uiv" => program_uniform2_uiv as *const std::ffi::c_void,
+        "glProgramUniform3uiv" => program_uniform3_uiv as *const std::ffi::c_void,
+        "glProgramUniform4uiv" => program_uniform4_uiv as *const std::ffi::c_void,
+        "glProgramUniformMatrix3fv" => program_uniform_matrix3_fv as *const std::ffi::c_void,
+        "glProgramUniformMatrix4fv" => program_uniform_matrix4_fv as *const std::ffi::c_void,
+        "glGenTextures" => gen_textures as *const std::ffi::c_void,
+        "glActiveTexture" => active_texture as *const std::ffi::c_void,
+        "glBindTexture" => bind_texture as *const std::ffi::c_void,
+        "glTexImage2D" => tex_image_2d as *const std::ffi::c_void,
+        "glTexImage3D" => tex_image_3d as *const std::ffi::c_void,
+        "glCompressedTexImage2D" => compressed_tex_image_2d as *const std::ffi::c_void,
+        "glTexStorage2D" => tex_storage_2d as *const std::ffi::c_void,
+        "glTexStorage3D" => tex_storage_3d as *const std::ffi::c_void,
+        "glTexSubImage2D" => tex_sub_image_2d as *const std::ffi::c_void,
+        "glTexSubImage3D" => tex_sub_image_3d as *const std::ffi::c_void,
+        "glGenerateMipmap" => generate_mipmap as *const std::ffi::c_void,
+        "glPixelStorei" => pixel_store_i as *const std::ffi::c_void,
+        "glDeleteTextures" => delete_textures as *const std::ffi::c_void,
+        "glGenQueries" => gen_queries as *const std::ffi::c_void,
+        "glBeginQuery" => begin_query as *const std::ffi::c_void,
+        "glEndQuery" => end_query as *const std::ffi::c_void,
+        "glQueryCounter" => query_counter as *const std::ffi::c_void,
+        "glGetQueryObjectiv" => get_query_object_iv as *const std::ffi::c_void,
+        "glGetQueryObjectuiv" => get_query_object_uiv as *const std::ffi::c_void,
+        "glGetQueryObjecti64v" => get_query_object_i64v as *const std::ffi::c_void,
+        "glGetQueryObjectui64v" => get_query_object_ui64v as *const std::ffi::c_void,
+        "glDeleteQueries" => delete_queries as *const std::ffi::c_void,
+        "glGenSamplers" => gen_samplers as *const std::ffi::c_void,
+        "glBindSampler" => bind_sampler as *const std::ffi::c_void,
+        "glSamplerParameteri" => sampler_parameter_i as *const std::ffi::c_void,
+        "glSamplerParameterf" => sampler_parameter_f as *const std::ffi::c_void,
+        "glSamplerParameterfv" => sampler_parameter_fv as *const std::ffi::c_void,
+        "glDeleteSamplers" => delete_samplers as *const std::ffi::c_void,
+        "glGenFramebuffers" => gen_framebuffers as *const std::ffi::c_void,
+        "glBindFramebuffer" => bind_framebuffer as *const std::ffi::c_void,
+        "glFramebufferTexture2D" => framebuffer_texture_2d as *const std::ffi::c_void,
+        "glFramebufferRenderbuffer" => framebuffer_renderbuffer as *const std::ffi::c_void,
+        "glCheckFramebufferStatus" => check_framebuffer_status as *const std::ffi::c_void,
+        "glDrawBuffers" => draw_buffers as *const std::ffi::c_void,
+        "glReadBuffer" => read_buffer as *const std::ffi::c_void,
+        "glReadPixels" => read_pixels as *const std::ffi::c_void,
+        "glBlitFramebuffer" => blit_framebuffer as *const std::ffi::c_void,
+        "glDeleteFramebuffers" => delete_framebuffers as *const std::ffi::c_void,
+        "glGenRenderbuffers" => gen_renderbuffers as *const std::ffi::c_void,
+        "glBindRenderbuffer" => bind_renderbuffer as *const std::ffi::c_void,
+        "glRenderbufferStorage" => renderbuffer_storage as *const std::ffi::c_void,
+        "glDeleteRenderbuffers" => delete_renderbuffers as *const std::ffi::c_void,
+        _ => std::ptr::null(),
+    }
+}
@@ -0,0 +1,37 @@
+// Generated by `cargo run -p gl_generator` from `registry/gl.xml` and `registry/gl44.txt`.
+// Do not edit by hand, change the allow-list and regenerate instead.
+
+use super::{call, Arg, Return};
+use crate::types::{GLbitField, GLenum, GLsizeiptr};
+
+unsafe extern "system" fn buffer_storage(
+    target: GLenum,
+    size: GLsizeiptr,
+    data: *const std::ffi::c_void,
+    flags: GLbitField,
+) {
+    unsafe {
+        call(
+            "glBufferStorage",
+            &[
+                Arg::from(target),
+                Arg::from(size),
+                Arg::from(data),
+                Arg::from(flags),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn get_error() -> GLenum {
+    unsafe { Return::from_name(call("glGetError", &[])) }
+}
+
+#[allow(clippy::too_many_lines)]
+pub(super) fn lookup(symbol: &str) -> *const std::ffi::c_void {
+    match symbol {
+        "glBufferStorage" => buffer_storage as *const std::ffi::c_void,
+        "glGetError" => get_error as *const std::ffi::c_void,
+        _ => std::ptr::null(),
+    }
+}
@@ -0,0 +1,342 @@
+// Generated by `cargo run -p gl_generator` from `registry/gl.xml` and `registry/gl45.txt`.
+// Do not edit by hand, change the allow-list and regenerate instead.
+
+use super::{call, Arg, Return};
+use crate::types::{GLbitField, GLboolean, GLenum, GLint, GLintptr, GLsizei, GLsizeiptr, GLuint};
+
+unsafe extern "system" fn create_buffers(n: GLsizei, buffers: *mut GLuint) {
+    unsafe {
+        call("glCreateBuffers", &[Arg::from(n), Arg::from(buffers)]);
+    }
+}
+
+unsafe extern "system" fn named_buffer_storage(
+    buffer: GLuint,
+    size: GLsizeiptr,
+    data: *const std::ffi::c_void,
+    flags: GLbitField,
+) {
+    unsafe {
+        call(
+            "glNamedBufferStorage",
+            &[
+                Arg::from(buffer),
+                Arg::from(size),
+                Arg::from(data),
+                Arg::from(flags),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn named_buffer_data(
+    buffer: GLuint,
+    size: GLsizeiptr,
+    data: *const std::ffi::c_void,
+    usage: GLenum,
+) {
+    unsafe {
+        call(
+            "glNamedBufferData",
+            &[
+                Arg::from(buffer),
+                Arg::from(size),
+                Arg::from(data),
+                Arg::from(usage),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn named_buffer_sub_data(
+    buffer: GLuint,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    data: *const std::ffi::c_void,
+) {
+    unsafe {
+        call(
+            "glNamedBufferSubData",
+            &[
+                Arg::from(buffer),
+                Arg::from(offset),
+                Arg::from(size),
+                Arg::from(data),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn create_textures(target: GLenum, n: GLsizei, textures: *mut GLuint) {
+    unsafe {
+        call(
+            "glCreateTextures",
+            &[Arg::from(target), Arg::from(n), Arg::from(textures)],
+        );
+    }
+}
+
+unsafe extern "system" fn texture_storage_2d(
+    texture: GLuint,
+    levels: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+) {
+    unsafe {
+        call(
+            "glTextureStorage2D",
+            &[
+                Arg::from(texture),
+                Arg::from(levels),
+                Arg::from(internalformat),
+                Arg::from(width),
+                Arg::from(height),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn texture_storage_3d(
+    texture: GLuint,
+    levels: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+) {
+    unsafe {
+        call(
+            "glTextureStorage3D",
+            &[
+                Arg::from(texture),
+                Arg::from(levels),
+                Arg::from(internalformat),
+                Arg::from(width),
+                Arg::from(height),
+                Arg::from(depth),
+            ],
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::similar_names)]
+unsafe extern "system" fn texture_sub_image_2d(
+    texture: GLuint,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: GLenum,
+    kind: GLenum,
+    pixels: *const std::ffi::c_void,
+) {
+    unsafe {
+        call(
+            "glTextureSubImage2D",
+            &[
+                Arg::from(texture),
+                Arg::from(level),
+                Arg::from(xoffset),
+                Arg::from(yoffset),
+                Arg::from(width),
+                Arg::from(height),
+                Arg::from(format),
+                Arg::from(kind),
+                Arg::from(pixels),
+            ],
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::similar_names)]
+unsafe extern "system" fn texture_sub_image_3d(
+    texture: GLuint,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    zoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    format: GLenum,
+    kind: GLenum,
+    pixels: *const std::ffi::c_void,
+) {
+    unsafe {
+        call(
+            "glTextureSubImage3D",
+            &[
+                Arg::from(texture),
+                Arg::from(level),
+                Arg::from(xoffset),
+                Arg::from(yoffset),
+                Arg::from(zoffset),
+                Arg::from(width),
+                Arg::from(height),
+                Arg::from(depth),
+                Arg::from(format),
+                Arg::from(kind),
+                Arg::from(pixels),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn generate_texture_mipmap(texture: GLuint) {
+    unsafe {
+        call("glGenerateTextureMipmap", &[Arg::from(texture)]);
+    }
+}
+
+unsafe extern "system" fn bind_texture_unit(unit: GLuint, texture: GLuint) {
+    unsafe {
+        call("glBindTextureUnit", &[Arg::from(unit), Arg::from(texture)]);
+    }
+}
+
+unsafe extern "system" fn create_samplers(n: GLsizei, samplers: *mut GLuint) {
+    unsafe {
+        call("glCreateSamplers", &[Arg::from(n), Arg::from(samplers)]);
+    }
+}
+
+unsafe extern "system" fn create_vertex_arrays(n: GLsizei, arrays: *mut GLuint) {
+    unsafe {
+        call("glCreateVertexArrays", &[Arg::from(n), Arg::from(arrays)]);
+    }
+}
+
+unsafe extern "system" fn enable_vertex_array_attrib(vaobj: GLuint, index: GLuint) {
+    unsafe {
+        call(
+            "glEnableVertexArrayAttrib",
+            &[Arg::from(vaobj), Arg::from(index)],
+        );
+    }
+}
+
+unsafe extern "system" fn vertex_array_attrib_format(
+    vaobj: GLuint,
+    attribindex: GLuint,
+    size: GLint,
+    kind: GLenum,
+    normalized: GLboolean,
+    relativeoffset: GLuint,
+) {
+    unsafe {
+        call(
+            "glVertexArrayAttribFormat",
+            &[
+                Arg::from(vaobj),
+                Arg::from(attribindex),
+                Arg::from(size),
+                Arg::from(kind),
+                Arg::from(normalized),
+                Arg::from(relativeoffset),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn vertex_array_attrib_i_format(
+    vaobj: GLuint,
+    attribindex: GLuint,
+    size: GLint,
+    kind: GLenum,
+    relativeoffset: GLuint,
+) {
+    unsafe {
+        call(
+            "glVertexArrayAttribIFormat",
+            &[
+                Arg::from(vaobj),
+                Arg::from(attribindex),
+                Arg::from(size),
+                Arg::from(kind),
+                Arg::from(relativeoffset),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn vertex_array_attrib_binding(
+    vaobj: GLuint,
+    attribindex: GLuint,
+    bindingindex: GLuint,
+) {
+    unsafe {
+        call(
+            "glVertexArrayAttribBinding",
+            &[
+                Arg::from(vaobj),
+                Arg::from(attribindex),
+                Arg::from(bindingindex),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn vertex_array_vertex_buffer(
+    vaobj: GLuint,
+    bindingindex: GLuint,
+    buffer: GLuint,
+    offset: GLintptr,
+    stride: GLsizei,
+) {
+    unsafe {
+        call(
+            "glVertexArrayVertexBuffer",
+            &[
+                Arg::from(vaobj),
+                Arg::from(bindingindex),
+                Arg::from(buffer),
+                Arg::from(offset),
+                Arg::from(stride),
+            ],
+        );
+    }
+}
+
+unsafe extern "system" fn vertex_array_element_buffer(vaobj: GLuint, buffer: GLuint) {
+    unsafe {
+        call(
+            "glVertexArrayElementBuffer",
+            &[Arg::from(vaobj), Arg::from(buffer)],
+        );
+    }
+}
+
+unsafe extern "system" fn get_error() -> GLenum {
+    unsafe { Return::from_name(call("glGetError", &[])) }
+}
+
+#[allow(clippy::too_many_lines)]
+pub(super) fn lookup(symbol: &str) -> *const std::ffi::c_void {
+    match symbol {
+        "glCreateBuffers" => create_buffers as *const std::ffi::c_void,
+        "glNamedBufferStorage" => named_buffer_storage as *const std::ffi::c_void,
+        "glNamedBufferData" => named_buffer_data as *const std::ffi::c_void,
+        "glNamedBufferSubData" => named_buffer_sub_data as *const std::ffi::c_void,
+        "glCreateTextures" => create_textures as *const std::ffi::c_void,
+        "glTextureStorage2D" => texture_storage_2d as *const std::ffi::c_void,
+        "glTextureStorage3D" => texture_storage_3d as *const std::ffi::c_void,
+        "glTextureSubImage2D" => texture_sub_image_2d as *const std::ffi::c_void,
+        "glTextureSubImage3D" => texture_sub_image_3d as *const std::ffi::c_void,
+        "glGenerateTextureMipmap" => generate_texture_mipmap as *const std::ffi::c_void,
+        "glBindTextureUnit" => bind_texture_unit as *const std::ffi::c_void,
+        "glCreateSamplers" => create_samplers as *const std::ffi::c_void,
+        "glCreateVertexArrays" => create_vertex_arrays as *const std::ffi::c_void,
+        "glEnableVertexArrayAttrib" => enable_vertex_array_attrib as *const std::ffi::c_void,
+        "glVertexArrayAttribFormat" => vertex_array_attrib_format as *const std::ffi::c_void,
+        "glVertexArrayAttribIFormat" => vertex_array_attrib_i_format as *const std::ffi::c_void,
+        "glVertexArrayAttribBinding" => vertex_array_attrib_binding as *const std::ffi::c_void,
+        "glVertexArrayVertexBuffer" => vertex_array_vertex_buffer as *const std::ffi::c_void,
+        "glVertexArrayElementBuffer" => vertex_array_element_buffer as *const std::ffi::c_void,
+        "glGetError" => get_error as *const std::ffi::c_void,
+        _ => std::ptr::null(),
+    }
+}
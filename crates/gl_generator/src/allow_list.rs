@@ -0,0 +1,325 @@
+//! Parser for the curated allow-lists in `crates/gl_bindings/registry`.
+//!
+//! ```text
+//! # comments start with a hash and are ignored
+//! // comments with two slashes are copied into the generated code
+//!
+//! enum Capability: GLenum from EnableCap
+//!     DEPTH = GL_DEPTH_TEST
+//!     GL_CULL_FACE
+//!
+//! bitmask ClearMask: GLbitField from ClearBufferMask
+//!     COLOR = GL_COLOR_BUFFER_BIT
+//!
+//! fn glCreateShader
+//!     /// Documentation for the wrapper
+//!     type: ShaderKind
+//!     -> Shader
+//!
+//! fn glGetStringi as get_stringi
+//!
+//! use TextureFormat from PixelFormat
+//! ```
+//!
+//! Enum members without an explicit name use the registry name minus the `GL_` prefix.
+//! `use` declares a type that is defined outside of the generated file, so the bindings of
+//! one version can share the enums of another.
+//! Parameters of commands are typed by their registry group if an enum was declared
+//! `from` that group; everything else can be overridden with `param: Type`. Pointer levels are
+//! always taken from the registry, so overriding `buffers: Buffer` of `glGenBuffers` results
+//! in `*mut Buffer`.
+
+pub struct AllowList {
+    pub items: Vec<Item>,
+}
+
+pub enum Item {
+    Comment(String),
+    Enum(EnumType),
+    Command(CommandEntry),
+    Use { name: String, group: String },
+}
+
+pub struct EnumType {
+    pub name: String,
+    pub base: String,
+    pub group: Option<String>,
+    pub bitmask: bool,
+    pub members: Vec<Member>,
+}
+
+pub enum Member {
+    Constant { name: String, gl_name: String },
+    Comment(String),
+}
+
+pub struct CommandEntry {
+    pub gl_name: String,
+    pub rust_name: Option<String>,
+    pub doc: Vec<String>,
+    pub params: Vec<(String, String)>,
+    pub result: Option<String>,
+}
+
+impl AllowList {
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut items = Vec::new();
+
+        for (number, line) in source.lines().enumerate() {
+            let error = |message: &str| format!("line {}: {message}: {line}", number + 1);
+
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let indented = line.starts_with(char::is_whitespace);
+
+            if !indented {
+                let item = if let Some(comment) = trimmed.strip_prefix("//") {
+                    Item::Comment(comment.trim().to_string())
+                } else if let Some(rest) = trimmed.strip_prefix("enum ") {
+                    Item::Enum(parse_enum(rest, false).ok_or_else(|| error("invalid enum"))?)
+                } else if let Some(rest) = trimmed.strip_prefix("bitmask ") {
+                    Item::Enum(parse_enum(rest, true).ok_or_else(|| error("invalid bitmask"))?)
+                } else if let Some(rest) = trimmed.strip_prefix("fn ") {
+                    Item::Command(parse_command(rest).ok_or_else(|| error("invalid fn"))?)
+                } else if let Some(rest) = trimmed.strip_prefix("use ") {
+                    parse_use(rest).ok_or_else(|| error("invalid use"))?
+                } else {
+                    return Err(error("unknown item"));
+                };
+
+                items.push(item);
+                continue;
+            }
+
+            match items.last_mut() {
+                Some(Item::Enum(e)) => {
+                    let member = if let Some(comment) = trimmed.strip_prefix("//") {
+                        Member::Comment(comment.trim().to_string())
+                    } else if let Some((name, gl_name)) = trimmed.split_once('=') {
+                        Member::Constant {
+                            name: name.trim().to_string(),
+                            gl_name: gl_name.trim().to_string(),
+                        }
+                    } else {
+                        let name = trimmed
+                            .strip_prefix("GL_")
+                            .ok_or_else(|| error("enum member needs a GL_ name"))?;
+
+                        Member::Constant {
+                            name: name.to_string(),
+                            gl_name: trimmed.to_string(),
+                        }
+                    };
+                    e.members.push(member);
+                }
+                Some(Item::Command(command)) => {
+                    if let Some(doc) = trimmed.strip_prefix("///") {
+                        command.doc.push(doc.trim_end().to_string());
+                    } else if let Some(result) = trimmed.strip_prefix("->") {
+                        command.result = Some(result.trim().to_string());
+                    } else if let Some((param, ty)) = trimmed.split_once(':') {
+                        command
+                            .params
+                            .push((param.trim().to_string(), ty.trim().to_string()));
+                    } else {
+                        return Err(error("expected doc, param override or result"));
+                    }
+                }
+                _ => return Err(error("indented line outside of an item")),
+            }
+        }
+
+        Ok(Self { items })
+    }
+}
+
+/// `Name: Base [from Group]`
+fn parse_enum(rest: &str, bitmask: bool) -> Option<EnumType> {
+    let (name, rest) = rest.split_once(':')?;
+    let mut words = rest.split_whitespace();
+    let base = words.next()?;
+
+    let group = match (words.next(), words.next()) {
+        (Some("from"), Some(group)) => Some(group.to_string()),
+        (None, None) => None,
+        _ => return None,
+    };
+
+    Some(EnumType {
+        name: name.trim().to_string(),
+        base: base.to_string(),
+        group,
+        bitmask,
+        members: Vec::new(),
+    })
+}
+
+/// `Name from Group`
+fn parse_use(rest: &str) -> Option<Item> {
+    match rest.split_whitespace().collect::<Vec<_>>()[..] {
+        [name, "from", group] => Some(Item::Use {
+            name: name.to_string(),
+            group: group.to_string(),
+        }),
+        _ => None,
+    }
+}
+
+/// `glName [as rust_name]`
+fn parse_command(rest: &str) -> Option<CommandEntry> {
+    let mut words = rest.split_whitespace();
+    let gl_name = words.next()?;
+
+    let rust_name = match (words.next(), words.next()) {
+        (Some("as"), Some(name)) => Some(name.to_string()),
+        (None, None) => None,
+        _ => return None,
+    };
+
+    Some(CommandEntry {
+        gl_name: gl_name.to_string(),
+        rust_name,
+        doc: Vec::new(),
+        params: Vec::new(),
+        result: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AllowList, Item, Member};
+
+    fn parse_error(source: &str) -> String {
+        match AllowList::parse(source) {
+            Ok(_) => panic!("{source:?} should not parse"),
+            Err(error) => error,
+        }
+    }
+
+    #[test]
+    fn enums_keep_their_group_and_members() {
+        let list = AllowList::parse(
+            "# ignored
+enum Capability: GLenum from EnableCap
+    DEPTH = GL_DEPTH_TEST
+    // culling
+    GL_CULL_FACE
+bitmask ClearMask: GLbitField
+    COLOR = GL_COLOR_BUFFER_BIT
+",
+        )
+        .unwrap();
+
+        let [Item::Enum(capability), Item::Enum(clear)] = &list.items[..] else {
+            panic!("expected two enums");
+        };
+
+        assert_eq!(capability.name, "Capability");
+        assert_eq!(capability.base, "GLenum");
+        assert_eq!(capability.group.as_deref(), Some("EnableCap"));
+        assert!(!capability.bitmask);
+        assert!(matches!(
+            &capability.members[..],
+            [
+                Member::Constant { name: depth, gl_name: depth_gl },
+                Member::Comment(comment),
+                Member::Constant { name: cull, gl_name: cull_gl },
+            ] if depth == "DEPTH"
+                && depth_gl == "GL_DEPTH_TEST"
+                && comment == "culling"
+                && cull == "CULL_FACE"
+                && cull_gl == "GL_CULL_FACE"
+        ));
+
+        assert_eq!(clear.group, None);
+        assert!(clear.bitmask);
+    }
+
+    #[test]
+    fn commands_take_renames_docs_params_and_results() {
+        let list = AllowList::parse(
+            "// shaders
+fn glCreateShader
+    /// Creates a shader
+    type: ShaderKind
+    -> Shader
+fn glGetStringi as get_stringi
+",
+        )
+        .unwrap();
+
+        let [Item::Comment(comment), Item::Command(create), Item::Command(get)] = &list.items[..]
+        else {
+            panic!("expected a comment and two commands");
+        };
+
+        assert_eq!(comment, "shaders");
+        assert_eq!(create.gl_name, "glCreateShader");
+        assert_eq!(create.rust_name, None);
+        assert_eq!(create.doc, [" Creates a shader"]);
+        assert_eq!(
+            create.params,
+            [("type".to_string(), "ShaderKind".to_string())]
+        );
+        assert_eq!(create.result.as_deref(), Some("Shader"));
+
+        assert_eq!(get.gl_name, "glGetStringi");
+        assert_eq!(get.rust_name.as_deref(), Some("get_stringi"));
+        assert!(get.params.is_empty());
+        assert_eq!(get.result, None);
+    }
+
+    #[test]
+    fn uses_name_a_type_and_group() {
+        let list = AllowList::parse("use TextureFormat from PixelFormat").unwrap();
+
+        assert!(matches!(
+            &list.items[..],
+            [Item::Use { name, group }] if name == "TextureFormat" && group == "PixelFormat"
+        ));
+    }
+
+    #[test]
+    fn malformed_lines_are_rejected_with_their_number() {
+        for (source, expected) in [
+            (
+                "    GL_DEPTH_TEST",
+                "line 1: indented line outside of an item",
+            ),
+            (
+                "// comment\n    GL_DEPTH_TEST",
+                "line 2: indented line outside of an item",
+            ),
+            (
+                "enum Capability: GLenum\n    DEPTH_TEST",
+                "line 2: enum member needs a GL_ name",
+            ),
+            (
+                "fn glClear\n    mask",
+                "line 2: expected doc, param override or result",
+            ),
+            ("enum Capability", "line 1: invalid enum"),
+            (
+                "enum Capability: GLenum in EnableCap",
+                "line 1: invalid enum",
+            ),
+            (
+                "bitmask ClearMask: GLbitField from",
+                "line 1: invalid bitmask",
+            ),
+            ("fn glGetStringi get_stringi", "line 1: invalid fn"),
+            ("fn glGetStringi as", "line 1: invalid fn"),
+            ("use TextureFormat", "line 1: invalid use"),
+            ("struct Foo", "line 1: unknown item"),
+        ] {
+            let error = parse_error(source);
+            assert!(
+                error.starts_with(expected),
+                "{source:?} failed with {error:?} instead of {expected:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,901 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Write,
+};
+
+use crate::{
+    allow_list::{AllowList, CommandEntry, EnumType, Item, Member},
+    registry::{Declaration, Registry},
+};
+
+const SAFETY_DOC: &str = "
+    /// # Safety
+    /// The caller has to make sure that the OpenGL context that loaded the functions
+    /// is made current for the thread calling the functions.
+    ///
+    /// Also, unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers. That means if the context doesn't support certain
+    /// OpenGL functions, there is no way to figure that out during load time.";
+
+const LOADER_DOC: &str = "
+    /// Loads all function pointers using a context function loader,
+    /// also called `ProcAddress`. Works with GLFW, SDL2 any pretty much any OpenGL context.
+    ///
+    /// Functions the loader returns a null pointer for are listed by `missing_functions` and
+    /// replaced with stand-ins. Calling one panics inside an `extern \"system\"` function, which
+    /// aborts the whole process, see `try_with_loader` to fail at load time instead.
+    ///
+    /// # Safety
+    /// Unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers.
+    ///";
+
+/// The methods next to `with_loader`.
+const LOADER_METHODS: &str = r"
+    /// Loads all function pointers like `with_loader`, but fails with the functions the loader
+    /// returned a null pointer for instead of putting in stand-ins that abort when called.
+    ///
+    /// # Safety
+    /// See `with_loader`.
+    pub unsafe fn try_with_loader(
+        loader: &impl Fn(&str) -> *const std::ffi::c_void,
+    ) -> Result<Self, crate::Error> {
+        let api = unsafe { Self::with_loader(loader) };
+        if api.missing.is_empty() {
+            Ok(api)
+        } else {
+            Err(crate::Error::FailedToLoad(api.missing))
+        }
+    }
+
+    /// The functions the context doesn't provide, calling them aborts the process.
+    #[must_use]
+    pub fn missing_functions(&self) -> &[&'static str] {
+        &self.missing
+    }
+";
+
+const GET_ERROR: &str = "glGetError";
+
+/// What gets generated for one allow-list.
+pub struct Target<'a> {
+    pub allow_list_name: &'a str,
+    pub api_doc: &'a str,
+}
+
+/// A command from the allow-list with all types resolved.
+struct Resolved<'a> {
+    entry: &'a CommandEntry,
+    name: String,
+    params: Vec<(String, String)>,
+    result: Option<String>,
+}
+
+pub fn generate(
+    registry: &Registry,
+    allow_list: &AllowList,
+    target: &Target,
+) -> Result<String, String> {
+    let mut group_types = HashMap::new();
+
+    for item in &allow_list.items {
+        let (Item::Enum(EnumType {
+            name,
+            group: Some(group),
+            ..
+        })
+        | Item::Use { name, group }) = item
+        else {
+            continue;
+        };
+
+        if let Some(previous) = group_types.insert(group.as_str(), name.as_str()) {
+            return Err(format!("{previous} and {name} both claim group {group}"));
+        }
+    }
+
+    let mut out = header(target);
+
+    for item in &allow_list.items {
+        if let Item::Enum(e) = item {
+            emit_enum(&mut out, registry, e)?;
+        }
+    }
+
+    let commands = allow_list
+        .items
+        .iter()
+        .map(|item| match item {
+            Item::Command(entry) => resolve(registry, &group_types, entry).map(Some),
+            _ => Ok(None),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // struct
+    writeln!(out, "\n/// {}", target.api_doc).unwrap();
+    writeln!(out, "#[allow(clippy::struct_field_names)]").unwrap();
+    writeln!(out, "pub struct Api {{").unwrap();
+    for (item, command) in allow_list.items.iter().zip(&commands) {
+        match (item, command) {
+            (Item::Comment(comment), _) => writeln!(out, "// {comment}").unwrap(),
+            (_, Some(command)) => writeln!(
+                out,
+                "{}_ptr: unsafe extern \"system\" fn({}){},",
+                command.name,
+                signature(&command.params),
+                result(command.result.as_ref())
+            )
+            .unwrap(),
+            _ => (),
+        }
+    }
+    writeln!(out, "missing: Vec<&'static str>,").unwrap();
+    writeln!(out, "}}\n").unwrap();
+
+    // loader
+    writeln!(out, "impl Api {{{LOADER_DOC}").unwrap();
+    writeln!(out, "#[allow(clippy::too_many_lines)]").unwrap();
+    writeln!(
+        out,
+        "pub unsafe fn with_loader(loader: &impl Fn(&str) -> *const std::ffi::c_void) -> Self {{"
+    )
+    .unwrap();
+    writeln!(out, "let mut missing = Vec::new();").unwrap();
+    writeln!(out, "Self {{").unwrap();
+    for (item, command) in allow_list.items.iter().zip(&commands) {
+        match (item, command) {
+            (Item::Comment(comment), _) => writeln!(out, "// {comment}").unwrap(),
+            (_, Some(command)) => writeln!(
+                out,
+                "{0}_ptr: loader.load_or(\"{1}\", &mut missing, missing_{0}),",
+                command.name, command.entry.gl_name
+            )
+            .unwrap(),
+            _ => (),
+        }
+    }
+    writeln!(out, "missing,\n}}\n}}").unwrap();
+
+    out.push_str(LOADER_METHODS);
+
+    // wrappers, checked with `glGetError` if the allow-list has it
+    let checked = commands
+        .iter()
+        .flatten()
+        .any(|command| command.entry.gl_name == GET_ERROR);
+    for (item, command) in allow_list.items.iter().zip(&commands) {
+        match (item, command) {
+            (Item::Comment(comment), _) => writeln!(out, "\n// {comment}").unwrap(),
+            (_, Some(command)) => emit_wrapper(&mut out, command, checked),
+            _ => (),
+        }
+    }
+    writeln!(out, "}}").unwrap();
+
+    // stand-ins for missing functions
+    writeln!(out).unwrap();
+    for command in commands.iter().flatten() {
+        emit_missing(&mut out, command);
+    }
+
+    Ok(out)
+}
+
+/// Recording fakes of all commands of the allow-list for `gl_bindings::mock`. They use the
+/// plain registry types, so the bodies don't depend on the curated enums.
+pub fn generate_mock(
+    registry: &Registry,
+    allow_list: &AllowList,
+    target: &Target,
+) -> Result<String, String> {
+    let mut types = BTreeSet::new();
+    let mut functions = String::new();
+    let mut lookup = String::new();
+    let mut returns_values = false;
+
+    for item in &allow_list.items {
+        let Item::Command(entry) = item else {
+            continue;
+        };
+
+        let gl_name = &entry.gl_name;
+        let command = registry
+            .commands
+            .get(gl_name)
+            .ok_or_else(|| format!("unknown command {gl_name}"))?;
+
+        let mut raw = |declaration: &Declaration| {
+            let base = raw_type(&declaration.base)
+                .ok_or_else(|| format!("{gl_name}: unknown type {}", declaration.base))?;
+            if base.starts_with("GL") {
+                types.insert(base.clone());
+            }
+            Ok::<_, String>(pointer(declaration, base))
+        };
+
+        let params = command
+            .params
+            .iter()
+            .map(|param| Ok((param_name(&param.name), raw(&param.declaration)?)))
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let returns = if command.result.is_void() {
+            None
+        } else {
+            Some(raw(&command.result)?)
+        };
+
+        let name = entry
+            .rust_name
+            .clone()
+            .unwrap_or_else(|| command_name(gl_name));
+
+        let arguments = params
+            .iter()
+            .map(|(name, _)| format!("Arg::from({name})"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        writeln!(functions).unwrap();
+        if params.len() > 7 {
+            writeln!(functions, "#[allow(clippy::too_many_arguments)]").unwrap();
+        }
+        if has_similar_names(&params) {
+            writeln!(functions, "#[allow(clippy::similar_names)]").unwrap();
+        }
+        writeln!(
+            functions,
+            "unsafe extern \"system\" fn {name}({}){} {{",
+            signature(&params),
+            result(returns.as_ref())
+        )
+        .unwrap();
+        if returns.is_some() {
+            returns_values = true;
+            writeln!(
+                functions,
+                "unsafe {{ Return::from_name(call(\"{gl_name}\", &[{arguments}])) }}"
+            )
+            .unwrap();
+        } else {
+            writeln!(
+                functions,
+                "unsafe {{ call(\"{gl_name}\", &[{arguments}]); }}"
+            )
+            .unwrap();
+        }
+        writeln!(functions, "}}").unwrap();
+
+        writeln!(
+            lookup,
+            "\"{gl_name}\" => {name} as *const std::ffi::c_void,"
+        )
+        .unwrap();
+    }
+
+    let mut out = header(target);
+    let imports = if returns_values {
+        "call, Arg, Return"
+    } else {
+        "call, Arg"
+    };
+    writeln!(out, "\nuse super::{{{imports}}};").unwrap();
+    writeln!(
+        out,
+        "use crate::types::{{{}}};",
+        types.into_iter().collect::<Vec<_>>().join(", ")
+    )
+    .unwrap();
+    out.push_str(&functions);
+    writeln!(
+        out,
+        "\n#[allow(clippy::too_many_lines)]\npub(super) fn lookup(symbol: &str) -> *const std::ffi::c_void {{"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "match symbol {{\n{lookup}_ => std::ptr::null(),\n}}\n}}"
+    )
+    .unwrap();
+
+    Ok(out)
+}
+
+fn header(target: &Target) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// Generated by `cargo run -p gl_generator` from `registry/gl.xml` and `registry/{}`.",
+        target.allow_list_name
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "// Do not edit by hand, change the allow-list and regenerate instead."
+    )
+    .unwrap();
+    out
+}
+
+fn emit_enum(out: &mut String, registry: &Registry, e: &EnumType) -> Result<(), String> {
+    let group = match &e.group {
+        Some(group) => Some(
+            registry
+                .groups
+                .get(group)
+                .ok_or_else(|| format!("{}: unknown group {group}", e.name))?,
+        ),
+        None => None,
+    };
+
+    writeln!(out, "\n#[repr(transparent)]").unwrap();
+    writeln!(out, "#[derive(Copy, Clone, Eq, PartialEq, Debug)]").unwrap();
+    writeln!(out, "pub struct {}({});\n", e.name, e.base).unwrap();
+    writeln!(out, "impl {} {{", e.name).unwrap();
+
+    let mut constants: Vec<(&str, String)> = Vec::with_capacity(e.members.len());
+
+    for member in &e.members {
+        match member {
+            Member::Comment(comment) => writeln!(out, "// {comment}").unwrap(),
+            Member::Constant { name, gl_name } => {
+                let value = registry
+                    .enums
+                    .get(gl_name)
+                    .ok_or_else(|| format!("{}: unknown enum {gl_name}", e.name))?;
+
+                // the groups of the registry are not complete yet, so only warn
+                if group.is_some_and(|g| !g.contains(gl_name)) {
+                    eprintln!(
+                        "note: {gl_name} is not listed in group {} of {}",
+                        e.group.as_deref().unwrap_or_default(),
+                        e.name
+                    );
+                }
+
+                let value = trim_value(value);
+                writeln!(out, "pub const {name}: Self = Self({value});").unwrap();
+                constants.push((name, value));
+            }
+        }
+    }
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(out, "impl std::fmt::Display for {} {{", e.name).unwrap();
+    writeln!(
+        out,
+        "fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{"
+    )
+    .unwrap();
+
+    if e.bitmask {
+        let flags = constants
+            .iter()
+            .filter(|(_, value)| parse_value(value) != Some(0))
+            .map(|(name, _)| format!("(Self::{name}, \"{name}\")"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        writeln!(out, "let mut separator = \"\";").unwrap();
+        writeln!(out, "for (flag, name) in [{flags}] {{").unwrap();
+        writeln!(out, "if self.0 & flag.0 == flag.0 {{").unwrap();
+        writeln!(out, r#"write!(f, "{{separator}}{{name}}")?;"#).unwrap();
+        writeln!(out, "separator = \" | \";\n}}\n}}").unwrap();
+        writeln!(out, "if separator.is_empty() {{\nwrite!(f, \"NONE\")?;\n}}").unwrap();
+        writeln!(out, "Ok(())").unwrap();
+    } else {
+        writeln!(out, "match *self {{").unwrap();
+
+        let mut seen = Vec::with_capacity(constants.len());
+        for (name, value) in &constants {
+            // aliases would be unreachable patterns
+            if seen.contains(&value) {
+                continue;
+            }
+            seen.push(value);
+            writeln!(out, "Self::{name} => write!(f, \"{name}\"),").unwrap();
+        }
+        writeln!(out, r#"_ => write!(f, "UNKNOWN({{:#X}})", self.0),"#).unwrap();
+        writeln!(out, "}}").unwrap();
+    }
+    writeln!(out, "}}\n}}").unwrap();
+
+    if e.bitmask {
+        let name = &e.name;
+        writeln!(
+            out,
+            "
+impl std::ops::BitOr for {name} {{
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {{
+        Self(self.0 | rhs.0)
+    }}
+}}
+
+impl std::ops::BitOrAssign for {name} {{
+    fn bitor_assign(&mut self, rhs: Self) {{
+        self.0 |= rhs.0;
+    }}
+}}"
+        )
+        .unwrap();
+    }
+
+    Ok(())
+}
+
+fn emit_wrapper(out: &mut String, command: &Resolved, checked: bool) {
+    let checked = checked && command.entry.gl_name != GET_ERROR;
+
+    writeln!(out).unwrap();
+    for line in &command.entry.doc {
+        writeln!(out, "///{line}").unwrap();
+    }
+    if !command.entry.doc.is_empty() {
+        writeln!(out, "///").unwrap();
+    }
+    writeln!(out, "{}", SAFETY_DOC.trim_start()).unwrap();
+    writeln!(out, "#[inline]").unwrap();
+
+    if checked {
+        writeln!(out, "#[cfg_attr(feature = \"checked\", track_caller)]").unwrap();
+    }
+
+    if command.result.is_some() {
+        writeln!(out, "#[must_use]").unwrap();
+    }
+
+    // clippy counts `self` as well
+    if command.params.len() + 1 > 7 {
+        writeln!(out, "#[allow(clippy::too_many_arguments)]").unwrap();
+    }
+
+    if has_similar_names(&command.params) {
+        writeln!(out, "#[allow(clippy::similar_names)]").unwrap();
+    }
+
+    let arguments = command
+        .params
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    writeln!(
+        out,
+        "pub unsafe fn {}(&self, {}){} {{",
+        command.name,
+        signature(&command.params),
+        result(command.result.as_ref())
+    )
+    .unwrap();
+    // void calls are statements, everything else is the returned value
+    let terminator = if command.result.is_some() { "" } else { ";" };
+    let call = format!(
+        "unsafe {{ (self.{}_ptr)({arguments}){terminator} }}",
+        command.name
+    );
+
+    if !checked {
+        writeln!(out, "{call}\n}}").unwrap();
+        return;
+    }
+
+    let formatted = command
+        .params
+        .iter()
+        .map(|(name, _)| format!("{name}: {{{name}:?}}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    // clippy wants plain strings without arguments
+    let describe = if command.params.is_empty() {
+        "String::from"
+    } else {
+        "format!"
+    };
+    let check = format!(
+        r#"#[cfg(feature = "checked")]
+crate::check_error(|| {describe}("{}({formatted})"), || unsafe {{ (self.get_error_ptr)() }});"#,
+        command.entry.gl_name
+    );
+
+    if command.result.is_some() {
+        writeln!(out, "let result = {call};\n{check}\nresult\n}}").unwrap();
+    } else {
+        writeln!(out, "{call}\n{check}\n}}").unwrap();
+    }
+}
+
+/// The function `with_loader` puts in place of a null pointer.
+fn emit_missing(out: &mut String, command: &Resolved) {
+    let params = command
+        .params
+        .iter()
+        .map(|(_, ty)| format!("_: {ty}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    if command.params.len() > 7 {
+        writeln!(out, "#[allow(clippy::too_many_arguments)]").unwrap();
+    }
+    writeln!(
+        out,
+        "extern \"system\" fn missing_{}({params}){} {{ crate::missing(\"{}\") }}",
+        command.name,
+        result(command.result.as_ref()),
+        command.entry.gl_name
+    )
+    .unwrap();
+}
+
+fn resolve<'a>(
+    registry: &Registry,
+    group_types: &HashMap<&str, &str>,
+    entry: &'a CommandEntry,
+) -> Result<Resolved<'a>, String> {
+    let gl_name = &entry.gl_name;
+    let command = registry
+        .commands
+        .get(gl_name)
+        .ok_or_else(|| format!("unknown command {gl_name}"))?;
+
+    for (name, _) in &entry.params {
+        if !command.params.iter().any(|p| &p.name == name) {
+            return Err(format!("{gl_name} has no parameter {name}"));
+        }
+    }
+
+    let params = command
+        .params
+        .iter()
+        .map(|param| {
+            let overridden = entry
+                .params
+                .iter()
+                .find(|(name, _)| name == &param.name)
+                .map(|(_, ty)| ty.clone());
+
+            let grouped = param
+                .group
+                .as_deref()
+                .and_then(|group| group_types.get(group))
+                .map(ToString::to_string);
+
+            let base = match overridden.or(grouped) {
+                Some(ty) => ty,
+                None => base_type(&param.declaration.base)
+                    .ok_or_else(|| format!("{gl_name}: unknown type {}", param.declaration.base))?,
+            };
+
+            Ok((param_name(&param.name), pointer(&param.declaration, base)))
+        })
+        .collect::<Result<_, String>>()?;
+
+    let result = match &entry.result {
+        Some(ty) => Some(ty.clone()),
+        None if command.result.is_void() => None,
+        None => Some(pointer(
+            &command.result,
+            base_type(&command.result.base)
+                .ok_or_else(|| format!("{gl_name}: unknown type {}", command.result.base))?,
+        )),
+    };
+
+    Ok(Resolved {
+        entry,
+        name: entry
+            .rust_name
+            .clone()
+            .unwrap_or_else(|| command_name(gl_name)),
+        params,
+        result,
+    })
+}
+
+/// Names like `src_x0` and `src_y0` that clippy considers too similar.
+fn has_similar_names(params: &[(String, String)]) -> bool {
+    params.iter().enumerate().any(|(i, (a, _))| {
+        params[i + 1..].iter().any(|(b, _)| {
+            a.len() == b.len() && a.chars().zip(b.chars()).filter(|(x, y)| x != y).count() == 1
+        })
+    })
+}
+
+fn signature(params: &[(String, String)]) -> String {
+    params
+        .iter()
+        .map(|(name, ty)| format!("{name}: {ty}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn result(result: Option<&String>) -> String {
+    result.map(|ty| format!(" -> {ty}")).unwrap_or_default()
+}
+
+fn pointer(declaration: &Declaration, base: String) -> String {
+    let qualifier = if declaration.is_const {
+        "*const "
+    } else {
+        "*mut "
+    };
+
+    (0..declaration.pointers).fold(base, |ty, _| format!("{qualifier}{ty}"))
+}
+
+fn base_type(ptype: &str) -> Option<String> {
+    let ty = match ptype {
+        "void" => "std::ffi::c_void",
+        "GLbitfield" => "GLbitField",
+        "GLDEBUGPROC" => "Option<DebugMessageCallback>",
+        "GLenum" | "GLboolean" | "GLbyte" | "GLubyte" | "GLshort" | "GLushort" | "GLint"
+        | "GLuint" | "GLsizei" | "GLfloat" | "GLdouble" | "GLchar" | "GLintptr" | "GLsizeiptr"
+        | "GLint64" | "GLuint64" | "GLsync" | "GLhalf" => ptype,
+        _ => return None,
+    };
+
+    Some(ty.to_string())
+}
+
+/// Like `base_type`, but without the types that only exist in the curated bindings.
+fn raw_type(ptype: &str) -> Option<String> {
+    match ptype {
+        "GLbitfield" => Some("GLbitField".to_string()),
+        "GLDEBUGPROC" => Some("*const std::ffi::c_void".to_string()),
+        _ => base_type(ptype),
+    }
+}
+
+/// The registry pads bitmasks to eight digits, `0x00004000` becomes `0x4000`.
+fn trim_value(value: &str) -> String {
+    match value.strip_prefix("0x") {
+        Some(hex) if hex.len() > 4 => {
+            let digits = hex.trim_start_matches('0');
+            format!("0x{digits:0>4}")
+        }
+        _ => value.to_string(),
+    }
+}
+
+fn parse_value(value: &str) -> Option<u64> {
+    value.strip_prefix("0x").map_or_else(
+        || value.parse().ok(),
+        |hex| u64::from_str_radix(hex, 16).ok(),
+    )
+}
+
+/// `userParam` becomes `user_param`, keywords get renamed.
+fn param_name(name: &str) -> String {
+    match name {
+        "type" => return "kind".to_string(),
+        "ref" => return "reference".to_string(),
+        _ => (),
+    }
+
+    let mut snake = String::with_capacity(name.len() + 4);
+    let mut previous_lower = false;
+
+    for c in name.chars() {
+        if c.is_ascii_uppercase() && previous_lower {
+            snake.push('_');
+        }
+        previous_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        snake.push(c.to_ascii_lowercase());
+    }
+
+    snake
+}
+
+/// Type suffixes of commands, longest first so `uiv` wins over `v`.
+const SUFFIXES: &[&str] = &[
+    "ui64v", "i64v", "ui64", "i64", "uiv", "ui", "iv", "fv", "dv", "i", "f", "v",
+];
+
+/// `glTexImage2D` becomes `tex_image_2d`, `glUniformMatrix4fv` becomes `uniform_matrix4_fv`.
+fn command_name(gl_name: &str) -> String {
+    let name = gl_name.strip_prefix("gl").unwrap_or(gl_name);
+    let chars: Vec<char> = name.chars().collect();
+    let mut words: Vec<String> = Vec::new();
+
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+
+            // dimensions like 2D are a word of their own
+            if chars.get(i) == Some(&'D') && chars.get(i + 1).is_none_or(char::is_ascii_uppercase) {
+                words.push(chars[start..=i].iter().collect());
+                i += 1;
+            } else if let Some(word) = words.last_mut() {
+                word.extend(&chars[start..i]);
+            } else {
+                words.push(chars[start..i].iter().collect());
+            }
+            continue;
+        }
+
+        if c.is_ascii_uppercase() || words.is_empty() {
+            words.push(String::new());
+        }
+        words.last_mut().unwrap().push(c);
+        i += 1;
+    }
+
+    if let Some(last) = words.pop() {
+        let split = SUFFIXES.iter().find_map(|suffix| {
+            let stem = last.strip_suffix(suffix)?;
+            stem.ends_with(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit())
+                .then_some((stem, *suffix))
+        });
+
+        match split {
+            Some((stem, suffix)) => {
+                words.push(stem.to_string());
+                words.push(suffix.to_string());
+            }
+            None => words.push(last),
+        }
+    }
+
+    words
+        .iter()
+        .map(|w| w.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, generate_mock, Target};
+    use crate::{allow_list::AllowList, registry::Registry};
+
+    const REGISTRY: &str = r#"<registry>
+    <groups>
+        <group name="ClearBufferMask">
+            <enum name="GL_COLOR_BUFFER_BIT"/>
+            <enum name="GL_DEPTH_BUFFER_BIT"/>
+        </group>
+    </groups>
+    <enums namespace="GL" group="ClearBufferMask" type="bitmask">
+        <enum value="0x00000100" name="GL_DEPTH_BUFFER_BIT"/>
+        <enum value="0x00004000" name="GL_COLOR_BUFFER_BIT"/>
+    </enums>
+    <enums namespace="GL">
+        <enum value="0" name="GL_NONE"/>
+        <enum value="0x1F03" name="GL_EXTENSIONS"/>
+        <enum value="0x8888" name="GL_EXTENSIONS" api="gles2"/>
+    </enums>
+    <commands namespace="GL">
+        <command>
+            <proto>void <name>glClear</name></proto>
+            <param group="ClearBufferMask"><ptype>GLbitfield</ptype> <name>mask</name></param>
+        </command>
+        <command>
+            <proto group="String">const <ptype>GLubyte</ptype> *<name>glGetStringi</name></proto>
+            <param><ptype>GLenum</ptype> <name>name</name></param>
+            <param><ptype>GLuint</ptype> <name>index</name></param>
+        </command>
+        <command>
+            <proto>void <name>glShaderSource</name></proto>
+            <param><ptype>GLuint</ptype> <name>shader</name></param>
+            <param><ptype>GLsizei</ptype> <name>count</name></param>
+            <param len="count">const <ptype>GLchar</ptype> *const*<name>string</name></param>
+            <param len="count">const <ptype>GLint</ptype> *<name>length</name></param>
+        </command>
+    </commands>
+</registry>"#;
+
+    const TARGET: Target = Target {
+        allow_list_name: "test.txt",
+        api_doc: "Test bindings",
+    };
+
+    fn generated(allow_list: &str) -> Result<String, String> {
+        let registry = Registry::parse(REGISTRY).unwrap();
+        generate(&registry, &AllowList::parse(allow_list).unwrap(), &TARGET)
+    }
+
+    #[test]
+    fn registry_keeps_gl_values_and_groups() {
+        let registry = Registry::parse(REGISTRY).unwrap();
+
+        assert_eq!(registry.enums["GL_EXTENSIONS"], "0x1F03");
+        assert!(registry.groups["ClearBufferMask"].contains("GL_DEPTH_BUFFER_BIT"));
+    }
+
+    #[test]
+    fn renamed_commands_keep_the_registry_name_for_loading() {
+        let code = generated(
+            "fn glGetStringi as get_stringi
+    -> *const GLubyte
+",
+        )
+        .unwrap();
+
+        assert!(code.contains(
+            "pub unsafe fn get_stringi(&self, name: GLenum, index: GLuint) -> *const GLubyte {"
+        ));
+        assert!(code.contains("get_stringi_ptr: loader.load_or(\"glGetStringi\""));
+        assert!(code.contains(
+            "extern \"system\" fn missing_get_stringi(_: GLenum, _: GLuint) -> *const GLubyte { crate::missing(\"glGetStringi\") }"
+        ));
+        assert!(!code.contains("get_string_i"));
+
+        let registry = Registry::parse(REGISTRY).unwrap();
+        let mock = generate_mock(
+            &registry,
+            &AllowList::parse("fn glGetStringi as get_stringi").unwrap(),
+            &TARGET,
+        )
+        .unwrap();
+        assert!(mock.contains("\"glGetStringi\" => get_stringi as *const std::ffi::c_void,"));
+    }
+
+    #[test]
+    fn bitmasks_combine_and_type_their_group_params() {
+        let code = generated(
+            "bitmask ClearMask: GLbitField from ClearBufferMask
+    NONE = GL_NONE
+    COLOR = GL_COLOR_BUFFER_BIT
+    DEPTH = GL_DEPTH_BUFFER_BIT
+fn glClear
+",
+        )
+        .unwrap();
+
+        assert!(code.contains("pub struct ClearMask(GLbitField);"));
+        assert!(code.contains("pub const COLOR: Self = Self(0x4000);"));
+        assert!(code.contains("pub const DEPTH: Self = Self(0x0100);"));
+        assert!(code.contains("impl std::ops::BitOr for ClearMask {"));
+        // the empty mask is printed as NONE instead of being a flag of its own
+        assert!(code.contains(
+            "for (flag, name) in [(Self::COLOR, \"COLOR\"), (Self::DEPTH, \"DEPTH\")] {"
+        ));
+        assert!(code.contains("pub unsafe fn clear(&self, mask: ClearMask) {"));
+    }
+
+    #[test]
+    fn const_pointers_keep_every_level() {
+        let code = generated(
+            "fn glShaderSource
+    shader: Shader
+",
+        )
+        .unwrap();
+
+        assert!(code.contains(
+            "pub unsafe fn shader_source(&self, shader: Shader, count: GLsizei, string: *const *const GLchar, length: *const GLint) {"
+        ));
+    }
+
+    #[test]
+    fn unknown_names_fail() {
+        for (allow_list, expected) in [
+            ("fn glDoesNotExist", "unknown command glDoesNotExist"),
+            (
+                "fn glClear\n    flags: ClearMask",
+                "glClear has no parameter flags",
+            ),
+            (
+                "enum Missing: GLenum from NoSuchGroup",
+                "Missing: unknown group NoSuchGroup",
+            ),
+            (
+                "enum Missing: GLenum\n    GL_DOES_NOT_EXIST",
+                "Missing: unknown enum GL_DOES_NOT_EXIST",
+            ),
+            (
+                "enum A: GLenum from ClearBufferMask\nuse B from ClearBufferMask",
+                "A and B both claim group ClearBufferMask",
+            ),
+        ] {
+            assert_eq!(generated(allow_list).err().as_deref(), Some(expected));
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Generates the bindings of `gl_bindings` from the Khronos `gl.xml` registry.
+//!
+//! Only the commands and enums picked in the allow-lists in `crates/gl_bindings/registry`
+//! are generated, to keep the bindings small. After changing an allow-list, run
+//! `cargo run -p gl_generator` and commit the regenerated files.
+use std::{fs, path::Path, process::Command};
+
+mod allow_list;
+mod emit;
+mod registry;
+
+struct Output {
+    allow_list: &'static str,
+    path: &'static str,
+    mock_path: &'static str,
+    api_doc: &'static str,
+}
+
+const OUTPUTS: &[Output] = &[
+    Output {
+        allow_list: "gl43.txt",
+        path: "src/gl43/generated.rs",
+        mock_path: "src/mock/gl43.rs",
+        api_doc: "Bindings to a curated subset of OpenGL 4.3",
+    },
+    Output {
+        allow_list: "gl44.txt",
+        path: "src/gl44/generated.rs",
+        mock_path: "src/mock/gl44.rs",
+        api_doc: "Buffer storage functions of OpenGL 4.4, loaded next to `gl43::Api`",
+    },
+    Output {
+        allow_list: "gl45.txt",
+        path: "src/gl45/generated.rs",
+        mock_path: "src/mock/gl45.rs",
+        api_doc: "Direct state access functions of OpenGL 4.5, loaded next to `gl43::Api`",
+    },
+];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let bindings = Path::new(env!("CARGO_MANIFEST_DIR")).join("../gl_bindings");
+    let registry =
+        registry::Registry::parse(&fs::read_to_string(bindings.join("registry/gl.xml"))?)?;
+
+    for output in OUTPUTS {
+        let allow_list = allow_list::AllowList::parse(&fs::read_to_string(
+            bindings.join("registry").join(output.allow_list),
+        )?)
+        .map_err(|e| format!("{}: {e}", output.allow_list))?;
+
+        let target = emit::Target {
+            allow_list_name: output.allow_list,
+            api_doc: output.api_doc,
+        };
+
+        let code = emit::generate(&registry, &allow_list, &target)
+            .map_err(|e| format!("{}: {e}", output.allow_list))?;
+        write(&bindings.join(output.path), &code)?;
+
+        let mock = emit::generate_mock(&registry, &allow_list, &target)
+            .map_err(|e| format!("{}: {e}", output.allow_list))?;
+        write(&bindings.join(output.mock_path), &mock)?;
+    }
+
+    Ok(())
+}
+
+fn write(path: &Path, code: &str) -> std::io::Result<()> {
+    fs::write(path, code)?;
+
+    let formatted = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .arg(path)
+        .status()
+        .is_ok_and(|status| status.success());
+
+    if !formatted {
+        eprintln!("warning: failed to run rustfmt on {}", path.display());
+    }
+
+    println!("generated {}", path.display());
+    Ok(())
+}
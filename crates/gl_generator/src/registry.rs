@@ -0,0 +1,153 @@
+use std::collections::{HashMap, HashSet};
+
+/// The parts of the Khronos `gl.xml` registry the generator cares about.
+pub struct Registry {
+    /// Enum name (e.g. `GL_DEPTH_TEST`) to its value as written in the registry.
+    pub enums: HashMap<String, String>,
+    /// Registry group (e.g. `EnableCap`) to the enum names it contains.
+    pub groups: HashMap<String, HashSet<String>>,
+    pub commands: HashMap<String, Command>,
+}
+
+pub struct Command {
+    pub result: Declaration,
+    pub params: Vec<Param>,
+}
+
+pub struct Param {
+    pub name: String,
+    pub group: Option<String>,
+    pub declaration: Declaration,
+}
+
+/// A C declaration broken down into what matters for FFI: `const GLchar *const*` becomes
+/// base `GLchar`, const and two levels of pointers.
+pub struct Declaration {
+    pub base: String,
+    pub is_const: bool,
+    pub pointers: usize,
+}
+
+impl Declaration {
+    pub fn is_void(&self) -> bool {
+        self.base == "void" && self.pointers == 0
+    }
+}
+
+impl Registry {
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let document = roxmltree::Document::parse(source).map_err(|e| e.to_string())?;
+        let root = document.root_element();
+
+        let mut enums = HashMap::new();
+        let mut groups = HashMap::new();
+        let mut commands = HashMap::new();
+
+        for node in root.children().filter(roxmltree::Node::is_element) {
+            match node.tag_name().name() {
+                "enums" => {
+                    for e in node.children().filter(|n| n.has_tag_name("enum")) {
+                        // some enums have a different value on GLES
+                        if e.attribute("api").is_some_and(|api| api != "gl") {
+                            continue;
+                        }
+
+                        if let (Some(name), Some(value)) =
+                            (e.attribute("name"), e.attribute("value"))
+                        {
+                            enums
+                                .entry(name.to_string())
+                                .or_insert_with(|| value.to_string());
+                        }
+                    }
+                }
+                "groups" => {
+                    for group in node.children().filter(|n| n.has_tag_name("group")) {
+                        let Some(name) = group.attribute("name") else {
+                            continue;
+                        };
+
+                        let members = group
+                            .children()
+                            .filter(|n| n.has_tag_name("enum"))
+                            .filter_map(|n| n.attribute("name"))
+                            .map(ToString::to_string)
+                            .collect();
+
+                        groups.insert(name.to_string(), members);
+                    }
+                }
+                "commands" => {
+                    for command in node.children().filter(|n| n.has_tag_name("command")) {
+                        let proto = command
+                            .children()
+                            .find(|n| n.has_tag_name("proto"))
+                            .ok_or("command without proto")?;
+
+                        let name = child_text(proto, "name").ok_or("command without name")?;
+
+                        let params = command
+                            .children()
+                            .filter(|n| n.has_tag_name("param"))
+                            .map(|param| {
+                                Ok(Param {
+                                    name: child_text(param, "name")
+                                        .ok_or_else(|| format!("{name}: param without name"))?,
+                                    group: param.attribute("group").map(ToString::to_string),
+                                    declaration: Declaration::parse(param),
+                                })
+                            })
+                            .collect::<Result<_, String>>()?;
+
+                        commands.insert(
+                            name,
+                            Command {
+                                result: Declaration::parse(proto),
+                                params,
+                            },
+                        );
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        Ok(Self {
+            enums,
+            groups,
+            commands,
+        })
+    }
+}
+
+impl Declaration {
+    fn parse(node: roxmltree::Node) -> Self {
+        let mut text = String::new();
+        let mut base = None;
+
+        for child in node.children() {
+            if child.has_tag_name("name") {
+                break;
+            }
+
+            if child.has_tag_name("ptype") {
+                base = child.text().map(ToString::to_string);
+            } else if let Some(t) = child.text().filter(|_| child.is_text()) {
+                text.push_str(t);
+            }
+        }
+
+        Self {
+            base: base.unwrap_or_else(|| "void".to_string()),
+            is_const: text.contains("const"),
+            pointers: text.matches('*').count(),
+        }
+    }
+}
+
+fn child_text(node: roxmltree::Node, tag: &str) -> Option<String> {
+    node.children()
+        .find(|n| n.has_tag_name(tag))
+        .and_then(|n| n.text())
+        .map(ToString::to_string)
+}